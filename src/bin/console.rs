@@ -0,0 +1,248 @@
+//! Talks to Hodgey in a terminal instead of Discord, as a few fake users in a fake server.
+//! `cargo run --bin console`, then type messages like you would in Discord. Lines starting with ":" control the console.
+use std::io::{BufRead, Write};
+use serde_json::Value;
+use serenity::all::{ChannelId, GuildId, Permissions};
+use serenity::async_trait;
+use serenity::builder::{CreateActionRow, CreateEmbed};
+use serenity::prelude::*;
+use hodgeybot::commands::Incoming;
+use hodgeybot::config::{self, ConfigStore};
+use hodgeybot::content::Content;
+use hodgeybot::permissions::MemberInfo;
+use hodgeybot::transport::{Outgoing, Transport};
+use hodgeybot::{bot_state, respond, rng, settings};
+
+const GUILD_ID: u64 = 1;
+const CHANNEL_ID: u64 = 2;
+
+const CONSOLE_HELP: &str = "\
+Type anything to send it as the current user, \"@name\" mentions another user.
+:as <name>   switch user, making them up if they don't exist yet
+:admin       toggle whether the current user is a server admin
+:users       list everyone
+:help        show this
+:quit        stop";
+
+struct FakeUser {
+    id: u64,
+    name: String,
+    admin: bool
+}
+
+struct Console {
+    users: Vec<FakeUser>,
+    current: usize
+}
+
+impl Console {
+    fn new() -> Self {
+        Self {
+            users: vec![
+                FakeUser { id: 101, name: String::from("alice"), admin: true },
+                FakeUser { id: 102, name: String::from("bob"), admin: false }
+            ],
+            current: 0
+        }
+    }
+
+    fn current_user(&self) -> &FakeUser {
+        &self.users[self.current]
+    }
+
+    fn switch_to(&mut self, name: &str) {
+        let name = name.to_lowercase();
+        self.current = match self.users.iter().position(|user| user.name == name) {
+            Some(index) => index,
+            None => {
+                let id = self.users.iter().map(|user| user.id).max().unwrap_or(100) + 1;
+                self.users.push(FakeUser { id, name, admin: false });
+                self.users.len() - 1
+            }
+        };
+    }
+
+    fn name_for(&self, user_id: u64) -> Option<&str> {
+        if user_id == settings::get().bot_id {
+            return Some("hodgey");
+        }
+        self.users.iter().find(|user| user.id == user_id).map(|user| user.name.as_str())
+    }
+
+    /// Turns "@bob" into "<@102>" like the Discord client does, and collects who was mentioned
+    fn resolve_mentions(&self, line: &str) -> (String, Vec<u64>) {
+        let mut mentions = Vec::new();
+        let words: Vec<String> = line.split(' ').map(|word| {
+            let id = match word.strip_prefix('@') {
+                Some("hodgey") => Some(settings::get().bot_id),
+                Some(name) => self.users.iter().find(|user| user.name == name.to_lowercase()).map(|user| user.id),
+                None => word.strip_prefix("<@").and_then(|word| word.strip_suffix('>')).and_then(|id| id.parse().ok())
+            };
+            match id {
+                Some(id) => {
+                    mentions.push(id);
+                    format!("<@{id}>")
+                },
+                None => word.to_string()
+            }
+        }).collect();
+        (words.join(" "), mentions)
+    }
+
+    /// The other way, so replies read as "@bob" instead of "<@102>"
+    fn show_mentions(&self, text: &str) -> String {
+        let mut text = text.to_string();
+        for user_id in self.users.iter().map(|user| user.id).chain([settings::get().bot_id]) {
+            text = text.replace(&format!("<@{user_id}>"), &format!("@{}", self.name_for(user_id).unwrap_or_default()));
+        }
+        text
+    }
+}
+
+/// Prints everything Hodgey sends.
+struct ConsoleTransport<'a> {
+    console: &'a Console
+}
+
+impl ConsoleTransport<'_> {
+    fn print(&self, text: &str) {
+        for line in self.console.show_mentions(text).lines() {
+            println!("hodgey> {line}");
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for ConsoleTransport<'_> {
+    async fn send(&self, outgoing: Outgoing) -> serenity::Result<()> {
+        match outgoing {
+            Outgoing::Reply(reply) => {
+                if !reply.content.is_empty() {
+                    self.print(&reply.content);
+                }
+                for embed in &reply.embeds {
+                    self.print(&render_embed(embed));
+                }
+                for row in &reply.components {
+                    self.print(&render_action_row(row));
+                }
+            },
+            Outgoing::Say(content) => self.print(&content),
+            Outgoing::React(reaction) => println!("hodgey reacted {reaction}")
+        }
+        Ok(())
+    }
+
+    async fn member_info(&self) -> Option<MemberInfo> {
+        let permissions = if self.console.current_user().admin {
+            Permissions::ADMINISTRATOR
+        }
+        else {
+            Permissions::SEND_MESSAGES
+        };
+        Some(MemberInfo { is_owner: false, permissions, role_ids: Vec::new() })
+    }
+
+    async fn human_member_ids(&self) -> serenity::Result<Vec<u64>> {
+        Ok(self.console.users.iter().map(|user| user.id).collect())
+    }
+
+    //There is only the one server
+    async fn channel_in_guild(&self, guild_id: GuildId, _channel_id: ChannelId) -> bool {
+        guild_id.get() == GUILD_ID
+    }
+}
+
+fn text_of(value: &Value, key: &str) -> String {
+    value.get(key).and_then(Value::as_str).unwrap_or_default().to_string()
+}
+
+/// The builders only expose what they contain through serde
+fn render_embed(embed: &CreateEmbed) -> String {
+    let embed = serde_json::to_value(embed).unwrap_or_default();
+    let mut lines = vec![format!("┌ {}", text_of(&embed, "title"))];
+    for line in text_of(&embed, "description").lines() {
+        lines.push(format!("│ {line}"));
+    }
+    for field in embed.get("fields").and_then(Value::as_array).into_iter().flatten() {
+        lines.push(format!("│ {}", text_of(field, "name")));
+        for line in text_of(field, "value").lines() {
+            lines.push(format!("│   {line}"));
+        }
+    }
+    let footer = embed.get("footer").map(|footer| text_of(footer, "text")).unwrap_or_default();
+    lines.push(format!("└ {footer}"));
+    lines.join("\n")
+}
+
+/// Buttons as [Label], select menus as a list of their options. They can't be clicked here.
+fn render_action_row(row: &CreateActionRow) -> String {
+    let row = serde_json::to_value(row).unwrap_or_default();
+    row.get("components").and_then(Value::as_array).into_iter().flatten().map(|component| {
+        match component.get("options").and_then(Value::as_array) {
+            Some(options) => {
+                let labels: Vec<String> = options.iter().map(|option| text_of(option, "label")).collect();
+                format!("{}: {}", text_of(component, "placeholder"), labels.join(", "))
+            },
+            None => format!("[{}]", text_of(component, "label"))
+        }
+    }).collect::<Vec<String>>().join(" ")
+}
+
+#[tokio::main]
+async fn main() {
+    match settings::load() {
+        Ok(loaded) => settings::set(loaded),
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    }
+    let seed = rng::seed_from_env();
+    let data = RwLock::new(bot_state(ConfigStore::in_memory(), Content::load(), seed));
+    let mut console = Console::new();
+    println!("Hodgey console, seed {seed} (run with HODGEY_SEED={seed} to replay)");
+    println!("{CONSOLE_HELP}");
+
+    let mut lines = std::io::stdin().lock().lines();
+    loop {
+        print!("{}> ", console.current_user().name);
+        std::io::stdout().flush().expect("Error flushing stdout");
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        let line = line.trim();
+
+        match line.split_once(' ').unwrap_or((line, "")) {
+            (":quit", _) => break,
+            (":help", _) => println!("{CONSOLE_HELP}"),
+            (":as", name) if !name.trim().is_empty() => console.switch_to(name.trim()),
+            (":admin", _) => {
+                let user = &mut console.users[console.current];
+                user.admin = !user.admin;
+                println!("{} is {}an admin", user.name, if user.admin { "" } else { "not " });
+            },
+            (":users", _) => {
+                for user in &console.users {
+                    println!("{} ({}){}", user.name, user.id, if user.admin { ", admin" } else { "" });
+                }
+            },
+            (command, _) if command.starts_with(':') => println!("{CONSOLE_HELP}"),
+            _ => {
+                let (content, mentions) = console.resolve_mentions(line);
+                let incoming = Incoming {
+                    author_id: console.current_user().id,
+                    channel_id: ChannelId::new(CHANNEL_ID),
+                    guild_id: Some(GuildId::new(GUILD_ID)),
+                    mentions,
+                    content
+                };
+                let config = config::guild_config(&data, incoming.guild_id).await;
+                let transport = ConsoleTransport { console: &console };
+                if let Some(quote) = respond(&data, &transport, &incoming, &config).await {
+                    transport.print(&quote);
+                }
+            }
+        }
+    }
+}
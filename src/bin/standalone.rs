@@ -0,0 +1,96 @@
+//! Runs the bot on its own, without Shuttle, for self hosting and containers.
+//! `DISCORD_TOKEN` comes from the environment or from the secrets file,
+//! which is `Secrets.toml` (the same one Shuttle uses) unless `HODGEY_SECRETS_PATH` says otherwise.
+//! Anything else in the file, like `HODGEY_CONFIG_PATH` or `HODGEY_SEED`, is used when the environment doesn't set it.
+use std::collections::HashMap;
+use tracing::{error, info};
+use hodgeybot::client_builder;
+use hodgeybot::config::ConfigStore;
+use hodgeybot::content::Content;
+use hodgeybot::{rng, settings};
+
+const DEFAULT_SECRETS_PATH: &str = "Secrets.toml";
+
+/// Copies secrets from the file into the environment, without overwriting what is already set.
+/// A missing file is fine as long as the environment has everything.
+fn load_secrets_file() {
+    let path = std::env::var("HODGEY_SECRETS_PATH").unwrap_or_else(|_| String::from(DEFAULT_SECRETS_PATH));
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        info!("No secrets file at {path}, only using environment variables");
+        return;
+    };
+    let secrets: HashMap<String, toml::Value> = match toml::from_str(&contents) {
+        Ok(secrets) => secrets,
+        Err(e) => {
+            error!("Error reading secrets file {path}: {e}");
+            return;
+        }
+    };
+
+    for (key, value) in secrets {
+        if std::env::var_os(&key).is_some() {
+            continue;
+        }
+        let value = match value {
+            toml::Value::String(value) => value,
+            value => value.to_string()
+        };
+        //Nothing else is running yet, so changing the environment is safe
+        std::env::set_var(key, value);
+    }
+}
+
+/// Ctrl-c, or SIGTERM which is what containers get when they are stopped
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        let mut terminate = signal(SignalKind::terminate()).expect("Err listening for SIGTERM");
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => (),
+            _ = terminate.recv() => ()
+        }
+    }
+    #[cfg(not(unix))]
+    if let Err(e) = tokio::signal::ctrl_c().await {
+        error!("Error waiting for ctrl-c: {e:?}");
+    }
+}
+
+fn main() {
+    tracing_subscriber::fmt::init();
+    load_secrets_file();
+
+    let Ok(token) = std::env::var("DISCORD_TOKEN") else {
+        error!("'DISCORD_TOKEN' was not found in the environment or secrets file");
+        std::process::exit(1);
+    };
+
+    match settings::load() {
+        Ok(loaded) => settings::set(loaded),
+        Err(e) => {
+            error!("{e}");
+            std::process::exit(1);
+        }
+    }
+
+    let runtime = tokio::runtime::Runtime::new().expect("Err creating tokio runtime");
+    runtime.block_on(async {
+        settings::watch();
+        let mut client = client_builder(&token, ConfigStore::load(), Content::load(), rng::seed_from_env())
+            .await
+            .expect("Err creating client");
+
+        let shard_manager = client.shard_manager.clone();
+        tokio::spawn(async move {
+            shutdown_signal().await;
+            info!("Shutting down");
+            shard_manager.shutdown_all().await;
+        });
+
+        if let Err(e) = client.start_autosharded().await {
+            error!("Client error: {e:?}");
+            std::process::exit(1);
+        }
+    });
+}
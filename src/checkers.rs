@@ -0,0 +1,495 @@
+use std::fmt;
+use serenity::prelude::*;
+use rand::{Rng, seq::SliceRandom};
+use crate::locale::Locale;
+
+//American checkers calls it a draw after 40 moves each without a capture or a crowning
+const DRAW_PLIES: u32 = 80;
+const SEARCH_DEPTH: u32 = 7;
+const WIN_SCORE: i32 = 100_000;
+
+pub struct CheckersGames;
+
+impl TypeMapKey for CheckersGames {
+    type Value = Mutex<Vec<CheckersGame>>;
+}
+
+#[derive(Debug)]
+pub enum MoveError {
+    InvalidMove,
+    IllegalMove,
+    CaptureRequired(Vec<String>),
+    AmbiguousMove(Vec<String>)
+}
+
+impl MoveError {
+    /// What to tell the player
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            Self::InvalidMove => String::from(locale.text("checkers_invalid_move")),
+            Self::IllegalMove => String::from(locale.text("illegal_move")),
+            Self::CaptureRequired(captures) => locale.format("capture_required", &[("moves", &captures.join(", "))]),
+            Self::AmbiguousMove(candidates) => locale.format("ambiguous_move", &[("moves", &candidates.join(locale.text("or")))])
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Black,
+    White
+}
+
+impl Side {
+    const fn opponent(self) -> Self {
+        match self {
+            Self::Black => Self::White,
+            Self::White => Self::Black
+        }
+    }
+
+    /// Black starts at the top of the board and moves down
+    const fn forward(self) -> i32 {
+        match self {
+            Self::Black => 1,
+            Self::White => -1
+        }
+    }
+
+    const fn crowning_row(self) -> usize {
+        match self {
+            Self::Black => 7,
+            Self::White => 0
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Piece {
+    side: Side,
+    king: bool
+}
+
+/// Squares use the standard checkers numbering (0 here is square 1 on the board)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CheckersMove {
+    path: Vec<usize>,
+    captures: Vec<usize>
+}
+
+impl fmt::Display for CheckersMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = if self.captures.is_empty() { "-" } else { "x" };
+        let squares: Vec<String> = self.path.iter().map(|square| (square + 1).to_string()).collect();
+        write!(f, "{}", squares.join(separator))
+    }
+}
+
+const fn coords(square: usize) -> (i32, i32) {
+    let row = square / 4;
+    let col = (square % 4) * 2 + 1 - row % 2;
+    (row as i32, col as i32)
+}
+
+fn square_at(row: i32, col: i32) -> Option<usize> {
+    if !(0..8).contains(&row) || !(0..8).contains(&col) || (row + col) % 2 == 0 {
+        return None;
+    }
+    Some((row * 4 + col / 2) as usize)
+}
+
+#[derive(Clone, PartialEq)]
+struct Board {
+    squares: [Option<Piece>; 32],
+    turn: Side,
+    plies_since_progress: u32
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        let mut squares = [None; 32];
+        for square in 0..12 {
+            squares[square] = Some(Piece { side: Side::Black, king: false });
+            squares[31 - square] = Some(Piece { side: Side::White, king: false });
+        }
+        Self {
+            squares,
+            turn: Side::Black,
+            plies_since_progress: 0
+        }
+    }
+}
+
+impl Board {
+    fn directions(piece: Piece) -> Vec<(i32, i32)> {
+        let forward = piece.side.forward();
+        if piece.king {
+            vec![(forward, -1), (forward, 1), (-forward, -1), (-forward, 1)]
+        }
+        else {
+            vec![(forward, -1), (forward, 1)]
+        }
+    }
+
+    /// Captures are forced, so simple moves are only legal when there is nothing to jump.
+    fn legal_moves(&self) -> Vec<CheckersMove> {
+        let mut captures = Vec::new();
+        for square in 0..32 {
+            if let Some(piece) = self.squares[square].filter(|piece| piece.side == self.turn) {
+                self.find_jumps(piece, &mut vec![square], &mut Vec::new(), &mut captures);
+            }
+        }
+        if !captures.is_empty() {
+            return captures;
+        }
+
+        let mut moves = Vec::new();
+        for square in 0..32 {
+            if let Some(piece) = self.squares[square].filter(|piece| piece.side == self.turn) {
+                let (row, col) = coords(square);
+                for (row_step, col_step) in Self::directions(piece) {
+                    if let Some(target) = square_at(row + row_step, col + col_step).filter(|target| self.squares[*target].is_none()) {
+                        moves.push(CheckersMove { path: vec![square, target], captures: Vec::new() });
+                    }
+                }
+            }
+        }
+        moves
+    }
+
+    /// Extends a jump sequence as far as it will go. A man that gets crowned mid-jump stops there.
+    fn find_jumps(&self, piece: Piece, path: &mut Vec<usize>, captured: &mut Vec<usize>, results: &mut Vec<CheckersMove>) {
+        let (row, col) = coords(*path.last().unwrap());
+        let mut extended = false;
+
+        for (row_step, col_step) in Self::directions(piece) {
+            let Some(jumped) = square_at(row + row_step, col + col_step) else { continue };
+            let Some(landing) = square_at(row + 2 * row_step, col + 2 * col_step) else { continue };
+            let jumps_opponent = self.squares[jumped].is_some_and(|other| other.side != piece.side) && !captured.contains(&jumped);
+            let landing_free = self.squares[landing].is_none() || landing == path[0];
+            if !jumps_opponent || !landing_free {
+                continue;
+            }
+
+            extended = true;
+            path.push(landing);
+            captured.push(jumped);
+            if !piece.king && coords(landing).0 as usize == piece.side.crowning_row() {
+                results.push(CheckersMove { path: path.clone(), captures: captured.clone() });
+            }
+            else {
+                self.find_jumps(piece, path, captured, results);
+            }
+            path.pop();
+            captured.pop();
+        }
+
+        if !extended && !captured.is_empty() {
+            results.push(CheckersMove { path: path.clone(), captures: captured.clone() });
+        }
+    }
+
+    fn play(&mut self, selected_move: &CheckersMove) {
+        let start = selected_move.path[0];
+        let end = *selected_move.path.last().unwrap();
+        let mut piece = self.squares[start].take().expect("Move must start on a piece");
+        for captured in &selected_move.captures {
+            self.squares[*captured] = None;
+        }
+
+        let crowned = !piece.king && coords(end).0 as usize == piece.side.crowning_row();
+        piece.king |= crowned;
+        self.squares[end] = Some(piece);
+
+        if crowned || !selected_move.captures.is_empty() {
+            self.plies_since_progress = 0;
+        }
+        else {
+            self.plies_since_progress += 1;
+        }
+        self.turn = self.turn.opponent();
+    }
+
+    /// Material and advancement from the side to move's point of view
+    fn evaluate(&self) -> i32 {
+        let mut score = 0;
+        for (square, piece) in self.squares.iter().enumerate() {
+            let Some(piece) = piece else { continue };
+            let (row, _) = coords(square);
+            let value = if piece.king {
+                160
+            }
+            else {
+                let advancement = match piece.side {
+                    Side::Black => row,
+                    Side::White => 7 - row
+                };
+                100 + advancement * 3
+            };
+            score += if piece.side == self.turn { value } else { -value };
+        }
+        score
+    }
+
+    fn negamax(&self, depth: u32, mut alpha: i32, beta: i32) -> i32 {
+        let moves = self.legal_moves();
+        if moves.is_empty() {
+            //Losing later is better than losing now
+            return -WIN_SCORE - depth as i32;
+        }
+        if self.plies_since_progress >= DRAW_PLIES {
+            return 0;
+        }
+        if depth == 0 {
+            return self.evaluate();
+        }
+
+        let mut best = i32::MIN + 1;
+        for selected_move in moves {
+            let mut child = self.clone();
+            child.play(&selected_move);
+            let score = -child.negamax(depth - 1, -beta, -alpha);
+            best = best.max(score);
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+        best
+    }
+}
+
+#[derive(Clone)]
+pub struct CheckersGame {
+    pub black_id: u64,
+    pub white_id: u64,
+    board: Board,
+    last_move: Option<CheckersMove>
+}
+
+impl CheckersGame {
+    pub fn new_game_random_sides(player1_id: u64, player2_id: u64, rng: &mut impl Rng) -> Self {
+        let (black_id, white_id) = if rng.gen_bool(0.5) {
+            (player1_id, player2_id)
+        }
+        else {
+            (player2_id, player1_id)
+        };
+
+        Self {
+            black_id,
+            white_id,
+            board: Board::default(),
+            last_move: None
+        }
+    }
+
+    pub const fn has_user(&self, id: u64) -> bool {
+        self.black_id == id || self.white_id == id
+    }
+
+    pub fn id_to_move(&self) -> u64 {
+        match self.board.turn {
+            Side::Black => self.black_id,
+            Side::White => self.white_id
+        }
+    }
+
+    /// Accepts "11-15", "22x15x8", or just the start and end of a multi-jump ("22x8").
+    pub fn legal_move_from_string(&self, move_string: &str) -> Result<CheckersMove, MoveError> {
+        let path: Option<Vec<usize>> = move_string.split(['-', 'x', 'X'])
+            .map(|square| square.parse::<usize>().ok().filter(|square| (1..=32).contains(square)).map(|square| square - 1))
+            .collect();
+        let path = path.filter(|path| path.len() >= 2).ok_or(MoveError::InvalidMove)?;
+
+        let legal_moves = self.board.legal_moves();
+        if let Some(legal_move) = legal_moves.iter().find(|legal_move| legal_move.path == path) {
+            return Ok(legal_move.clone());
+        }
+
+        if path.len() == 2 {
+            let matching: Vec<&CheckersMove> = legal_moves.iter()
+                .filter(|legal_move| legal_move.path[0] == path[0] && legal_move.path.last() == Some(&path[1]))
+                .collect();
+            match matching.as_slice() {
+                [legal_move] => return Ok((*legal_move).clone()),
+                [] => (),
+                candidates => return Err(MoveError::AmbiguousMove(candidates.iter().map(|candidate| candidate.to_string()).collect()))
+            }
+        }
+
+        if legal_moves.iter().any(|legal_move| !legal_move.captures.is_empty()) {
+            return Err(MoveError::CaptureRequired(legal_moves.iter().map(|legal_move| legal_move.to_string()).collect()));
+        }
+        Err(MoveError::IllegalMove)
+    }
+
+    pub fn make_move_unchecked(&mut self, selected_move: CheckersMove) {
+        self.board.play(&selected_move);
+        self.last_move = Some(selected_move);
+    }
+
+    pub fn find_best_move(&self, rng: &mut impl Rng) -> CheckersMove {
+        let mut moves = self.board.legal_moves();
+        //Shuffle so equally good moves don't always play out the same
+        moves.shuffle(rng);
+
+        let mut best_move = moves[0].clone();
+        let mut alpha = i32::MIN + 1;
+        for selected_move in moves {
+            let mut child = self.board.clone();
+            child.play(&selected_move);
+            let score = -child.negamax(SEARCH_DEPTH - 1, i32::MIN + 1, -alpha);
+            if score > alpha {
+                alpha = score;
+                best_move = selected_move;
+            }
+        }
+        best_move
+    }
+
+    /// The same players and the same board, so a move found for one is legal in the other
+    pub fn same_position(&self, other: &Self) -> bool {
+        self.black_id == other.black_id && self.white_id == other.white_id && self.board == other.board
+    }
+
+    pub fn gameover(&self) -> bool {
+        self.board.legal_moves().is_empty() || self.board.plies_since_progress >= DRAW_PLIES
+    }
+
+    pub fn get_gameover_message(&self, locale: Locale) -> &'static str {
+        let key = if self.board.plies_since_progress >= DRAW_PLIES && !self.board.legal_moves().is_empty() {
+            "checkers_draw"
+        }
+        else {
+            match self.board.turn {
+                Side::Black => "black_stuck",
+                Side::White => "white_stuck"
+            }
+        };
+        locale.text(key)
+    }
+
+    /// Empty dark squares show their number so moves are easy to type.
+    pub fn render(&self, locale: Locale) -> String {
+        let mut result = String::from("```\n");
+        for row in 0..8 {
+            result += "|";
+            for col in 0..8 {
+                result += &match square_at(row, col) {
+                    None => String::from("   "),
+                    Some(square) => match self.board.squares[square] {
+                        Some(Piece { side: Side::Black, king: false }) => String::from(" b "),
+                        Some(Piece { side: Side::Black, king: true }) => String::from(" B "),
+                        Some(Piece { side: Side::White, king: false }) => String::from(" w "),
+                        Some(Piece { side: Side::White, king: true }) => String::from(" W "),
+                        None => format!("{:>2} ", square + 1)
+                    }
+                };
+            }
+            result += "|\n";
+        }
+
+        if let Some(last_move) = &self.last_move {
+            result += &locale.format("last_move", &[("move", &last_move.to_string())]);
+            result += "\n";
+        }
+        result += locale.text(match self.board.turn {
+            Side::Black => "checkers_black_to_move",
+            Side::White => "checkers_white_to_move"
+        });
+        result += "\n```";
+        result
+    }
+}
+
+/// `find_best_move` on a copy of the game, on a blocking thread so the bot keeps answering while it searches
+pub async fn think(game: CheckersGame, mut rng: impl Rng + Send + 'static) -> CheckersMove {
+    tokio::task::spawn_blocking(move || game.find_best_move(&mut rng)).await.expect("Checkers search panicked")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A game with only these pieces, by square number
+    fn game(black: &[usize], white: &[usize], kings: &[usize], turn: Side) -> CheckersGame {
+        let mut board = Board { squares: [None; 32], turn, plies_since_progress: 0 };
+        for (pieces, side) in [(black, Side::Black), (white, Side::White)] {
+            for square in pieces {
+                board.squares[square - 1] = Some(Piece { side, king: kings.contains(square) });
+            }
+        }
+        CheckersGame { black_id: 1, white_id: 2, board, last_move: None }
+    }
+
+    fn play(game: &mut CheckersGame, move_string: &str) -> String {
+        let legal_move = game.legal_move_from_string(move_string).unwrap();
+        let played = legal_move.to_string();
+        game.make_move_unchecked(legal_move);
+        played
+    }
+
+    #[test]
+    fn captures_are_forced() {
+        let game = game(&[11, 18], &[22, 24], &[], Side::White);
+        match game.legal_move_from_string("24-20") {
+            Err(MoveError::CaptureRequired(captures)) => assert_eq!(captures, ["22x15x8"]),
+            other => panic!("{other:?}")
+        }
+        assert!(matches!(game.legal_move_from_string("22x15"), Err(MoveError::CaptureRequired(_))));
+    }
+
+    #[test]
+    fn multi_jumps() {
+        let mut full = game(&[11, 18], &[22, 24], &[], Side::White);
+        assert_eq!(play(&mut full, "22x15x8"), "22x15x8");
+        assert!(full.board.squares[10].is_none() && full.board.squares[17].is_none());
+        assert!(full.board.squares[7].is_some_and(|piece| piece.side == Side::White));
+
+        //Only the start and end is enough
+        let mut shorthand = game(&[11, 18], &[22, 24], &[], Side::White);
+        assert_eq!(play(&mut shorthand, "22x8"), "22x15x8");
+        assert!(shorthand.same_position(&full));
+    }
+
+    #[test]
+    fn crowning_ends_the_jump() {
+        //As a king it could carry on over 7 to 11, but a man crowned on 2 has to stop
+        let mut game = game(&[6, 7], &[9], &[], Side::White);
+        assert!(matches!(game.legal_move_from_string("9x2x11"), Err(MoveError::CaptureRequired(_))));
+        assert_eq!(play(&mut game, "9x2"), "9x2");
+        assert!(game.board.squares[1].is_some_and(|piece| piece.king));
+        assert!(game.board.squares[6].is_some());
+    }
+
+    #[test]
+    fn kings_move_backwards() {
+        let mut king = game(&[18], &[32], &[18], Side::Black);
+        let mut moves: Vec<String> = king.board.legal_moves().iter().map(ToString::to_string).collect();
+        moves.sort_unstable();
+        assert_eq!(moves, ["18-14", "18-15", "18-22", "18-23"]);
+        play(&mut king, "18-14");
+
+        let man = game(&[18], &[32], &[], Side::Black);
+        assert!(matches!(man.legal_move_from_string("18-14"), Err(MoveError::IllegalMove)));
+    }
+
+    #[test]
+    fn draw_without_progress() {
+        let mut game = game(&[1], &[32], &[1, 32], Side::Black);
+        game.board.plies_since_progress = DRAW_PLIES - 1;
+        assert!(!game.gameover());
+        play(&mut game, "1-6");
+        assert!(game.gameover());
+        assert_eq!(game.get_gameover_message(Locale::English), "Draw! 40 moves without a capture or a crowning.");
+    }
+
+    #[test]
+    fn bad_move_strings() {
+        let game = game(&[11], &[22], &[], Side::Black);
+        for move_string in ["11-", "0-4", "33-29", "11", "eleven-15", ""] {
+            assert!(matches!(game.legal_move_from_string(move_string), Err(MoveError::InvalidMove)), "{move_string}");
+        }
+    }
+}
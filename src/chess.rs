@@ -1,11 +1,13 @@
-use std::time::Duration;
+use shakmaty::attacks;
 use shakmaty::san::{ParseSanError, San, SanError};
 use shakmaty::zobrist::{Zobrist64, ZobristHash};
-use shakmaty::{Bitboard, Chess, Color, Move, Position};
-use shakmaty::uci::{IllegalUciMoveError, UciMove};
+use shakmaty::{Bitboard, CastlingMode, Chess, Color, File, Move, Piece, Position, Rank, Role, Square};
+use shakmaty::uci::UciMove;
 use serenity::prelude::*;
-use rand::{Rng, thread_rng};
+use rand::Rng;
 use hodgey_chess_engine::find_best_move_with_time;
+use crate::locale::Locale;
+use crate::settings;
 
 pub struct ChessGames;
 
@@ -13,27 +15,45 @@ impl TypeMapKey for ChessGames {
     type Value = Mutex<Vec<ChessGame>>;
 }
 
+#[derive(Debug)]
 pub enum MoveError {
     InvalidMove,
-    IllegalMove
+    IllegalMove,
+    AmbiguousMove(Vec<String>),
+    WrongPiece(Vec<String>),
+    PinnedPiece,
+    KingInCheck(Vec<String>),
+    PromotionMissing(Vec<String>)
 }
 
-impl From<SanError> for MoveError {
-    fn from(_: SanError) -> Self {
-        Self::IllegalMove
+impl From<ParseSanError> for MoveError {
+    fn from(_: ParseSanError) -> Self {
+        Self::InvalidMove
     }
 }
 
-impl From<IllegalUciMoveError> for MoveError {
-    fn from(_: IllegalUciMoveError) -> Self {
-        Self::IllegalMove
+impl MoveError {
+    /// What to tell the player
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            Self::InvalidMove => String::from(locale.text("chess_invalid_move")),
+            Self::IllegalMove => String::from(locale.text("illegal_move")),
+            Self::AmbiguousMove(candidates) => locale.format("ambiguous_move", &[("moves", &candidates.join(locale.text("or")))]),
+            Self::WrongPiece(candidates) => locale.format("wrong_piece", &[("moves", &candidates.join(", "))]),
+            Self::PinnedPiece => String::from(locale.text("pinned_piece")),
+            Self::KingInCheck(candidates) => locale.format("king_in_check", &[("moves", &candidates.join(", "))]),
+            Self::PromotionMissing(candidates) => locale.format("promotion_missing", &[("moves", &candidates.join(", "))])
+        }
     }
 }
 
-impl From<ParseSanError> for MoveError {
-    fn from(_: ParseSanError) -> Self {
-        Self::InvalidMove
-    }
+/// A move as (SAN, UCI)
+pub type MoveOption = (String, String);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum RenderMode {
+    Link,
+    Text
 }
 
 #[derive(Clone)]
@@ -43,18 +63,24 @@ pub struct ChessGame {
     chess: Chess,
     pub show_coordinates: bool,
     pub board_flips: bool,
+    pub render_mode: RenderMode,
+    last_move: Option<(Option<Square>, Square, String)>,
+    pub draw_offer: Option<u64>,
     previously_seen_hashes: Vec<u64>,
 }
 
 impl ChessGame {
-    pub fn new_game_random_sides(player1_id: u64, player2_id: u64) -> Self {
-        if thread_rng().gen_bool(0.5) {
+    pub fn new_game_random_sides(player1_id: u64, player2_id: u64, rng: &mut impl Rng) -> Self {
+        if rng.gen_bool(0.5) {
             Self {
                 white_id: player1_id,
                 black_id: player2_id,
                 chess: Chess::default(),
                 show_coordinates: true,
                 board_flips: false,
+                render_mode: RenderMode::Link,
+                last_move: None,
+                draw_offer: None,
                 previously_seen_hashes: Vec::new(),
             }
         }
@@ -65,6 +91,9 @@ impl ChessGame {
                 chess: Chess::default(),
                 show_coordinates: true,
                 board_flips: false,
+                render_mode: RenderMode::Link,
+                last_move: None,
+                draw_offer: None,
                 previously_seen_hashes: Vec::new(),
             }
         }
@@ -82,32 +111,161 @@ impl ChessGame {
     }
 
     pub fn make_move_unchecked(&mut self, selected_move: Move) {
+        let san = San::from_move(&self.chess, &selected_move).to_string();
+        //A castling move goes to the rook's square, but the king's square is the one to mark
+        let to = match selected_move.castling_side() {
+            Some(side) => side.king_to(self.chess.turn()),
+            None => selected_move.to()
+        };
+        self.last_move = Some((selected_move.from(), to, san));
+        self.draw_offer = None;
         self.chess.play_unchecked(&selected_move);
         let new_hash = self.chess.zobrist_hash::<Zobrist64>(shakmaty::EnPassantMode::Legal).0;
         self.previously_seen_hashes.push(new_hash);
     }
 
-    pub fn legal_move_from_string(&self, mut move_string: String) -> Result<Move, MoveError> {
-        move_string = move_string.replace("o", "O").replace("0", "O");
-        
-        if let Ok(selected_move) = UciMove::from_ascii(move_string.as_bytes()) {
-            let legal_move = selected_move.to_move(&self.chess)?;
-            return Ok(legal_move)
+    /// Accepts SAN ("Nf3", "nf3", "O-O", "0-0"), UCI ("g1f3", "e7e8q"),
+    /// long algebraic ("Ng1-f3", "e7xd8=Q") and figurine notation ("♘f3").
+    /// Illegal moves are diagnosed so the reply can say why and list the candidates.
+    pub fn legal_move_from_string(&self, move_string: &str) -> Result<Move, MoveError> {
+        let move_string = normalize_move_string(move_string);
+
+        if let Some((stated_role, uci_string)) = long_algebraic_to_uci(&move_string) {
+            let Ok(uci) = uci_string.parse::<UciMove>() else {
+                return Err(MoveError::InvalidMove);
+            };
+            let UciMove::Normal { from, to, promotion } = uci else {
+                return Err(MoveError::InvalidMove);
+            };
+            let Some(piece) = self.chess.board().piece_at(from).filter(|piece| piece.color == self.chess.turn()) else {
+                return Err(MoveError::IllegalMove);
+            };
+            if stated_role.is_some_and(|role| role != piece.role) {
+                return Err(MoveError::WrongPiece(self.san_list(self.legal_moves_to(to))));
+            }
+            return match uci.to_move(&self.chess) {
+                Ok(legal_move) => Ok(legal_move),
+                Err(_) => Err(self.diagnose_illegal_move(piece.role, to, promotion))
+            };
+        }
+
+        let san = match San::from_ascii(move_string.as_bytes()) {
+            Ok(san) => san,
+            Err(parse_error) => {
+                //"nf3" should work like "Nf3", but "b" is a file so bishops still need a capital
+                let mut chars = move_string.chars();
+                match chars.next() {
+                    Some(letter @ ('n' | 'r' | 'q' | 'k')) => San::from_ascii(format!("{}{}", letter.to_ascii_uppercase(), chars.as_str()).as_bytes())?,
+                    _ => return Err(parse_error.into())
+                }
+            }
+        };
+
+        match san.to_move(&self.chess) {
+            Ok(legal_move) => Ok(legal_move),
+            Err(SanError::AmbiguousSan) => {
+                let candidates = self.chess.legal_moves().into_iter().filter(|legal_move| san.matches(legal_move)).collect();
+                Err(MoveError::AmbiguousMove(self.san_list(candidates)))
+            },
+            Err(SanError::IllegalSan) => match san {
+                San::Normal { role, to, promotion, .. } => Err(self.diagnose_illegal_move(role, to, promotion)),
+                _ if self.chess.is_check() => Err(MoveError::KingInCheck(self.san_list(self.chess.legal_moves().into_iter().collect()))),
+                _ => Err(MoveError::IllegalMove)
+            }
+        }
+    }
+
+    fn diagnose_illegal_move(&self, role: Role, to: Square, promotion: Option<Role>) -> MoveError {
+        let moves_to_square = self.legal_moves_to(to);
+
+        if role == Role::Pawn && promotion.is_none() && moves_to_square.iter().any(|legal_move| legal_move.promotion().is_some()) {
+            let promotions = moves_to_square.into_iter().filter(|legal_move| legal_move.role() == Role::Pawn).collect();
+            return MoveError::PromotionMissing(self.san_list(promotions));
+        }
+
+        if !moves_to_square.is_empty() && moves_to_square.iter().all(|legal_move| legal_move.role() != role) {
+            return MoveError::WrongPiece(self.san_list(moves_to_square));
+        }
+
+        if self.could_reach_ignoring_pins(role, to) {
+            if self.chess.is_check() {
+                return MoveError::KingInCheck(self.san_list(self.chess.legal_moves().into_iter().collect()));
+            }
+            if role != Role::King {
+                return MoveError::PinnedPiece;
+            }
+        }
+
+        MoveError::IllegalMove
+    }
+
+    fn legal_moves_to(&self, to: Square) -> Vec<Move> {
+        self.chess.legal_moves().into_iter().filter(|legal_move| legal_move.to() == to).collect()
+    }
+
+    /// Whether one of our pieces of this role could move to the square if checks and pins didn't exist.
+    fn could_reach_ignoring_pins(&self, role: Role, to: Square) -> bool {
+        let color = self.chess.turn();
+        let occupied = self.chess.board().occupied();
+        if self.chess.us().contains(to) {
+            return false;
+        }
+
+        self.chess.our(role).into_iter().any(|from| {
+            if role == Role::Pawn {
+                let forward = match color {
+                    Color::White => 8,
+                    Color::Black => -8
+                };
+                let capture = occupied.contains(to) && attacks::pawn_attacks(color, from).contains(to);
+                let push = !occupied.contains(to) && from.offset(forward) == Some(to);
+                capture || push
+            }
+            else {
+                attacks::attacks(from, Piece { color, role }, occupied).contains(to)
+            }
+        })
+    }
+
+    fn san_list(&self, moves: Vec<Move>) -> Vec<String> {
+        moves.into_iter().map(|legal_move| San::from_move(&self.chess, &legal_move).to_string()).collect()
+    }
+
+    /// Legal moves grouped by the piece that moves, for the move select menus.
+    pub fn legal_moves_by_role(&self) -> Vec<(Role, Vec<MoveOption>)> {
+        let mut grouped = Vec::new();
+        for role in [Role::Pawn, Role::Knight, Role::Bishop, Role::Rook, Role::Queen, Role::King] {
+            let moves: Vec<MoveOption> = self.chess.legal_moves().into_iter()
+                .filter(|legal_move| legal_move.role() == role)
+                .map(|legal_move| (San::from_move(&self.chess, &legal_move).to_string(), legal_move.to_uci(CastlingMode::Standard).to_string()))
+                .collect();
+            if !moves.is_empty() {
+                grouped.push((role, moves));
+            }
         }
-        
-        let selected_move: San = move_string.parse()?;
-        let legal_move = selected_move.to_move(&self.chess)?;
-        Ok(legal_move)
+        grouped
     }
 
     pub fn find_best_move(&mut self) -> Move {
-        find_best_move_with_time(&self.chess, Duration::from_secs(1), &mut self.previously_seen_hashes)
+        find_best_move_with_time(&self.chess, settings::get().chess_engine_time(), &mut self.previously_seen_hashes)
+    }
+
+    /// The same players and the same moves so far, so a move found for one is legal in the other
+    pub fn same_position(&self, other: &Self) -> bool {
+        self.white_id == other.white_id && self.black_id == other.black_id && self.previously_seen_hashes == other.previously_seen_hashes
     }
 
     pub fn is_in_check(&self) -> bool {
         self.chess.is_check()
     }
 
+    pub fn render(&self, locale: Locale) -> String {
+        match self.render_mode {
+            RenderMode::Link => self.to_link(),
+            RenderMode::Text => self.to_text_board(locale)
+        }
+    }
+
     pub fn to_link(&self) -> String {
         let fen = self.chess.board().board_fen(Bitboard::EMPTY).to_string();
         let mut result = format!("https://www.chess.com/dynboard?fen={fen}&board=bases&piece=classic&size=3");
@@ -123,22 +281,269 @@ impl ChessGame {
         result
     }
 
+    /// Renders the board as a monospaced code block for when the chess.com embed won't load.
+    /// The last move's squares are wrapped in brackets.
+    pub fn to_text_board(&self, locale: Locale) -> String {
+        let flipped = self.board_flips && self.chess.turn() == Color::Black;
+        let mut ranks: Vec<Rank> = Rank::ALL.into_iter().rev().collect();
+        let mut files: Vec<File> = File::ALL.into_iter().collect();
+        if flipped {
+            ranks.reverse();
+            files.reverse();
+        }
+
+        let file_labels = files.iter().fold(String::from("  "), |cur, file| cur + " " + &file.char().to_string() + " ");
+
+        let mut result = String::from("```\n");
+        if self.show_coordinates {
+            result += &file_labels;
+            result += "\n";
+        }
+        for rank in &ranks {
+            if self.show_coordinates {
+                result += &format!("{} ", rank.char());
+            }
+            for file in &files {
+                let square = Square::from_coords(*file, *rank);
+                let symbol = match self.chess.board().piece_at(square) {
+                    Some(piece) => piece_symbol(piece),
+                    None => '·'
+                };
+                let is_last_move = match &self.last_move {
+                    Some((from, to, _)) => *from == Some(square) || *to == square,
+                    None => false
+                };
+                if is_last_move {
+                    result += &format!("[{symbol}]");
+                }
+                else {
+                    result += &format!(" {symbol} ");
+                }
+            }
+            if self.show_coordinates {
+                result += &format!(" {}", rank.char());
+            }
+            result += "\n";
+        }
+        if self.show_coordinates {
+            result += &file_labels;
+            result += "\n";
+        }
+
+        if let Some((_, _, san)) = &self.last_move {
+            result += &locale.format("last_move", &[("move", san)]);
+            result += "\n";
+        }
+        result += locale.text(match self.chess.turn() {
+            Color::White => "white_to_move",
+            Color::Black => "black_to_move"
+        });
+        result += "\n```";
+        result
+    }
+
     pub fn gameover(&self) -> bool {
         self.chess.is_game_over() || (self.chess.halfmoves() > 100)
     }
 
-    pub fn get_gameover_message(&self) -> &'static str {        
-        if self.chess.is_checkmate() {
-            "Checkmate!"
+    pub fn get_gameover_message(&self, locale: Locale) -> &'static str {
+        let key = if self.chess.is_checkmate() {
+            "checkmate"
         }
         else if self.chess.is_insufficient_material() {
-            "Stalemate! Insufficient material."
+            "insufficient_material"
         }
         else if self.chess.halfmoves() > 100 {
-            "Draw by 50 move rule!"
+            "fifty_moves"
         }
         else {
-            "Stalemate!"
+            "stalemate"
+        };
+        locale.text(key)
+    }
+}
+fn normalize_move_string(move_string: &str) -> String {
+    let normalized: String = move_string.chars().filter_map(|c| match c {
+        '♔' | '♚' => Some('K'),
+        '♕' | '♛' => Some('Q'),
+        '♖' | '♜' => Some('R'),
+        '♗' | '♝' => Some('B'),
+        '♘' | '♞' => Some('N'),
+        '♙' | '♟' => None,
+        c => Some(c)
+    }).collect();
+    let normalized = normalized.trim_end_matches(['+', '#', '!', '?']);
+
+    //Castling can be typed with o or 0
+    if !normalized.is_empty() && normalized.chars().all(|c| matches!(c, 'o' | 'O' | '0' | '-')) {
+        return normalized.replace(['o', '0'], "O");
+    }
+    normalized.to_string()
+}
+
+/// Turns long algebraic notation ("Ng1-f3", "e7xd8=Q") or UCI ("g1f3", "e7e8Q") into a UCI string,
+/// along with the piece if a letter was given. Returns None for anything else.
+fn long_algebraic_to_uci(move_string: &str) -> Option<(Option<Role>, String)> {
+    let is_square = |square: &str| {
+        let bytes = square.as_bytes();
+        bytes.len() == 2 && (b'a'..=b'h').contains(&bytes[0]) && (b'1'..=b'8').contains(&bytes[1])
+    };
+
+    let (stated_role, rest) = match move_string.chars().next()? {
+        letter @ ('K' | 'Q' | 'R' | 'B' | 'N') => (Role::from_char(letter), &move_string[1..]),
+        _ => (None, move_string)
+    };
+
+    let from = rest.get(0..2).filter(|square| is_square(square))?;
+    let rest = rest[2..].trim_start_matches(['-', 'x']);
+    let to = rest.get(0..2).filter(|square| is_square(square))?;
+    let promotion = match rest[2..].trim_start_matches('=') {
+        "" => "",
+        "q" | "Q" => "q",
+        "r" | "R" => "r",
+        "b" | "B" => "b",
+        "n" | "N" => "n",
+        _ => return None
+    };
+
+    Some((stated_role, format!("{from}{to}{promotion}")))
+}
+
+/// `find_best_move` on a copy of the game, on a blocking thread so the bot keeps answering while the engine thinks
+pub async fn think(mut game: ChessGame) -> Move {
+    tokio::task::spawn_blocking(move || game.find_best_move()).await.expect("Chess engine panicked")
+}
+
+pub fn role_name(role: Role, locale: Locale) -> &'static str {
+    locale.text(match role {
+        Role::Pawn => "role_pawn",
+        Role::Knight => "role_knight",
+        Role::Bishop => "role_bishop",
+        Role::Rook => "role_rook",
+        Role::Queen => "role_queen",
+        Role::King => "role_king"
+    })
+}
+
+fn piece_symbol(piece: Piece) -> char {
+    match (piece.color, piece.role) {
+        (Color::White, Role::King) => '♔',
+        (Color::White, Role::Queen) => '♕',
+        (Color::White, Role::Rook) => '♖',
+        (Color::White, Role::Bishop) => '♗',
+        (Color::White, Role::Knight) => '♘',
+        (Color::White, Role::Pawn) => '♙',
+        (Color::Black, Role::King) => '♚',
+        (Color::Black, Role::Queen) => '♛',
+        (Color::Black, Role::Rook) => '♜',
+        (Color::Black, Role::Bishop) => '♝',
+        (Color::Black, Role::Knight) => '♞',
+        (Color::Black, Role::Pawn) => '♟'
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use shakmaty::fen::Fen;
+
+    const START: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+    fn game(fen: &str) -> ChessGame {
+        let mut game = ChessGame::new_game_random_sides(1, 2, &mut StdRng::seed_from_u64(0));
+        game.chess = fen.parse::<Fen>().unwrap().into_position(CastlingMode::Standard).unwrap();
+        game
+    }
+
+    /// The move as UCI, e.g. "g1f3"
+    fn uci(fen: &str, move_string: &str) -> String {
+        game(fen).legal_move_from_string(move_string).unwrap().to_uci(CastlingMode::Standard).to_string()
+    }
+
+    fn sorted(mut candidates: Vec<String>) -> Vec<String> {
+        candidates.sort_unstable();
+        candidates
+    }
+
+    #[test]
+    fn normalizing() {
+        assert_eq!(normalize_move_string("♘f3+"), "Nf3");
+        assert_eq!(normalize_move_string("♙e4!?"), "e4");
+        assert_eq!(normalize_move_string("o-o"), "O-O");
+        assert_eq!(normalize_move_string("0-0-0"), "O-O-O");
+        assert_eq!(normalize_move_string("Qxf7#"), "Qxf7");
+    }
+
+    #[test]
+    fn long_algebraic() {
+        assert_eq!(long_algebraic_to_uci("Ng1-f3"), Some((Some(Role::Knight), String::from("g1f3"))));
+        assert_eq!(long_algebraic_to_uci("e7xd8=Q"), Some((None, String::from("e7d8q"))));
+        assert_eq!(long_algebraic_to_uci("e7e8q"), Some((None, String::from("e7e8q"))));
+        assert_eq!(long_algebraic_to_uci("Nf3"), None);
+        assert_eq!(long_algebraic_to_uci("e7e8k"), None);
+    }
+
+    #[test]
+    fn every_notation_finds_the_move() {
+        assert_eq!(uci(START, "Ng1-f3"), "g1f3");
+        assert_eq!(uci(START, "♘f3"), "g1f3");
+        assert_eq!(uci(START, "nf3"), "g1f3");
+        assert_eq!(uci(START, "g1f3"), "g1f3");
+        assert_eq!(uci("4k3/8/8/8/8/2n5/1P6/4K3 w - - 0 1", "bxc3"), "b2c3");
+        assert_eq!(uci("k7/4P3/8/8/8/8/8/4K3 w - - 0 1", "e7e8q"), "e7e8q");
+        let castling = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1";
+        assert_eq!(uci(castling, "o-o"), "e1g1");
+        assert_eq!(uci(castling, "0-0-0"), "e1c1");
+    }
+
+    #[test]
+    fn castling_marks_where_the_king_went() {
+        let mut game = game("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+        game.make_move_unchecked(game.legal_move_from_string("O-O").unwrap());
+        assert_eq!(game.last_move.as_ref().map(|(from, to, _)| (*from, *to)), Some((Some(Square::E1), Square::G1)));
+        let board = game.to_text_board(Locale::English);
+        assert!(board.contains("[♔]") && board.contains("[·]"), "{board}");
+        assert_eq!(board.matches('[').count(), 2, "{board}");
+    }
+
+    #[test]
+    fn ambiguous_move() {
+        match game("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1").legal_move_from_string("Nd2") {
+            Err(MoveError::AmbiguousMove(candidates)) => assert_eq!(sorted(candidates), ["Nbd2", "Nfd2"]),
+            other => panic!("{other:?}")
+        }
+    }
+
+    #[test]
+    fn wrong_piece() {
+        match game(START).legal_move_from_string("Bf3") {
+            Err(MoveError::WrongPiece(candidates)) => assert_eq!(sorted(candidates), ["Nf3", "f3"]),
+            other => panic!("{other:?}")
         }
+        //The piece on the square doesn't match the letter
+        assert!(matches!(game(START).legal_move_from_string("Bg1-f3"), Err(MoveError::WrongPiece(_))));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn pinned_piece() {
+        assert!(matches!(game("4r2k/8/8/8/8/8/4N3/4K3 w - - 0 1").legal_move_from_string("Nc3"), Err(MoveError::PinnedPiece)));
+    }
+
+    #[test]
+    fn king_in_check() {
+        match game("4k3/8/8/8/8/8/3P4/r3K3 w - - 0 1").legal_move_from_string("d3") {
+            Err(MoveError::KingInCheck(candidates)) => assert_eq!(sorted(candidates), ["Ke2", "Kf2"]),
+            other => panic!("{other:?}")
+        }
+    }
+
+    #[test]
+    fn promotion_missing() {
+        match game("k7/4P3/8/8/8/8/8/4K3 w - - 0 1").legal_move_from_string("e8") {
+            Err(MoveError::PromotionMissing(candidates)) => assert_eq!(sorted(candidates), ["e8=B", "e8=N", "e8=Q", "e8=R"]),
+            other => panic!("{other:?}")
+        }
+    }
+}
@@ -0,0 +1,416 @@
+use serenity::all::{ButtonStyle, CommandInteraction, ComponentInteraction, ComponentInteractionDataKind};
+use serenity::builder::{CreateActionRow, CreateAutocompleteResponse, CreateButton, CreateInteractionResponse, CreateInteractionResponseFollowup, CreateInteractionResponseMessage, CreateSelectMenu, CreateSelectMenuKind, CreateSelectMenuOption, EditInteractionResponse};
+use serenity::prelude::*;
+use tracing::error;
+use rand::seq::SliceRandom;
+use crate::chess::{self, role_name, ChessGame, ChessGames, MoveOption, RenderMode};
+use crate::commands::{CommandContext, CommandError, CommandResult};
+use crate::locale::Locale;
+use crate::transport::Reply;
+use crate::{config, settings};
+
+fn no_active_game(cmd: &CommandContext<'_>) -> CommandError {
+    CommandError::User(String::from(cmd.text("no_chess_game")))
+}
+
+pub async fn resign(cmd: &CommandContext<'_>) -> CommandResult {
+    let bot_id = settings::get().bot_id;
+    let author_id = cmd.author_id;
+    let rw_lock = cmd.data.read().await;
+    let mut chess_games = rw_lock.get::<ChessGames>().expect("ChessGames not in TypeMap.").lock().await;
+    let mut opponent_id: Option<u64> = None;
+    chess_games.retain(|game| {
+        if game.has_user(author_id) {
+            if game.white_id == author_id {
+                opponent_id = Some(game.black_id)
+            }
+            else {
+                opponent_id = Some(game.white_id)
+            };
+        }
+        !game.has_user(author_id)
+    });
+    drop(chess_games); // drop mutex lock as soon as possible
+
+    match opponent_id {
+        Some(opponent_id) if opponent_id == bot_id => cmd.say(cmd.text("i_win")).await?,
+        Some(opponent_id) => cmd.say(cmd.locale.format("player_wins", &[("player", &opponent_id.to_string())])).await?,
+        None => return Err(no_active_game(cmd))
+    };
+    Ok(())
+}
+
+pub async fn toggle_coordinates(cmd: &CommandContext<'_>) -> CommandResult {
+    let rw_lock = cmd.data.read().await;
+    let mut chess_games = rw_lock.get::<ChessGames>().expect("ChessGames not in TypeMap.").lock().await;
+    let Some(game) = chess_games.iter_mut().find(|game| game.has_user(cmd.author_id)) else {
+        return Err(no_active_game(cmd));
+    };
+    game.show_coordinates = !game.show_coordinates;
+
+    let response = match game.show_coordinates {
+        true => cmd.text("coordinates_on"),
+        false => cmd.text("coordinates_off")
+    };
+    drop(chess_games); // drop mutex lock as soon as possible
+
+    cmd.reply(response).await?;
+    Ok(())
+}
+
+pub async fn toggle_board_flip(cmd: &CommandContext<'_>) -> CommandResult {
+    let rw_lock = cmd.data.read().await;
+    let mut chess_games = rw_lock.get::<ChessGames>().expect("ChessGames not in TypeMap.").lock().await;
+    let Some(game) = chess_games.iter_mut().find(|game| game.has_user(cmd.author_id)) else {
+        return Err(no_active_game(cmd));
+    };
+    game.board_flips = !game.board_flips;
+
+    let response = match game.board_flips {
+        true => cmd.text("board_flip_on"),
+        false => cmd.text("board_flip_off")
+    };
+    drop(chess_games); // drop mutex lock as soon as possible
+
+    cmd.reply(response).await?;
+    Ok(())
+}
+
+pub async fn toggle_text_board(cmd: &CommandContext<'_>) -> CommandResult {
+    let rw_lock = cmd.data.read().await;
+    let mut chess_games = rw_lock.get::<ChessGames>().expect("ChessGames not in TypeMap.").lock().await;
+    let Some(game) = chess_games.iter_mut().find(|game| game.has_user(cmd.author_id)) else {
+        return Err(no_active_game(cmd));
+    };
+    game.render_mode = match game.render_mode {
+        RenderMode::Link => RenderMode::Text,
+        RenderMode::Text => RenderMode::Link
+    };
+
+    let response = match game.render_mode {
+        RenderMode::Text => cmd.text("text_board_on"),
+        RenderMode::Link => cmd.text("text_board_off")
+    };
+    drop(chess_games); // drop mutex lock as soon as possible
+
+    cmd.reply(response).await?;
+    Ok(())
+}
+
+pub async fn show(cmd: &CommandContext<'_>) -> CommandResult {
+    let rw_lock = cmd.data.read().await;
+    let chess_games = rw_lock.get::<ChessGames>().expect("ChessGames not in TypeMap.").lock().await;
+    let Some(board) = chess_games.iter().find(|game| game.has_user(cmd.author_id)).map(|game| game.render(cmd.locale)) else {
+        return Err(no_active_game(cmd));
+    };
+    drop(chess_games); // drop mutex lock as soon as possible
+
+    cmd.reply(board).await?;
+    Ok(())
+}
+
+pub async fn board(cmd: &CommandContext<'_>) -> CommandResult {
+    let rw_lock = cmd.data.read().await;
+    let chess_games = rw_lock.get::<ChessGames>().expect("ChessGames not in TypeMap.").lock().await;
+    let Some(game) = chess_games.iter().find(|game| game.has_user(cmd.author_id)) else {
+        return Err(no_active_game(cmd));
+    };
+    let content = format!("{}\n{}", chess_status(game, cmd.locale), game.render(cmd.locale));
+    let components = chess_components(game, cmd.locale);
+    drop(chess_games); // drop mutex lock as soon as possible
+
+    cmd.send(Reply { content, components, ..Default::default() }).await?;
+    Ok(())
+}
+
+pub async fn new(cmd: &CommandContext<'_>) -> CommandResult {
+    let bot_id = settings::get().bot_id;
+    //Do this before locking mutex
+    let author_id = cmd.author_id;
+    let mut rng = cmd.rng().await;
+    let opponent_id = match cmd.mentions.choose(&mut rng) {
+        Some(user_id) => *user_id,
+        None => bot_id
+    };
+
+    let mut new_game = ChessGame::new_game_random_sides(author_id, opponent_id, &mut rng);
+    if new_game.white_id == bot_id {
+        let selected_move = chess::think(new_game.clone()).await;
+        new_game.make_move_unchecked(selected_move);
+    }
+
+    let rw_lock = cmd.data.read().await;
+    let mut chess_games = rw_lock.get::<ChessGames>().expect("ChessGames not in TypeMap.").lock().await;
+
+    //Check opponent isn't already in a game
+    if opponent_id != bot_id && chess_games.iter().any(|game| game.has_user(opponent_id) && !game.has_user(author_id)) {
+        drop(chess_games); // drop mutex lock as soon as possible
+        cmd.say(cmd.locale.format("opponent_busy", &[("command", "chess")])).await?;
+        return Ok(());
+    }
+
+    chess_games.retain(|game| !game.has_user(author_id));
+    chess_games.push(new_game.clone());
+    drop(chess_games); // drop mutex lock as soon as possible
+
+    cmd.reply(cmd.locale.format("chess_new_game", &[("white", &new_game.white_id.to_string()), ("black", &new_game.black_id.to_string())])).await?;
+    cmd.say(new_game.render(cmd.locale)).await?;
+    Ok(())
+}
+
+pub async fn make_move(cmd: &CommandContext<'_>) -> CommandResult {
+    let bot_id = settings::get().bot_id;
+    //stolen from https://stackoverflow.com/questions/57063777/remove-all-whitespace-from-a-string
+    let move_str: String = cmd.args.chars().filter(|c| !c.is_whitespace()).collect();
+    let author_id = cmd.author_id;
+
+    let rw_lock = cmd.data.read().await;
+    let mut chess_games = rw_lock.get::<ChessGames>().expect("ChessGames not in TypeMap.").lock().await;
+    let Some(game) = chess_games.iter_mut().find(|game| game.has_user(author_id)) else {
+        return Err(no_active_game(cmd));
+    };
+
+    //can't move on gameover
+    if game.gameover() {
+        cmd.say(cmd.text("game_over")).await?;
+        return Ok(());
+    }
+
+    if game.id_to_move() != author_id {
+        return Err(CommandError::User(String::from(cmd.text("not_your_turn"))));
+    }
+
+    let legal_move = game.legal_move_from_string(&move_str).map_err(|move_error| CommandError::User(move_error.message(cmd.locale)))?;
+    game.make_move_unchecked(legal_move);
+
+    let game = game.clone();
+    drop(chess_games); // drop mutex lock as soon as possible
+    drop(rw_lock);
+
+    let game = if !game.gameover() && game.id_to_move() == bot_id {
+        //The move is already made, so Hodgey has to answer it even if he can't react
+        if let Err(e) = cmd.react('👍').await {
+            error!("Error reacting to move: {e:?}");
+        }
+        match bot_move(cmd.data, game).await {
+            Some(game) => game,
+            None => return Ok(())
+        }
+    }
+    else {
+        game
+    };
+
+    cmd.say(chess_status(&game, cmd.locale)).await?;
+    cmd.say(game.render(cmd.locale)).await?;
+    Ok(())
+}
+
+/// Hodgey thinks without the games locked, then plays his move if the game is still where he left it.
+/// Returns the game after his move, or None if it was resigned or replaced while he was thinking.
+async fn bot_move(data: &RwLock<TypeMap>, position: ChessGame) -> Option<ChessGame> {
+    let selected_move = chess::think(position.clone()).await;
+    let rw_lock = data.read().await;
+    let mut chess_games = rw_lock.get::<ChessGames>().expect("ChessGames not in TypeMap.").lock().await;
+    let game = chess_games.iter_mut().find(|game| game.same_position(&position))?;
+    game.make_move_unchecked(selected_move);
+    Some(game.clone())
+}
+
+/// Gameover message, or whose turn it is.
+fn chess_status(game: &ChessGame, locale: Locale) -> String {
+    let id_to_move = game.id_to_move().to_string();
+    if game.gameover() {
+        game.get_gameover_message(locale).to_string()
+    }
+    else if game.is_in_check() {
+        locale.format("in_check", &[("player", &id_to_move)])
+    }
+    else {
+        locale.format("your_turn", &[("player", &id_to_move)])
+    }
+}
+
+/// Move select menus and Resign/Draw/Flip buttons for an interactive board.
+/// Custom ids carry the players so clicks can be matched back to the game.
+fn chess_components(game: &ChessGame, locale: Locale) -> Vec<CreateActionRow> {
+    if game.gameover() {
+        return Vec::new();
+    }
+    let game_key = format!("{}:{}", game.white_id, game.black_id);
+
+    //Pack the piece groups into as few menus as possible, each menu holds at most 25 options
+    let mut menus: Vec<(Vec<&str>, Vec<MoveOption>)> = Vec::new();
+    for (role, moves) in game.legal_moves_by_role() {
+        for chunk in moves.chunks(25) {
+            match menus.last_mut() {
+                Some((roles, options)) if options.len() + chunk.len() <= 25 => {
+                    roles.push(role_name(role, locale));
+                    options.extend_from_slice(chunk);
+                },
+                _ => menus.push((vec![role_name(role, locale)], chunk.to_vec()))
+            }
+        }
+    }
+
+    //Discord allows 5 rows and the buttons need one, anything left over can still be typed with "move"
+    let mut rows: Vec<CreateActionRow> = menus.into_iter().take(4).enumerate().map(|(index, (roles, options))| {
+        let options = options.into_iter().map(|(san, uci)| CreateSelectMenuOption::new(san, uci)).collect();
+        let menu = CreateSelectMenu::new(format!("chess_move:{game_key}:{index}"), CreateSelectMenuKind::String { options })
+            .placeholder(locale.format("move_menu", &[("pieces", &roles.join(" & "))]));
+        CreateActionRow::SelectMenu(menu)
+    }).collect();
+
+    rows.push(CreateActionRow::Buttons(vec![
+        CreateButton::new(format!("chess_resign:{game_key}")).label(locale.text("button_resign")).style(ButtonStyle::Danger),
+        CreateButton::new(format!("chess_draw:{game_key}")).label(locale.text("button_draw")).style(ButtonStyle::Secondary),
+        CreateButton::new(format!("chess_flip:{game_key}")).label(locale.text("button_flip")).style(ButtonStyle::Primary),
+    ]));
+    rows
+}
+
+fn ephemeral_response(content: &str) -> CreateInteractionResponse {
+    CreateInteractionResponse::Message(CreateInteractionResponseMessage::new().content(content).ephemeral(true))
+}
+
+fn board_update_response(game: &ChessGame, locale: Locale) -> CreateInteractionResponse {
+    CreateInteractionResponse::UpdateMessage(CreateInteractionResponseMessage::new()
+        .content(format!("{}\n{}", chess_status(game, locale), game.render(locale)))
+        .components(chess_components(game, locale)))
+}
+
+pub async fn chess_component_interaction(ctx: &Context, component: &ComponentInteraction) {
+    let bot_id = settings::get().bot_id;
+    let mut id_parts = component.data.custom_id.split(':');
+    let action = id_parts.next().unwrap_or_default();
+    let white_id = id_parts.next().and_then(|id| id.parse::<u64>().ok());
+    let black_id = id_parts.next().and_then(|id| id.parse::<u64>().ok());
+    let user_id = component.user.id.get();
+    let locale = Locale::from_code(&component.locale).unwrap_or(config::guild_config(&ctx.data, component.guild_id).await.locale);
+
+    let rw_lock = ctx.data.read().await;
+    let mut chess_games = rw_lock.get::<ChessGames>().expect("ChessGames not in TypeMap.").lock().await;
+    let game_index = chess_games.iter().position(|game| Some(game.white_id) == white_id && Some(game.black_id) == black_id);
+    //The position Hodgey has to answer, if it's his move next
+    let mut bot_to_move: Option<ChessGame> = None;
+
+    let response = match game_index {
+        None => ephemeral_response(locale.text("game_no_longer_active")),
+        Some(index) if !chess_games[index].has_user(user_id) => ephemeral_response(locale.text("not_your_game")),
+        Some(index) => {
+            let game = &mut chess_games[index];
+            let opponent_id = if game.white_id == user_id { game.black_id } else { game.white_id };
+            match action {
+                "chess_move" => {
+                    let selected_uci = match &component.data.kind {
+                        ComponentInteractionDataKind::StringSelect { values } => values.first().cloned(),
+                        _ => None
+                    };
+                    if game.gameover() {
+                        ephemeral_response(locale.text("game_over"))
+                    }
+                    else if game.id_to_move() != user_id {
+                        ephemeral_response(locale.text("not_your_turn"))
+                    }
+                    else if let Some(Ok(legal_move)) = selected_uci.map(|uci| game.legal_move_from_string(&uci)) {
+                        game.make_move_unchecked(legal_move);
+                        if !game.gameover() && game.id_to_move() == bot_id {
+                            bot_to_move = Some(game.clone());
+                        }
+                        board_update_response(game, locale)
+                    }
+                    else {
+                        ephemeral_response(locale.text("move_no_longer_legal"))
+                    }
+                },
+                "chess_resign" => {
+                    chess_games.remove(index);
+                    let content = match opponent_id {
+                        _ if opponent_id == bot_id => locale.text("i_win").to_string(),
+                        _ => locale.format("player_wins", &[("player", &opponent_id.to_string())])
+                    };
+                    CreateInteractionResponse::UpdateMessage(CreateInteractionResponseMessage::new().content(content).components(Vec::new()))
+                },
+                "chess_draw" => {
+                    if game.gameover() {
+                        ephemeral_response(locale.text("game_over"))
+                    }
+                    else if opponent_id == bot_id {
+                        ephemeral_response(locale.text("no_draws"))
+                    }
+                    else if game.draw_offer == Some(opponent_id) {
+                        chess_games.remove(index);
+                        CreateInteractionResponse::UpdateMessage(CreateInteractionResponseMessage::new().content(locale.text("draw_agreed")).components(Vec::new()))
+                    }
+                    else if game.draw_offer == Some(user_id) {
+                        ephemeral_response(locale.text("draw_already_offered"))
+                    }
+                    else {
+                        game.draw_offer = Some(user_id);
+                        CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+                            .content(locale.format("draw_offer", &[("player", &user_id.to_string()), ("opponent", &opponent_id.to_string())])))
+                    }
+                },
+                "chess_flip" => {
+                    game.board_flips = !game.board_flips;
+                    board_update_response(game, locale)
+                },
+                _ => ephemeral_response(locale.text("unknown_button"))
+            }
+        }
+    };
+    drop(chess_games); // drop mutex lock as soon as possible
+    drop(rw_lock);
+
+    let Some(position) = bot_to_move else {
+        if let Err(e) = component.create_response(&ctx.http, response).await {
+            error!("Error sending interaction response: {e:?}");
+        }
+        return;
+    };
+
+    //Discord gives up on interactions that aren't answered within 3 seconds and Hodgey can think for longer,
+    //so the click is acknowledged first and the board gets his move once he has found it
+    if let Err(e) = component.create_response(&ctx.http, CreateInteractionResponse::Acknowledge).await {
+        error!("Error sending interaction response: {e:?}");
+        return;
+    }
+    let result = match bot_move(&ctx.data, position).await {
+        Some(game) => component.edit_response(&ctx.http, EditInteractionResponse::new()
+            .content(format!("{}\n{}", chess_status(&game, locale), game.render(locale)))
+            .components(chess_components(&game, locale))).await.map(|_| ()),
+        None => component.create_followup(&ctx.http, CreateInteractionResponseFollowup::new()
+            .content(locale.text("game_no_longer_active"))
+            .ephemeral(true)).await.map(|_| ())
+    };
+    if let Err(e) = result {
+        error!("Error sending interaction response: {e:?}");
+    }
+}
+
+/// Suggests legal moves for "/chess move" as the user types, matching either SAN or UCI.
+pub async fn move_autocomplete(ctx: &Context, interaction: &CommandInteraction) {
+    let Some(focused) = interaction.data.autocomplete() else {
+        return;
+    };
+    let typed = focused.value.trim().to_lowercase();
+    let user_id = interaction.user.id.get();
+
+    let rw_lock = ctx.data.read().await;
+    let chess_games = rw_lock.get::<ChessGames>().expect("ChessGames not in TypeMap.").lock().await;
+    let suggestions: Vec<MoveOption> = match chess_games.iter().find(|game| game.has_user(user_id) && !game.gameover()) {
+        Some(game) => game.legal_moves_by_role().into_iter()
+            .flat_map(|(_, moves)| moves)
+            .filter(|(san, uci)| san.to_lowercase().starts_with(&typed) || uci.starts_with(&typed))
+            .take(25) //Discord shows at most 25 choices
+            .collect(),
+        None => Vec::new()
+    };
+    drop(chess_games); // drop mutex lock as soon as possible
+
+    let response = suggestions.into_iter()
+        .fold(CreateAutocompleteResponse::new(), |response, (san, _)| response.add_string_choice(san.clone(), san));
+    if let Err(e) = interaction.create_response(&ctx.http, CreateInteractionResponse::Autocomplete(response)).await {
+        error!("Error sending interaction response: {e:?}");
+    }
+}
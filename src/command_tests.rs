@@ -0,0 +1,586 @@
+//! Runs text commands through the same dispatch as `Bot::message`, with a fake transport that records replies.
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use rand::{SeedableRng, rngs::StdRng};
+use serenity::all::{ChannelId, GuildId, Permissions};
+use serenity::prelude::*;
+use crate::checkers::CheckersGames;
+use crate::chess::ChessGames;
+use crate::commands::{self, CommandContext, Incoming};
+use crate::config::{self, ConfigStore, GuildConfigs};
+use crate::content::{Content, Contents};
+use crate::connect_four::ConnectFour;
+use crate::games::TurnBasedGames;
+use crate::locale::Locale;
+use crate::permissions::MemberInfo;
+use crate::rate_limit::{Limit, RateLimiter, RateLimiters, RateLimits, SystemClock};
+use crate::rng::SharedRng;
+use crate::tic_tac_toe::TicTacToe;
+use crate::transport::{FakeTransport, Outgoing};
+use crate::{quotes, HODGEY_BOT_ID};
+
+const AUTHOR: u64 = 111;
+const FRIEND: u64 = 222;
+const CHANNEL: u64 = 333;
+const GUILD: u64 = 444;
+const SEED: u64 = 555;
+
+fn no_limits() -> RateLimits {
+    RateLimits {
+        per_user: Limit::new(u32::MAX, 1),
+        per_channel: Limit::new(u32::MAX, 1),
+        per_command: HashMap::new()
+    }
+}
+
+fn member(permissions: Permissions) -> MemberInfo {
+    MemberInfo { is_owner: false, permissions, role_ids: Vec::new() }
+}
+
+struct Harness {
+    data: RwLock<TypeMap>,
+    transport: FakeTransport
+}
+
+impl Harness {
+    fn with(permissions: Permissions, limits: RateLimits) -> Self {
+        Self::seeded(permissions, limits, SEED)
+    }
+
+    fn seeded(permissions: Permissions, limits: RateLimits, seed: u64) -> Self {
+        let mut data = TypeMap::new();
+        data.insert::<SharedRng>(Mutex::new(StdRng::seed_from_u64(seed)));
+        data.insert::<Contents>(Arc::new(Content::built_in()));
+        data.insert::<GuildConfigs>(Mutex::new(ConfigStore::in_memory()));
+        data.insert::<RateLimiters>(Mutex::new(RateLimiter::new(SystemClock, limits)));
+        data.insert::<ChessGames>(Mutex::new(Vec::new()));
+        data.insert::<CheckersGames>(Mutex::new(Vec::new()));
+        data.insert::<TurnBasedGames<ConnectFour>>(Mutex::new(Vec::new()));
+        data.insert::<TurnBasedGames<TicTacToe>>(Mutex::new(Vec::new()));
+
+        Self {
+            data: RwLock::new(data),
+            transport: FakeTransport {
+                member: Some(member(permissions)),
+                human_member_ids: vec![AUTHOR, FRIEND],
+                channels: vec![(ChannelId::new(CHANNEL), GuildId::new(GUILD)), (ChannelId::new(555), GuildId::new(GUILD)), (ChannelId::new(666), GuildId::new(777))],
+                ..Default::default()
+            }
+        }
+    }
+
+    fn new() -> Self {
+        Self::with(Permissions::SEND_MESSAGES, no_limits())
+    }
+
+    fn admin() -> Self {
+        Self::with(Permissions::ADMINISTRATOR, no_limits())
+    }
+
+    /// Sends a message as `author_id`, mentions are picked out of the content like Discord does
+    async fn send_as(&self, author_id: u64, content: &str) -> Vec<Outgoing> {
+        let mentions = content.split_whitespace()
+            .filter_map(|word| word.strip_prefix("<@")?.strip_suffix('>')?.parse().ok())
+            .collect();
+        let incoming = Incoming {
+            author_id,
+            channel_id: ChannelId::new(CHANNEL),
+            guild_id: Some(GuildId::new(GUILD)),
+            mentions,
+            content: content.to_string()
+        };
+        let config = config::guild_config(&self.data, incoming.guild_id).await;
+        //Any random quote is left out, posting it is up to the caller
+        crate::respond(&self.data, &self.transport, &incoming, &config).await;
+        self.transport.take_sent()
+    }
+
+    /// Runs a command by name the way a slash command does, with the arguments it was given
+    async fn run(&self, name: &str, args: &str) -> Vec<Outgoing> {
+        let command = commands::find_command_by_name(name).expect("No command with that name");
+        let cmd = CommandContext {
+            data: &self.data,
+            transport: &self.transport,
+            author_id: AUTHOR,
+            channel_id: ChannelId::new(CHANNEL),
+            guild_id: Some(GuildId::new(GUILD)),
+            mentions: Vec::new(),
+            args,
+            locale: Locale::English
+        };
+        commands::run(command, &cmd).await;
+        self.transport.take_sent()
+    }
+
+    async fn send(&self, content: &str) -> Vec<Outgoing> {
+        self.send_as(AUTHOR, content).await
+    }
+
+    /// Just the text of each reply and message
+    async fn texts(&self, content: &str) -> Vec<String> {
+        texts(self.send(content).await)
+    }
+}
+
+fn texts(sent: Vec<Outgoing>) -> Vec<String> {
+    sent.into_iter().filter_map(|outgoing| match outgoing {
+        Outgoing::Reply(reply) => Some(reply.content),
+        Outgoing::Say(content) => Some(content),
+        Outgoing::React(_) => None
+    }).collect()
+}
+
+fn single_text(sent: Vec<String>) -> String {
+    assert_eq!(sent.len(), 1, "expected one message, got {sent:?}");
+    sent.into_iter().next().unwrap()
+}
+
+//General commands
+
+#[tokio::test]
+async fn help_is_a_paged_embed() {
+    let sent = Harness::new().send("Hodgey Help").await;
+    let [Outgoing::Reply(reply)] = sent.as_slice() else { panic!("{sent:?}") };
+    assert_eq!(reply.embeds.len(), 1);
+    assert_eq!(reply.components.len(), 1);
+}
+
+#[tokio::test]
+async fn help_for_one_command() {
+    let sent = Harness::new().send("hodgey help spam").await;
+    let [Outgoing::Reply(reply)] = sent.as_slice() else { panic!("{sent:?}") };
+    assert_eq!(reply.embeds.len(), 1);
+    assert!(reply.components.is_empty());
+}
+
+#[tokio::test]
+async fn help_hides_admin_commands() {
+    let reply = single_text(Harness::new().texts("hodgey help config").await);
+    assert!(reply.starts_with("I don't have a command called"));
+
+    let sent = Harness::admin().send("hodgey help config").await;
+    let [Outgoing::Reply(reply)] = sent.as_slice() else { panic!("{sent:?}") };
+    assert_eq!(reply.embeds.len(), 1);
+}
+
+#[tokio::test]
+async fn joke_sends_embeds() {
+    let sent = Harness::new().send("hodgey joke").await;
+    assert!(!sent.is_empty());
+    for outgoing in sent {
+        let Outgoing::Reply(reply) = outgoing else { panic!("{outgoing:?}") };
+        assert_eq!(reply.embeds.len(), 1);
+    }
+}
+
+#[tokio::test]
+async fn val_agent() {
+    let agent = single_text(Harness::new().texts("hodgey val agent").await);
+    assert!(quotes::VAL_AGENTS.contains(&agent.as_str()));
+}
+
+#[tokio::test]
+async fn val_squad() {
+    let squad = single_text(Harness::new().texts("Hodgey Val Squad").await);
+    let agents: Vec<&str> = squad.lines().map(|line| line.strip_prefix("- ").unwrap()).collect();
+    assert_eq!(agents.len(), 5);
+    assert!(agents.iter().all(|agent| quotes::VAL_AGENTS.contains(agent)));
+}
+
+#[tokio::test]
+async fn clip_review() {
+    let review = single_text(Harness::new().texts("hodgey clip review").await);
+    assert!(review.starts_with("This clip is"));
+    assert_eq!(review.lines().count(), 8);
+    assert!(!review.contains('{'));
+}
+
+#[tokio::test]
+async fn see() {
+    assert_eq!(Harness::new().texts("see").await, ["said the blind man"]);
+}
+
+#[tokio::test]
+async fn one_plus_one() {
+    let harness = Harness::new();
+    assert_eq!(harness.texts("1+1").await, ["Two!"]);
+    assert_eq!(harness.texts("1 + 1").await, ["Two!"]);
+}
+
+#[tokio::test]
+async fn spam_needs_admin() {
+    assert_eq!(Harness::new().texts("spam 2 hello").await, [Locale::English.text("not_admin")]);
+}
+
+#[tokio::test]
+async fn spam_repeats() {
+    let harness = Harness::admin();
+    assert_eq!(harness.send("spam 3 Hello there").await, vec![Outgoing::Say(String::from("Hello there")); 3]);
+    assert_eq!(harness.texts("spam 6 hello").await, ["You are limited to 5"]);
+    assert_eq!(harness.texts("spam 2").await, ["I don't understand your message"]);
+    assert!(single_text(harness.texts("spam lots hello").await).starts_with("Usage: "));
+}
+
+#[tokio::test]
+async fn spam_can_be_opened_up() {
+    let admin = Harness::admin();
+    admin.send("hodgey config set admin only spam off").await;
+    //Same TypeMap, different member
+    let harness = Harness { data: admin.data, transport: FakeTransport { member: Some(member(Permissions::SEND_MESSAGES)), ..Default::default() } };
+    assert_eq!(harness.texts("spam 1 hello").await, ["hello"]);
+}
+
+#[tokio::test]
+async fn spam_cooldown() {
+    let harness = Harness::with(Permissions::ADMINISTRATOR, RateLimits::default());
+    assert_eq!(harness.texts("spam 1 hello").await, ["hello"]);
+    let cooldown = single_text(harness.texts("spam 1 hello").await);
+    assert!(cooldown.starts_with("Slow down!"));
+    //Only told once per cooldown
+    assert!(harness.texts("spam 1 hello").await.is_empty());
+}
+
+#[tokio::test]
+async fn pings() {
+    let harness = Harness::new();
+    assert_eq!(harness.texts("hey @everyone").await, ["Wow, you would ping @everyone!"]);
+    assert_eq!(harness.texts("hey @here").await, ["Wow, you would ping @here!"]);
+}
+
+#[tokio::test]
+async fn someone_pings_a_member() {
+    let ping = single_text(Harness::new().texts("hi @someone").await);
+    assert!(ping == format!("<@{AUTHOR}>") || ping == format!("<@{FRIEND}>"));
+}
+
+#[tokio::test]
+async fn someone_with_nobody_around() {
+    let harness = Harness::new();
+    let harness = Harness { transport: FakeTransport { human_member_ids: Vec::new(), ..harness.transport }, ..harness };
+    assert_eq!(harness.texts("@someone").await, ["There is no one else here"]);
+}
+
+#[tokio::test]
+async fn decide() {
+    let decision = single_text(Harness::new().texts("hodgey decide if I should sleep").await);
+    assert!(quotes::DECISION.contains(&decision.as_str()));
+}
+
+#[tokio::test]
+async fn slash_decide_quotes_the_question() {
+    let harness = Harness::new();
+    let decision = single_text(texts(harness.run("hodgey decide", "pizza or pasta?").await));
+    let decision = decision.strip_prefix("> pizza or pasta?\n").expect("question not quoted");
+    assert!(quotes::DECISION.contains(&decision));
+    let decision = single_text(texts(harness.run("hodgey decide", "").await));
+    assert!(quotes::DECISION.contains(&decision.as_str()));
+    let decision = single_text(texts(harness.run("hodgey decide", "should hodgey decide for me?").await));
+    assert!(decision.starts_with("> should hodgey decide for me?\n"), "{decision}");
+}
+
+#[tokio::test]
+async fn same_seed_same_responses() {
+    let conversation = ["hodgey val squad", "hodgey clip review", "hodgey decide", "I love chess", "new song", "tictactoe new"];
+    let mut replays = Vec::new();
+    for seed in [1, 1, 2] {
+        let harness = Harness::seeded(Permissions::SEND_MESSAGES, no_limits(), seed);
+        let mut sent = Vec::new();
+        for content in conversation {
+            sent.extend(harness.texts(content).await);
+        }
+        replays.push(sent);
+    }
+    assert_eq!(replays[0], replays[1]);
+    assert_ne!(replays[0], replays[2]);
+}
+
+//Responders
+
+#[tokio::test]
+async fn responders() {
+    let harness = Harness::new();
+    assert!(quotes::CHESS.contains(&single_text(harness.texts("I love chess").await).as_str()));
+    assert!(quotes::CHECKERS.contains(&single_text(harness.texts("anyone for checkers").await).as_str()));
+    assert_eq!(harness.texts("nice horse").await, ["It's not a horse, it's a knight"]);
+    assert_eq!(harness.texts("my castle").await, ["It's not a castle, it's a rook"]);
+    assert!(single_text(harness.texts("good book").await).starts_with("Have you read "));
+    let song = single_text(harness.texts("new song").await);
+    assert!(!song.is_empty() && !song.contains("{song}"));
+    assert!(!single_text(harness.texts("music time").await).is_empty());
+    assert!(single_text(harness.texts("movie night").await).starts_with("Have you seen "));
+    assert!(harness.texts("nothing to see here").await.is_empty());
+}
+
+#[tokio::test]
+async fn responders_can_be_switched_off() {
+    let harness = Harness::admin();
+    assert_eq!(harness.texts("hodgey config set responder horse off").await, ["**responder horse** is now off"]);
+    assert!(harness.texts("nice horse").await.is_empty());
+    harness.send("hodgey config reset responder horse").await;
+    assert_eq!(harness.texts("nice horse").await, ["It's not a horse, it's a knight"]);
+}
+
+#[tokio::test]
+async fn events_can_be_switched_off() {
+    let harness = Harness::admin();
+    assert_eq!(harness.texts("hodgey config get event ban").await, ["**event ban**: on"]);
+    assert_eq!(harness.texts("hodgey config set event role delete off").await, ["**event role delete** is now off"]);
+    assert!(!config::guild_config(&harness.data, Some(GuildId::new(GUILD))).await.event_enabled("role delete"));
+    assert!(single_text(harness.texts("hodgey config set event birthdays off").await).starts_with("There is no setting called"));
+    harness.send("hodgey config reset event role delete").await;
+    assert!(config::guild_config(&harness.data, Some(GuildId::new(GUILD))).await.event_enabled("role delete"));
+}
+
+//Settings
+
+#[tokio::test]
+async fn config_needs_admin() {
+    assert_eq!(Harness::new().texts("hodgey config").await, [Locale::English.text("not_admin")]);
+    assert_eq!(Harness::new().texts("set message channel").await, [Locale::English.text("not_admin")]);
+}
+
+#[tokio::test]
+async fn config_get_set_reset() {
+    let harness = Harness::admin();
+    assert!(single_text(harness.texts("hodgey config").await).starts_with("## Hodgey Settings"));
+    assert_eq!(harness.texts("hodgey config set random reply chance 5%").await, ["**random reply chance** is now 5%"]);
+    assert_eq!(harness.texts("hodgey config get random reply chance").await, ["**random reply chance**: 5%"]);
+    assert_eq!(harness.texts("hodgey config set random reply chance 200%").await, ["That value doesn't work, use a chance between 0% and 100%."]);
+    assert_eq!(harness.texts("hodgey config reset").await, ["All settings are back to normal."]);
+    assert_eq!(harness.texts("hodgey config get random reply chance").await, ["**random reply chance**: 1%"]);
+    assert!(single_text(harness.texts("hodgey config get colour").await).starts_with("There is no setting called"));
+}
+
+#[tokio::test]
+async fn language() {
+    let harness = Harness::admin();
+    assert_eq!(harness.texts("hodgey config set language klingon").await, ["That value doesn't work, use English (en) or Español (es)."]);
+    assert_eq!(harness.texts("hodgey config set language es").await, ["**language** is now Español"]);
+    assert_eq!(harness.texts("see").await, ["dijo el ciego"]);
+    assert_eq!(harness.texts("nice horse").await, ["No es un caballo, es un caballero"]);
+    assert!(single_text(harness.texts("good book").await).starts_with("¿Has leído "));
+    assert_eq!(harness.texts("hodgey config set language klingon").await, ["Ese valor no sirve, usa English (en) o Español (es)."]);
+    assert_eq!(harness.texts("chess show").await, [Locale::Spanish.text("no_chess_game")]);
+    assert_eq!(harness.texts("connect4 show").await, ["No tienes ninguna partida de Conecta 4 activa. Empieza una nueva con \"connect4 new\""]);
+    assert_eq!(harness.texts("hodgey trigger remove 3").await, ["No hay ningún trigger 3, \"Hodgey Trigger List\" los muestra todos"]);
+    harness.send("hodgey config reset language").await;
+    assert_eq!(harness.texts("see").await, ["said the blind man"]);
+}
+
+#[tokio::test]
+async fn message_channel() {
+    let harness = Harness::admin();
+    assert_eq!(harness.texts("set message channel <#555>").await, ["I'll send my random messages to <#555> from now on."]);
+    assert_eq!(harness.texts("hodgey config get message channel").await, ["**message channel**: <#555>"]);
+    assert_eq!(harness.texts("Remove Message Channel").await, ["Message channel removed. I'll talk wherever things happen."]);
+    assert_eq!(harness.texts("remove message channel").await, ["There is no message channel to remove."]);
+    assert_eq!(harness.texts("set message channel").await, [format!("I'll send my random messages to <#{CHANNEL}> from now on.")]);
+
+    //Channels in other servers, or that can't be seen, are refused
+    assert_eq!(harness.texts("set message channel <#666>").await, ["That channel isn't in this server"]);
+    assert_eq!(harness.texts("hodgey config set message channel <#666>").await, ["That channel isn't in this server"]);
+    assert_eq!(harness.texts("hodgey config set message channel <#999>").await, ["That channel isn't in this server"]);
+    assert_eq!(harness.texts("hodgey config get message channel").await, [format!("**message channel**: <#{CHANNEL}>")]);
+    assert_eq!(harness.texts("hodgey config set message channel <#555>").await, ["**message channel** is now <#555>"]);
+}
+
+#[tokio::test]
+async fn custom_triggers() {
+    let harness = Harness::admin();
+    assert_eq!(harness.texts("hodgey trigger").await, ["There are no custom triggers yet. Add one with \"Hodgey Trigger Add\"."]);
+    assert_eq!(harness.texts("hodgey trigger add word Pizza => I love pizza {author}").await, ["Added trigger 1. word \"pizza\" => I love pizza {author}"]);
+    assert_eq!(harness.texts("anyone want PIZZA?").await, [format!("I love pizza <@{AUTHOR}>")]);
+    assert!(harness.texts("pizzazz").await.is_empty());
+    //Built in commands come first
+    harness.send("hodgey trigger add contains see => Nope").await;
+    assert_eq!(harness.texts("see").await, ["said the blind man"]);
+
+    assert!(single_text(harness.texts("hodgey trigger add regex (oops => hi").await).starts_with("That regex doesn't work"));
+    assert!(single_text(harness.texts("hodgey trigger add sometimes pizza => hi").await).starts_with("Usage: "));
+    assert!(single_text(harness.texts("hodgey trigger list").await).starts_with("## Custom Triggers\n1. word \"pizza\""));
+    //Triggers survive resetting the other settings
+    harness.send("hodgey config reset").await;
+    assert_eq!(harness.texts("hodgey trigger remove 1").await, ["Removed word \"pizza\" => I love pizza {author}"]);
+    assert!(harness.texts("pizza").await.is_empty());
+    assert_eq!(Harness::new().texts("hodgey trigger list").await, [Locale::English.text("not_admin")]);
+}
+
+//Chess
+
+async fn chess_move_for(harness: &Harness, user_id: u64) -> String {
+    let rw_lock = harness.data.read().await;
+    let chess_games = rw_lock.get::<ChessGames>().unwrap().lock().await;
+    let game = chess_games.iter().find(|game| game.has_user(user_id)).unwrap();
+    assert_eq!(game.id_to_move(), user_id);
+    game.legal_moves_by_role()[0].1[0].0.clone()
+}
+
+#[tokio::test]
+async fn chess_without_a_game() {
+    let harness = Harness::new();
+    for command in ["chess show", "chess board", "chess resign", "move e4", "toggle coordinates", "toggle board flip", "toggle text board"] {
+        assert_eq!(harness.texts(command).await, [Locale::English.text("no_chess_game")], "{command}");
+    }
+}
+
+#[tokio::test]
+async fn chess_against_hodgey() {
+    let harness = Harness::new();
+    let sent = harness.texts("chess new").await;
+    assert_eq!(sent.len(), 2);
+    assert!(sent[0].starts_with("New game created!"));
+    assert!(sent[0].contains(&format!("<@{HODGEY_BOT_ID}>")));
+
+    //Hodgey has already moved if he is white, so it is always our turn
+    let selected_move = chess_move_for(&harness, AUTHOR).await;
+    let sent = harness.send(&format!("move {selected_move}")).await;
+    assert_eq!(sent[0], Outgoing::React('👍'));
+    let sent = texts(sent);
+    assert_eq!(sent.len(), 2);
+    assert_eq!(sent[0], format!("Your turn <@{AUTHOR}>!"));
+
+    assert_eq!(harness.texts("chess show").await.len(), 1);
+    assert_eq!(harness.texts("chess resign").await, ["I WIN!"]);
+    assert_eq!(harness.texts("chess show").await, [Locale::English.text("no_chess_game")]);
+}
+
+#[tokio::test]
+async fn chess_bad_moves() {
+    let harness = Harness::new();
+    harness.send("chess new").await;
+    assert_eq!(harness.texts("move e5e4").await, ["That's an illegal move"]);
+    assert_eq!(harness.texts("move hello").await, ["I don't understand the move you are trying to make"]);
+    //Still our turn
+    chess_move_for(&harness, AUTHOR).await;
+}
+
+#[tokio::test]
+async fn chess_against_a_friend() {
+    let harness = Harness::new();
+    let sent = harness.texts(&format!("chess new <@{FRIEND}>")).await;
+    assert!(sent[0].contains(&format!("<@{FRIEND}>")) && sent[0].contains(&format!("<@{AUTHOR}>")));
+
+    let rw_lock = harness.data.read().await;
+    let white_id = rw_lock.get::<ChessGames>().unwrap().lock().await[0].white_id;
+    drop(rw_lock);
+    let black_id = if white_id == AUTHOR { FRIEND } else { AUTHOR };
+
+    assert_eq!(texts(harness.send_as(black_id, "move e5").await), ["It is not your turn"]);
+    let sent = harness.send_as(white_id, "move e4").await;
+    //No thumbs up when there is no bot to think
+    assert!(!sent.contains(&Outgoing::React('👍')));
+    assert_eq!(texts(sent)[0], format!("Your turn <@{black_id}>!"));
+
+    //Nobody else can pull a player out of the middle of a game
+    let sent = texts(harness.send_as(999, &format!("chess new <@{FRIEND}>")).await);
+    assert!(single_text(sent).starts_with("Opponent is in another game"));
+    assert_eq!(texts(harness.send_as(black_id, "chess resign").await), [format!("<@{white_id}> wins!")]);
+}
+
+#[tokio::test]
+async fn chess_toggles() {
+    let harness = Harness::new();
+    harness.send("chess new").await;
+    assert_eq!(harness.texts("toggle coordinates").await, ["Coordinates disabled."]);
+    assert_eq!(harness.texts("toggle coordinates").await, ["Coordinates enabled."]);
+    assert_eq!(harness.texts("toggle board flip").await, ["Board flip enabled."]);
+    assert_eq!(harness.texts("toggle text board").await, ["Text board enabled."]);
+    assert!(single_text(harness.texts("chess show").await).starts_with("```"));
+}
+
+#[tokio::test]
+async fn chess_board_has_menus_and_buttons() {
+    let harness = Harness::new();
+    harness.send("chess new").await;
+    let sent = harness.send("chess board").await;
+    let [Outgoing::Reply(reply)] = sent.as_slice() else { panic!("{sent:?}") };
+    //At least one move menu plus the button row
+    assert!(reply.components.len() >= 2);
+    assert!(reply.content.starts_with(&format!("Your turn <@{AUTHOR}>!")));
+}
+
+#[tokio::test]
+async fn new_game_needs_the_whole_word() {
+    let harness = Harness::new();
+    harness.send("chess newbie here").await;
+    harness.send("checkers news").await;
+    let rw_lock = harness.data.read().await;
+    assert!(rw_lock.get::<ChessGames>().unwrap().lock().await.is_empty());
+    assert!(rw_lock.get::<CheckersGames>().unwrap().lock().await.is_empty());
+    drop(rw_lock);
+
+    assert!(harness.texts("chess new ").await[0].starts_with("New game created!"));
+    assert!(harness.texts(&format!("checkers new <@{FRIEND}>")).await[0].starts_with("New checkers game created!"));
+}
+
+//Checkers
+
+#[tokio::test]
+async fn checkers_against_hodgey() {
+    let harness = Harness::new();
+    assert_eq!(harness.texts("checkers show").await, [Locale::English.text("no_checkers_game")]);
+
+    let sent = harness.texts("checkers new").await;
+    assert!(sent[0].starts_with("New checkers game created!"));
+    assert_eq!(sent.len(), 2);
+
+    let selected_move = {
+        let rw_lock = harness.data.read().await;
+        let checkers_games = rw_lock.get::<CheckersGames>().unwrap().lock().await;
+        checkers_games[0].find_best_move(&mut StdRng::seed_from_u64(SEED)).to_string()
+    };
+    let sent = harness.texts(&format!("checkers move {selected_move}")).await;
+    assert_eq!(sent[0], format!("Your turn <@{AUTHOR}>!"));
+    assert_eq!(harness.texts("checkers move 1-2").await.len(), 1);
+
+    assert_eq!(harness.texts("checkers show").await.len(), 1);
+    assert_eq!(harness.texts("checkers surrender").await, ["I WIN! Still a bad game though."]);
+}
+
+//Connect Four and Tic-Tac-Toe
+
+#[tokio::test]
+async fn connect_four_against_hodgey() {
+    let harness = Harness::new();
+    let sent = harness.texts("connect4 new").await;
+    assert!(sent[0].starts_with("New Connect Four game created!"));
+    //Every column has room this early on
+    let sent = harness.texts("connect4 move 4").await;
+    assert_eq!(sent[0], format!("Your turn <@{AUTHOR}>!"));
+    assert_eq!(harness.texts("connect4 move 9").await.len(), 1);
+    assert_eq!(harness.texts("connect4 show").await.len(), 1);
+    assert_eq!(harness.texts("connect4 resign").await, ["I WIN!"]);
+    assert!(single_text(harness.texts("connect4 show").await).starts_with("You don't have an active Connect Four game"));
+    assert!(single_text(harness.texts("connect4 dance").await).starts_with("Usage: "));
+}
+
+#[tokio::test]
+async fn tic_tac_toe_against_a_friend() {
+    let harness = Harness::new();
+    harness.send(&format!("tictactoe new <@{FRIEND}>")).await;
+    let first_player = {
+        let rw_lock = harness.data.read().await;
+        let games = rw_lock.get::<TurnBasedGames<TicTacToe>>().unwrap().lock().await;
+        games[0].id_to_move()
+    };
+    let second_player = if first_player == AUTHOR { FRIEND } else { AUTHOR };
+
+    assert_eq!(texts(harness.send_as(second_player, "tictactoe move 5").await), ["It is not your turn"]);
+    assert_eq!(texts(harness.send_as(first_player, "tictactoe move 5").await)[0], format!("Your turn <@{second_player}>!"));
+    assert_eq!(texts(harness.send_as(second_player, "tictactoe move 5").await).len(), 1);
+    assert_eq!(texts(harness.send_as(second_player, "tictactoe surrender").await), [format!("<@{first_player}> wins!")]);
+}
+
+#[tokio::test]
+async fn cooldown_reply_is_polite() {
+    let limits = RateLimits {
+        per_user: Limit { capacity: 1, refill_every: Duration::from_secs(60) },
+        ..no_limits()
+    };
+    let harness = Harness::with(Permissions::SEND_MESSAGES, limits);
+    assert_eq!(harness.texts("see").await, ["said the blind man"]);
+    assert_eq!(harness.texts("1+1").await, ["Slow down! Try again in 60 seconds."]);
+    //Responders just go quiet instead of complaining
+    assert!(harness.texts("nice horse").await.is_empty());
+}
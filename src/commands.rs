@@ -0,0 +1,632 @@
+use std::sync::Arc;
+use rand::rngs::StdRng;
+use serenity::all::{ChannelId, GuildId};
+use serenity::futures::future::BoxFuture;
+use serenity::model::channel::Message;
+use serenity::prelude::*;
+use tracing::error;
+use crate::config::GuildConfig;
+use crate::content::{self, Content, QuoteKind};
+use crate::keywords::Keyword;
+use crate::locale::Locale;
+use crate::rate_limit::{self, RateLimiters};
+use crate::transport::{Outgoing, Reply, Transport};
+use crate::{chess_commands, config_commands, custom_triggers, game_commands, general_commands, help, permissions, rng, templates};
+
+
+pub type CommandResult = Result<(), CommandError>;
+pub type Handler = for<'a> fn(&'a CommandContext<'a>) -> BoxFuture<'a, CommandResult>;
+
+/// Wraps an `async fn(&CommandContext<'_>) -> CommandResult` so it can be stored in the registry.
+macro_rules! handler {
+    ($function:path) => {{
+        fn wrapper<'a>(cmd: &'a CommandContext<'a>) -> BoxFuture<'a, CommandResult> {
+            Box::pin($function(cmd))
+        }
+        wrapper as Handler
+    }};
+}
+
+pub enum Trigger {
+    /// The whole message, e.g. "hodgey help"
+    Exact(&'static str),
+    /// The start of the message, whatever follows is passed to the handler as arguments
+    Prefix(&'static str),
+    /// A whole word or phrase anywhere in the message, see `keywords`
+    Keyword(Keyword)
+}
+
+impl Trigger {
+    /// Lower values win. Exact commands beat prefixes, which beat keywords found anywhere in the message.
+    const fn precedence(&self) -> usize {
+        match self {
+            Self::Exact(_) => 0,
+            Self::Prefix(_) => 1,
+            Self::Keyword(_) => 2
+        }
+    }
+
+    pub const fn text(&self) -> &'static str {
+        match self {
+            Self::Exact(text) | Self::Prefix(text) => text,
+            Self::Keyword(keyword) => keyword.text
+        }
+    }
+
+    fn matches(&self, msg_lower: &str) -> bool {
+        match self {
+            Self::Exact(text) => msg_lower == *text,
+            Self::Prefix(text) => msg_lower.starts_with(text),
+            Self::Keyword(keyword) => keyword.matches(msg_lower)
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    Everyone,
+    /// See `permissions::is_bot_admin`
+    Admin
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    General,
+    Chess,
+    Checkers,
+    Games,
+    Responses
+}
+
+impl Category {
+    /// In the order they appear in help
+    pub const ALL: [Category; 5] = [Self::General, Self::Chess, Self::Checkers, Self::Games, Self::Responses];
+
+    pub const fn title_key(&self) -> &'static str {
+        match self {
+            Self::General => "category_general",
+            Self::Chess => "category_chess",
+            Self::Checkers => "category_checkers",
+            Self::Games => "category_games",
+            Self::Responses => "category_responses"
+        }
+    }
+
+    pub fn title(&self, locale: Locale) -> &'static str {
+        locale.text(self.title_key())
+    }
+
+    /// What to type after "hodgey help" to get this category
+    pub const fn keyword(&self) -> &'static str {
+        match self {
+            Self::General => "general",
+            Self::Chess => "chess",
+            Self::Checkers => "checkers",
+            Self::Games => "games",
+            Self::Responses => "responses"
+        }
+    }
+}
+
+pub struct Command {
+    pub name: &'static str,
+    /// The first trigger is the main one, the rest are aliases
+    pub triggers: &'static [Trigger],
+    /// The locale key for what it does, see `Command::help_text`
+    pub help: &'static str,
+    pub category: Category,
+    pub permission: Permission,
+    pub handler: Handler
+}
+
+impl Command {
+    pub fn help_text(&self, locale: Locale) -> &'static str {
+        locale.text(self.help)
+    }
+
+    /// Responders react to a word anywhere in a message rather than being asked for
+    pub fn is_responder(&self) -> bool {
+        self.triggers.iter().any(|trigger| matches!(trigger, Trigger::Keyword(_)))
+    }
+}
+
+/// A text message, as far as commands care
+pub struct Incoming {
+    pub author_id: u64,
+    pub channel_id: ChannelId,
+    pub guild_id: Option<GuildId>,
+    pub mentions: Vec<u64>,
+    pub content: String
+}
+
+impl Incoming {
+    pub fn from_message(msg: &Message) -> Self {
+        Self {
+            author_id: msg.author.id.get(),
+            channel_id: msg.channel_id,
+            guild_id: msg.guild_id,
+            mentions: msg.mentions.iter().map(|user| user.id.get()).collect(),
+            content: msg.content.clone()
+        }
+    }
+}
+
+pub struct CommandContext<'a> {
+    /// The bot's shared state, the same TypeMap as `Context::data`
+    pub data: &'a RwLock<TypeMap>,
+    pub transport: &'a dyn Transport,
+    pub author_id: u64,
+    pub channel_id: ChannelId,
+    pub guild_id: Option<GuildId>,
+    /// Users pinged in the message, or picked in a slash command's user option
+    pub mentions: Vec<u64>,
+    /// Whatever followed a prefix trigger, in its original case
+    pub args: &'a str,
+    /// What to reply in, see `locale::Locale`
+    pub locale: Locale
+}
+
+impl CommandContext<'_> {
+    /// Replies to the message, or answers the slash command.
+    pub async fn reply(&self, content: impl Into<String>) -> serenity::Result<()> {
+        self.transport.send(Outgoing::Reply(Reply::text(content))).await
+    }
+
+    /// Posts in the channel, or answers the slash command.
+    pub async fn say(&self, content: impl Into<String>) -> serenity::Result<()> {
+        self.transport.send(Outgoing::Say(content.into())).await
+    }
+
+    /// Replies with embeds and components.
+    pub async fn send(&self, reply: Reply) -> serenity::Result<()> {
+        self.transport.send(Outgoing::Reply(reply)).await
+    }
+
+    /// Reacts to the message. Slash commands have no message to react to so nothing happens.
+    pub async fn react(&self, reaction: char) -> serenity::Result<()> {
+        self.transport.send(Outgoing::React(reaction)).await
+    }
+
+    /// A message in the language for this command
+    pub fn text(&self, key: &'static str) -> &'static str {
+        self.locale.text(key)
+    }
+
+    /// Randomness for this command, see `rng::fork`
+    pub async fn rng(&self) -> StdRng {
+        rng::fork(self.data).await
+    }
+
+    /// The built in quotes and any packs, see `content::Content::load`
+    pub async fn content(&self) -> Arc<Content> {
+        content::get(self.data).await
+    }
+
+    /// A random quote of that kind, filled in if it's a template
+    pub async fn quote(&self, kind: QuoteKind) -> String {
+        templates::quote(self.data, kind, self.transport, self.author_id, self.channel_id.get()).await
+    }
+
+    /// Fills in placeholders and choices, see `templates::Template`
+    pub async fn render(&self, text: &str) -> String {
+        let content = self.content().await;
+        templates::render(text, &content, self.transport, self.author_id, self.channel_id.get(), &mut self.rng().await).await
+    }
+}
+
+pub enum CommandError {
+    /// Something the user did wrong, sent back to them as a reply
+    User(String),
+    /// The arguments didn't make sense, the command's help is sent back
+    Usage,
+    Discord(serenity::Error)
+}
+
+impl From<serenity::Error> for CommandError {
+    fn from(e: serenity::Error) -> Self {
+        Self::Discord(e)
+    }
+}
+
+pub const COMMANDS: &[Command] = &[
+    Command {
+        name: "hodgey help",
+        triggers: &[Trigger::Exact("hodgey help"), Trigger::Prefix("hodgey help ")],
+        help: "help_help",
+        category: Category::General,
+        permission: Permission::Everyone,
+        handler: handler!(help::help)
+    },
+    Command {
+        name: "hodgey trigger",
+        triggers: &[Trigger::Exact("hodgey trigger"), Trigger::Prefix("hodgey trigger ")],
+        help: "help_trigger",
+        category: Category::General,
+        permission: Permission::Admin,
+        handler: handler!(custom_triggers::trigger)
+    },
+    Command {
+        name: "hodgey config",
+        triggers: &[Trigger::Exact("hodgey config"), Trigger::Prefix("hodgey config ")],
+        help: "help_config",
+        category: Category::General,
+        permission: Permission::Admin,
+        handler: handler!(config_commands::config)
+    },
+    Command {
+        name: "set message channel",
+        triggers: &[Trigger::Exact("set message channel"), Trigger::Prefix("set message channel ")],
+        help: "help_set_message_channel",
+        category: Category::General,
+        permission: Permission::Admin,
+        handler: handler!(config_commands::set_message_channel)
+    },
+    Command {
+        name: "remove message channel",
+        triggers: &[Trigger::Exact("remove message channel")],
+        help: "help_remove_message_channel",
+        category: Category::General,
+        permission: Permission::Admin,
+        handler: handler!(config_commands::remove_message_channel)
+    },
+    Command {
+        name: "hodgey joke",
+        triggers: &[Trigger::Exact("hodgey joke")],
+        help: "help_joke",
+        category: Category::General,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::joke)
+    },
+    Command {
+        name: "hodgey val agent",
+        triggers: &[Trigger::Exact("hodgey val agent")],
+        help: "help_val_agent",
+        category: Category::General,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::val_agent)
+    },
+    Command {
+        name: "hodgey val squad",
+        triggers: &[Trigger::Exact("hodgey val squad")],
+        help: "help_val_squad",
+        category: Category::General,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::val_squad)
+    },
+    Command {
+        name: "hodgey clip review",
+        triggers: &[Trigger::Exact("hodgey clip review")],
+        help: "help_clip_review",
+        category: Category::General,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::clip_review)
+    },
+    Command {
+        name: "see",
+        triggers: &[Trigger::Exact("see")],
+        help: "help_see",
+        category: Category::Responses,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::see)
+    },
+    Command {
+        name: "1+1",
+        triggers: &[Trigger::Exact("1+1"), Trigger::Exact("1 + 1")],
+        help: "help_maths",
+        category: Category::Responses,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::one_plus_one)
+    },
+    Command {
+        name: "chess resign",
+        triggers: &[Trigger::Exact("chess resign"), Trigger::Exact("chess surrender")],
+        help: "help_chess_resign",
+        category: Category::Chess,
+        permission: Permission::Everyone,
+        handler: handler!(chess_commands::resign)
+    },
+    Command {
+        name: "toggle coordinates",
+        triggers: &[Trigger::Exact("toggle coordinates")],
+        help: "help_toggle_coordinates",
+        category: Category::Chess,
+        permission: Permission::Everyone,
+        handler: handler!(chess_commands::toggle_coordinates)
+    },
+    Command {
+        name: "toggle board flip",
+        triggers: &[Trigger::Exact("toggle board flip")],
+        help: "help_toggle_board_flip",
+        category: Category::Chess,
+        permission: Permission::Everyone,
+        handler: handler!(chess_commands::toggle_board_flip)
+    },
+    Command {
+        name: "toggle text board",
+        triggers: &[Trigger::Exact("toggle text board")],
+        help: "help_toggle_text_board",
+        category: Category::Chess,
+        permission: Permission::Everyone,
+        handler: handler!(chess_commands::toggle_text_board)
+    },
+    Command {
+        name: "chess show",
+        triggers: &[Trigger::Exact("chess show")],
+        help: "help_chess_show",
+        category: Category::Chess,
+        permission: Permission::Everyone,
+        handler: handler!(chess_commands::show)
+    },
+    Command {
+        name: "chess board",
+        triggers: &[Trigger::Exact("chess board")],
+        help: "help_chess_board",
+        category: Category::Chess,
+        permission: Permission::Everyone,
+        handler: handler!(chess_commands::board)
+    },
+    Command {
+        name: "chess new",
+        triggers: &[Trigger::Exact("chess new"), Trigger::Prefix("chess new ")],
+        help: "help_chess_new",
+        category: Category::Chess,
+        permission: Permission::Everyone,
+        handler: handler!(chess_commands::new)
+    },
+    Command {
+        name: "move",
+        triggers: &[Trigger::Prefix("move ")],
+        help: "help_move",
+        category: Category::Chess,
+        permission: Permission::Everyone,
+        handler: handler!(chess_commands::make_move)
+    },
+    Command {
+        name: "checkers resign",
+        triggers: &[Trigger::Exact("checkers resign"), Trigger::Exact("checkers surrender")],
+        help: "help_checkers_resign",
+        category: Category::Checkers,
+        permission: Permission::Everyone,
+        handler: handler!(game_commands::checkers_resign)
+    },
+    Command {
+        name: "checkers show",
+        triggers: &[Trigger::Exact("checkers show")],
+        help: "help_checkers_show",
+        category: Category::Checkers,
+        permission: Permission::Everyone,
+        handler: handler!(game_commands::checkers_show)
+    },
+    Command {
+        name: "checkers new",
+        triggers: &[Trigger::Exact("checkers new"), Trigger::Prefix("checkers new ")],
+        help: "help_checkers_new",
+        category: Category::Checkers,
+        permission: Permission::Everyone,
+        handler: handler!(game_commands::checkers_new)
+    },
+    Command {
+        name: "checkers move",
+        triggers: &[Trigger::Prefix("checkers move ")],
+        help: "help_checkers_move",
+        category: Category::Checkers,
+        permission: Permission::Everyone,
+        handler: handler!(game_commands::checkers_move)
+    },
+    Command {
+        name: "connect4",
+        triggers: &[Trigger::Prefix("connect4 ")],
+        help: "help_connect4",
+        category: Category::Games,
+        permission: Permission::Everyone,
+        handler: handler!(game_commands::connect_four)
+    },
+    Command {
+        name: "tictactoe",
+        triggers: &[Trigger::Prefix("tictactoe ")],
+        help: "help_tictactoe",
+        category: Category::Games,
+        permission: Permission::Everyone,
+        handler: handler!(game_commands::tic_tac_toe)
+    },
+    Command {
+        name: "spam",
+        triggers: &[Trigger::Prefix("spam ")],
+        help: "help_spam",
+        category: Category::General,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::spam)
+    },
+    Command {
+        name: "@everyone",
+        triggers: &[Trigger::Keyword(Keyword::new("@everyone"))],
+        help: "help_everyone",
+        category: Category::Responses,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::ping_everyone)
+    },
+    Command {
+        name: "@here",
+        triggers: &[Trigger::Keyword(Keyword::new("@here"))],
+        help: "help_here",
+        category: Category::Responses,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::ping_here)
+    },
+    Command {
+        name: "@someone",
+        triggers: &[Trigger::Keyword(Keyword::new("@someone"))],
+        help: "help_someone",
+        category: Category::General,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::someone)
+    },
+    Command {
+        name: "hodgey decide",
+        triggers: &[Trigger::Keyword(Keyword::new("hodgey decide"))],
+        help: "help_decide",
+        category: Category::General,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::decide)
+    },
+    Command {
+        name: "chess",
+        triggers: &[Trigger::Keyword(Keyword::new("chess"))],
+        help: "help_chess",
+        category: Category::Responses,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::chess_quote)
+    },
+    Command {
+        name: "checkers",
+        triggers: &[Trigger::Keyword(Keyword::new("checkers"))],
+        help: "help_checkers",
+        category: Category::Responses,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::checkers_quote)
+    },
+    Command {
+        name: "horse",
+        triggers: &[Trigger::Keyword(Keyword::new("horse").with_plurals().ignoring(&["horse around"]))],
+        help: "help_horse",
+        category: Category::Responses,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::horse)
+    },
+    Command {
+        name: "castle",
+        triggers: &[Trigger::Keyword(Keyword::new("castle").with_plurals().ignoring(&["white castle"]))],
+        help: "help_castle",
+        category: Category::Responses,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::castle)
+    },
+    Command {
+        name: "book",
+        triggers: &[Trigger::Keyword(Keyword::new("book").with_plurals().ignoring(&["book a", "book an", "book it"]))],
+        help: "help_book",
+        category: Category::Responses,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::book)
+    },
+    Command {
+        name: "music",
+        triggers: &[Trigger::Keyword(Keyword::new("music")), Trigger::Keyword(Keyword::new("song").with_plurals())],
+        help: "help_music",
+        category: Category::Responses,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::music)
+    },
+    Command {
+        name: "movie",
+        triggers: &[Trigger::Keyword(Keyword::new("movie").with_plurals())],
+        help: "help_movie",
+        category: Category::Responses,
+        permission: Permission::Everyone,
+        handler: handler!(general_commands::movie)
+    }
+];
+
+/// Finds the command for a message. Exact triggers beat prefixes (longest prefix first),
+/// which beat keywords, and ties between keywords go to whichever is registered first.
+/// Also returns the matched trigger so the handler can be given its arguments.
+pub fn find_command(msg_lower: &str) -> Option<(&'static Command, &'static Trigger)> {
+    let mut best: Option<(&'static Command, &'static Trigger)> = None;
+    for command in COMMANDS {
+        for trigger in command.triggers {
+            if !trigger.matches(msg_lower) {
+                continue;
+            }
+            let better = match best {
+                None => true,
+                Some((_, best_trigger)) => match (trigger, best_trigger) {
+                    (Trigger::Prefix(text), Trigger::Prefix(best_text)) => text.len() > best_text.len(),
+                    _ => trigger.precedence() < best_trigger.precedence()
+                }
+            };
+            if better {
+                best = Some((command, trigger));
+            }
+        }
+    }
+
+    best
+}
+
+async fn has_permission(cmd: &CommandContext<'_>, permission: Permission) -> bool {
+    match permission {
+        Permission::Everyone => true,
+        Permission::Admin => permissions::is_admin(cmd).await
+    }
+}
+
+/// Runs the matching command, if there is one. Returns whether a command handled the message.
+/// Responders the server has switched off are treated as if they didn't match.
+pub async fn dispatch(data: &RwLock<TypeMap>, transport: &dyn Transport, incoming: &Incoming, config: &GuildConfig) -> bool {
+    let Some((command, trigger)) = find_command(&incoming.content.to_lowercase()) else {
+        return false;
+    };
+    if !config.responder_enabled(command.name) {
+        return false;
+    }
+
+    let cmd = CommandContext {
+        data,
+        transport,
+        author_id: incoming.author_id,
+        channel_id: incoming.channel_id,
+        guild_id: incoming.guild_id,
+        mentions: incoming.mentions.clone(),
+        args: match trigger {
+            Trigger::Exact(text) | Trigger::Prefix(text) => incoming.content.get(text.len()..).unwrap_or_default(),
+            //A keyword can be anywhere in the message, so nothing counts as its arguments
+            Trigger::Keyword(_) => ""
+        },
+        locale: config.locale
+    };
+    run(command, &cmd).await;
+    true
+}
+
+pub fn find_command_by_name(name: &str) -> Option<&'static Command> {
+    COMMANDS.iter().find(|command| command.name == name)
+}
+
+/// Checks cooldowns and permissions, runs the command and reports any error the same way whatever the source.
+pub async fn run(command: &Command, cmd: &CommandContext<'_>) {
+    let rw_lock = cmd.data.read().await;
+    let mut rate_limiter = rw_lock.get::<RateLimiters>().expect("RateLimiters not in TypeMap.").lock().await;
+    let rate_limit = rate_limiter.check(cmd.author_id, cmd.channel_id.get(), command.name);
+    drop(rate_limiter); // drop mutex lock as soon as possible
+    drop(rw_lock);
+
+    if let Err(cooldown) = rate_limit {
+        //Responders just go quiet, only people asking for something on purpose get told
+        if !cooldown.already_warned && !command.is_responder() {
+            if let Err(e) = cmd.reply(rate_limit::cooldown_message(&cooldown, cmd.locale)).await {
+                error!("Error sending message: {e:?}");
+            }
+        }
+        return;
+    }
+
+    let result = if has_permission(cmd, command.permission).await {
+        (command.handler)(cmd).await
+    }
+    else {
+        Err(CommandError::User(String::from(cmd.text("not_admin"))))
+    };
+
+    let reply = match result {
+        Ok(()) => return,
+        Err(CommandError::User(reply)) => reply,
+        Err(CommandError::Usage) => cmd.locale.format("usage", &[("help", command.help_text(cmd.locale))]),
+        Err(CommandError::Discord(e)) => {
+            error!("Error running \"{}\": {e:?}", command.name);
+            return;
+        }
+    };
+    if let Err(e) = cmd.reply(reply).await {
+        error!("Error sending message: {e:?}");
+    }
+}
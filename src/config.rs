@@ -0,0 +1,396 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+use serde::{Deserialize, Deserializer, Serialize};
+use serenity::all::GuildId;
+use serenity::prelude::*;
+use tracing::{error, info};
+use crate::commands::COMMANDS;
+use crate::custom_triggers::CustomTrigger;
+use crate::locale::Locale;
+use crate::settings;
+
+const DEFAULT_CONFIG_PATH: &str = "guild_config.json";
+
+/// Settings for one server. Anything missing from the saved file falls back to the default.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildConfig {
+    /// Keyword responders that have been switched off, by command name
+    pub disabled_responders: BTreeSet<String>,
+    /// Server events Hodgey doesn't post about, from `EVENTS`
+    pub disabled_events: BTreeSet<String>,
+    /// Chance of a random reply to any message, from 0 to 1. `None` follows `default_random_reply_chance` in hodgey.toml.
+    pub random_reply_chance: Option<f64>,
+    /// Channels Hodgey is allowed to talk in, empty means everywhere
+    pub allowed_channels: BTreeSet<u64>,
+    /// The bot Hodgey gets mad at. `None` follows `default_rival_bot_id` in hodgey.toml, `Some(None)` is no rival at all.
+    #[serde(deserialize_with = "deserialize_override", skip_serializing_if = "Option::is_none")]
+    pub rival_bot_id: Option<Option<u64>>,
+    /// Members with this role count as admins for Hodgey, on top of Administrator and Manage Server
+    pub bot_admin_role: Option<u64>,
+    /// Whether only admins can use "spam"
+    pub admin_only_spam: bool,
+    /// Where event messages and random replies go, see `message_channel::say`
+    pub message_channel: Option<u64>,
+    /// What to do when there is no message channel or it can't be posted in
+    pub message_channel_fallback: MessageChannelFallback,
+    /// Changed with "hodgey trigger" rather than "hodgey config"
+    pub custom_triggers: Vec<CustomTrigger>,
+    /// What language Hodgey replies in, slash commands use the user's own instead
+    pub locale: Locale
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageChannelFallback {
+    /// Post wherever the event happened, like Hodgey always used to
+    EventChannel,
+    /// Don't post at all
+    Silent
+}
+
+/// A saved `null` is an override to nothing, only a missing field means the default
+fn deserialize_override<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Option<u64>>, D::Error> {
+    Option::<u64>::deserialize(deserializer).map(Some)
+}
+
+impl Default for GuildConfig {
+    fn default() -> Self {
+        Self {
+            disabled_responders: BTreeSet::new(),
+            disabled_events: BTreeSet::new(),
+            random_reply_chance: None,
+            allowed_channels: BTreeSet::new(),
+            rival_bot_id: None,
+            bot_admin_role: None,
+            admin_only_spam: true,
+            message_channel: None,
+            message_channel_fallback: MessageChannelFallback::EventChannel,
+            custom_triggers: Vec::new(),
+            locale: Locale::default()
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    UnknownKey(String),
+    /// The locale key for what the value should look like
+    InvalidValue(&'static str)
+}
+
+impl ConfigError {
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            Self::UnknownKey(key) => locale.format("unknown_setting", &[("key", key)]),
+            Self::InvalidValue(expected) => locale.format("invalid_value", &[("expected", locale.text(expected))])
+        }
+    }
+}
+
+/// Things happening in a server that Hodgey posts about, each can be switched off with "event <name>"
+pub const EVENTS: [&str; 8] = ["member join", "member leave", "new emoji", "deleted emoji", "role create", "role delete", "ban", "unban"];
+
+/// The keyword responders that can be switched off, any command triggered by a word anywhere in the message
+pub fn responder_names() -> impl Iterator<Item = &'static str> {
+    COMMANDS.iter()
+        .filter(|command| command.is_responder())
+        .map(|command| command.name)
+}
+
+/// "<#123>", "<@!123>", "<@&123>" and "123" all become 123. Discord never uses 0.
+pub fn parse_id(text: &str) -> Option<u64> {
+    text.trim_start_matches(['<', '#', '@', '!', '&']).trim_end_matches('>').parse().ok().filter(|id| *id != 0)
+}
+
+fn parse_toggle(value: &str) -> Result<bool, ConfigError> {
+    match value {
+        "on" | "true" | "yes" | "enable" | "enabled" => Ok(true),
+        "off" | "false" | "no" | "disable" | "disabled" => Ok(false),
+        _ => Err(ConfigError::InvalidValue("expected_toggle"))
+    }
+}
+
+impl GuildConfig {
+    pub fn channel_allowed(&self, channel_id: u64) -> bool {
+        self.allowed_channels.is_empty() || self.allowed_channels.contains(&channel_id)
+    }
+
+    pub fn responder_enabled(&self, name: &str) -> bool {
+        !self.disabled_responders.contains(name)
+    }
+
+    pub fn event_enabled(&self, name: &str) -> bool {
+        !self.disabled_events.contains(name)
+    }
+
+    /// The server's own chance, or the current default from hodgey.toml
+    pub fn random_reply_chance(&self) -> f64 {
+        self.random_reply_chance.unwrap_or_else(|| settings::get().default_random_reply_chance)
+    }
+
+    /// The server's own rival, or the current default from hodgey.toml
+    pub fn rival_bot_id(&self) -> Option<u64> {
+        self.rival_bot_id.unwrap_or_else(|| settings::get().default_rival_bot_id)
+    }
+
+    /// Every setting name, responders are "responder <name>" and events "event <name>"
+    pub fn keys() -> Vec<String> {
+        let mut keys = ["random reply chance", "allowed channels", "rival bot", "bot admin role", "admin only spam", "message channel", "message channel fallback", "language"].map(String::from).to_vec();
+        keys.extend(responder_names().map(|name| format!("responder {name}")));
+        keys.extend(EVENTS.map(|name| format!("event {name}")));
+        keys
+    }
+
+    pub fn get(&self, key: &str) -> Result<String, ConfigError> {
+        match key {
+            "random reply chance" => Ok(format!("{}%", (self.random_reply_chance() * 10000.0).round() / 100.0)),
+            "allowed channels" if self.allowed_channels.is_empty() => Ok(String::from("everywhere")),
+            "allowed channels" => Ok(self.allowed_channels.iter().map(|id| format!("<#{id}>")).collect::<Vec<String>>().join(" ")),
+            "rival bot" => Ok(self.rival_bot_id().map_or(String::from("none"), |id| format!("<@{id}>"))),
+            "bot admin role" => Ok(self.bot_admin_role.map_or(String::from("none"), |id| format!("<@&{id}>"))),
+            "admin only spam" => Ok(String::from(if self.admin_only_spam { "on" } else { "off" })),
+            "message channel" => Ok(self.message_channel.map_or(String::from("none"), |id| format!("<#{id}>"))),
+            "message channel fallback" => Ok(String::from(match self.message_channel_fallback {
+                MessageChannelFallback::EventChannel => "event channel",
+                MessageChannelFallback::Silent => "silent"
+            })),
+            "language" => Ok(String::from(self.locale.name())),
+            _ => match (key.strip_prefix("responder "), key.strip_prefix("event ")) {
+                (Some(name), _) if responder_names().any(|responder| responder == name) => {
+                    Ok(String::from(if self.responder_enabled(name) { "on" } else { "off" }))
+                },
+                (_, Some(name)) if EVENTS.contains(&name) => {
+                    Ok(String::from(if self.event_enabled(name) { "on" } else { "off" }))
+                },
+                _ => Err(ConfigError::UnknownKey(key.to_string()))
+            }
+        }
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), ConfigError> {
+        match key {
+            "random reply chance" => {
+                //Accept both "5%" and "0.05"
+                let chance = match value.strip_suffix('%') {
+                    Some(percent) => percent.trim().parse::<f64>().map(|percent| percent / 100.0),
+                    None => value.parse::<f64>()
+                };
+                match chance {
+                    Ok(chance) if (0.0..=1.0).contains(&chance) => self.random_reply_chance = Some(chance),
+                    _ => return Err(ConfigError::InvalidValue("expected_chance"))
+                }
+            },
+            "allowed channels" => {
+                if value == "all" || value == "everywhere" {
+                    self.allowed_channels.clear();
+                }
+                else {
+                    let Some(channels) = value.split_whitespace().map(parse_id).collect::<Option<BTreeSet<u64>>>() else {
+                        return Err(ConfigError::InvalidValue("expected_channels"));
+                    };
+                    self.allowed_channels = channels;
+                }
+            },
+            "rival bot" => {
+                if value == "none" {
+                    self.rival_bot_id = Some(None);
+                }
+                else {
+                    let Some(id) = parse_id(value) else {
+                        return Err(ConfigError::InvalidValue("expected_bot"));
+                    };
+                    self.rival_bot_id = Some(Some(id));
+                }
+            },
+            "bot admin role" => {
+                if value == "none" {
+                    self.bot_admin_role = None;
+                }
+                else {
+                    let Some(id) = parse_id(value) else {
+                        return Err(ConfigError::InvalidValue("expected_role"));
+                    };
+                    self.bot_admin_role = Some(id);
+                }
+            },
+            "admin only spam" => self.admin_only_spam = parse_toggle(value)?,
+            "message channel" => {
+                if value == "none" {
+                    self.message_channel = None;
+                }
+                else {
+                    let Some(id) = parse_id(value) else {
+                        return Err(ConfigError::InvalidValue("expected_channel"));
+                    };
+                    self.message_channel = Some(id);
+                }
+            },
+            "message channel fallback" => {
+                self.message_channel_fallback = match value {
+                    "event channel" => MessageChannelFallback::EventChannel,
+                    "silent" => MessageChannelFallback::Silent,
+                    _ => return Err(ConfigError::InvalidValue("expected_fallback"))
+                };
+            },
+            "language" => self.locale = Locale::from_code(value).ok_or(ConfigError::InvalidValue("expected_language"))?,
+            _ => match (key.strip_prefix("responder "), key.strip_prefix("event ")) {
+                (Some(name), _) if responder_names().any(|responder| responder == name) => {
+                    if parse_toggle(value)? {
+                        self.disabled_responders.remove(name);
+                    }
+                    else {
+                        self.disabled_responders.insert(name.to_string());
+                    }
+                },
+                (_, Some(name)) if EVENTS.contains(&name) => {
+                    if parse_toggle(value)? {
+                        self.disabled_events.remove(name);
+                    }
+                    else {
+                        self.disabled_events.insert(name.to_string());
+                    }
+                },
+                _ => return Err(ConfigError::UnknownKey(key.to_string()))
+            }
+        }
+        Ok(())
+    }
+
+    pub fn reset(&mut self, key: &str) -> Result<(), ConfigError> {
+        let default = Self::default();
+        match key {
+            "random reply chance" => self.random_reply_chance = default.random_reply_chance,
+            "allowed channels" => self.allowed_channels = default.allowed_channels,
+            "rival bot" => self.rival_bot_id = default.rival_bot_id,
+            "bot admin role" => self.bot_admin_role = default.bot_admin_role,
+            "admin only spam" => self.admin_only_spam = default.admin_only_spam,
+            "message channel" => self.message_channel = default.message_channel,
+            "message channel fallback" => self.message_channel_fallback = default.message_channel_fallback,
+            "language" => self.locale = default.locale,
+            _ => match (key.strip_prefix("responder "), key.strip_prefix("event ")) {
+                (Some(name), _) if responder_names().any(|responder| responder == name) => {
+                    self.disabled_responders.remove(name);
+                },
+                (_, Some(name)) if EVENTS.contains(&name) => {
+                    self.disabled_events.remove(name);
+                },
+                _ => return Err(ConfigError::UnknownKey(key.to_string()))
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Every server's settings, saved as JSON whenever an admin changes something.
+pub struct ConfigStore {
+    /// Nowhere to save to when None
+    path: Option<PathBuf>,
+    guilds: HashMap<u64, GuildConfig>
+}
+
+impl ConfigStore {
+    /// Loads from `HODGEY_CONFIG_PATH`, or guild_config.json if that isn't set.
+    /// A missing file just means nothing has been configured yet.
+    pub fn load() -> Self {
+        let path = PathBuf::from(std::env::var("HODGEY_CONFIG_PATH").unwrap_or_else(|_| String::from(DEFAULT_CONFIG_PATH)));
+        let guilds: HashMap<u64, GuildConfig> = match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                error!("Error reading guild config {}: {e:?}", path.display());
+                HashMap::new()
+            }),
+            Err(_) => {
+                info!("No guild config at {}, using defaults", path.display());
+                HashMap::new()
+            }
+        };
+        for guild_config in guilds.values() {
+            guild_config.custom_triggers.iter().for_each(CustomTrigger::compile);
+        }
+        Self { path: Some(path), guilds }
+    }
+
+    /// Starts empty and never touches the disk, for tests and the console
+    pub fn in_memory() -> Self {
+        Self { path: None, guilds: HashMap::new() }
+    }
+
+    pub fn get(&self, guild_id: u64) -> GuildConfig {
+        self.guilds.get(&guild_id).cloned().unwrap_or_default()
+    }
+
+    pub fn get_mut(&mut self, guild_id: u64) -> &mut GuildConfig {
+        self.guilds.entry(guild_id).or_default()
+    }
+
+    /// Forgets the message channel if it was deleted. Returns whether it was.
+    pub fn remove_message_channel(&mut self, guild_id: u64, channel_id: u64) -> bool {
+        match self.guilds.get_mut(&guild_id) {
+            Some(guild_config) if guild_config.message_channel == Some(channel_id) => {
+                guild_config.message_channel = None;
+                true
+            },
+            _ => false
+        }
+    }
+
+    /// Callers keep the store locked until this is done, so saves can't finish out of order
+    pub async fn save(&self) -> std::io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let contents = serde_json::to_string_pretty(&self.guilds)?;
+        //Write then rename so a crash can't leave half a file
+        let temp_path = path.with_extension("json.tmp");
+        tokio::fs::write(&temp_path, contents).await?;
+        tokio::fs::rename(temp_path, path).await
+    }
+}
+
+pub struct GuildConfigs;
+
+impl TypeMapKey for GuildConfigs {
+    type Value = Mutex<ConfigStore>;
+}
+
+/// The settings for a server, or the defaults for DMs.
+pub async fn guild_config(data: &RwLock<TypeMap>, guild_id: Option<GuildId>) -> GuildConfig {
+    let Some(guild_id) = guild_id else {
+        return GuildConfig::default();
+    };
+    let rw_lock = data.read().await;
+    let config_store = rw_lock.get::<GuildConfigs>().expect("GuildConfigs not in TypeMap.").lock().await;
+    config_store.get(guild_id.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_are_not_copied_into_servers() {
+        let mut config = GuildConfig::default();
+        assert_eq!(config.random_reply_chance(), settings::get().default_random_reply_chance);
+        assert_eq!(config.rival_bot_id(), settings::get().default_rival_bot_id);
+        let saved = serde_json::to_string(&config).unwrap();
+        assert!(saved.contains("\"random_reply_chance\":null") && !saved.contains("rival_bot_id"), "{saved}");
+
+        config.set("rival bot", "none").unwrap();
+        config.set("random reply chance", "5%").unwrap();
+        let loaded: GuildConfig = serde_json::from_str(&serde_json::to_string(&config).unwrap()).unwrap();
+        assert_eq!(loaded.rival_bot_id, Some(None));
+        assert_eq!(loaded.random_reply_chance, Some(0.05));
+
+        config.reset("rival bot").unwrap();
+        config.reset("random reply chance").unwrap();
+        assert_eq!((config.rival_bot_id, config.random_reply_chance), (None, None));
+    }
+
+    #[test]
+    fn older_saved_configs_still_load() {
+        let loaded: GuildConfig = serde_json::from_str(r#"{"random_reply_chance":0.5,"rival_bot_id":123,"admin_only_spam":false}"#).unwrap();
+        assert_eq!((loaded.random_reply_chance(), loaded.rival_bot_id()), (0.5, Some(123)));
+        let loaded: GuildConfig = serde_json::from_str(r#"{"rival_bot_id":null}"#).unwrap();
+        assert_eq!(loaded.rival_bot_id(), None);
+    }
+}
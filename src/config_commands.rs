@@ -0,0 +1,127 @@
+use serenity::all::{ChannelId, GuildId};
+use tracing::error;
+use crate::commands::{CommandContext, CommandError, CommandResult};
+use crate::config::{self, ConfigError, GuildConfig, GuildConfigs};
+
+/// "hodgey config", "hodgey config get <setting>", "hodgey config set <setting> <value>" and "hodgey config reset [setting]"
+pub async fn config(cmd: &CommandContext<'_>) -> CommandResult {
+    let Some(guild_id) = cmd.guild_id else {
+        return Err(CommandError::User(String::from(cmd.text("server_only"))));
+    };
+    let command = cmd.args.trim().to_lowercase();
+    let (subcommand, rest) = command.split_once(' ').unwrap_or((&command, ""));
+    if let Some(channel_id) = rest.strip_prefix("message channel ").filter(|_| subcommand == "set").and_then(config::parse_id) {
+        check_channel(cmd, guild_id, channel_id).await?;
+    }
+
+    let rw_lock = cmd.data.read().await;
+    let mut config_store = rw_lock.get::<GuildConfigs>().expect("GuildConfigs not in TypeMap.").lock().await;
+    let guild_config = config_store.get_mut(guild_id.get());
+
+    let (response, changed) = match subcommand {
+        "" | "get" if rest.is_empty() => {
+            let settings = GuildConfig::keys().into_iter()
+                .map(|key| format!("**{key}**: {}", guild_config.get(&key).unwrap_or_default()))
+                .collect::<Vec<String>>()
+                .join("\n");
+            (cmd.locale.format("settings_list", &[("settings", &settings)]), false)
+        },
+        "get" => match guild_config.get(rest) {
+            Ok(value) => (format!("**{rest}**: {value}"), false),
+            Err(config_error) => (config_error.message(cmd.locale), false)
+        },
+        "set" => {
+            //Setting names can have spaces, so find the longest one the message starts with
+            let key = GuildConfig::keys().into_iter()
+                .filter(|key| rest.starts_with(key.as_str()))
+                .max_by_key(|key| key.len());
+            match key {
+                None => (ConfigError::UnknownKey(rest.to_string()).message(cmd.locale), false),
+                Some(key) => match guild_config.set(&key, rest[key.len()..].trim()) {
+                    Ok(()) => (cmd.locale.format("setting_now", &[("key", &key), ("value", &guild_config.get(&key).unwrap_or_default())]), true),
+                    Err(config_error) => (config_error.message(cmd.locale), false)
+                }
+            }
+        },
+        "reset" if rest.is_empty() => {
+            //Custom triggers have their own command to remove them
+            let custom_triggers = std::mem::take(&mut guild_config.custom_triggers);
+            *guild_config = GuildConfig { custom_triggers, ..Default::default() };
+            (String::from(cmd.text("settings_reset")), true)
+        },
+        "reset" => match guild_config.reset(rest) {
+            Ok(()) => (cmd.locale.format("setting_reset", &[("key", rest), ("value", &guild_config.get(rest).unwrap_or_default())]), true),
+            Err(config_error) => (config_error.message(cmd.locale), false)
+        },
+        _ => return Err(CommandError::Usage)
+    };
+
+    if changed {
+        if let Err(e) = config_store.save().await {
+            error!("Error saving guild config: {e:?}");
+        }
+    }
+    drop(config_store); // drop mutex lock as soon as possible
+
+    cmd.reply(response).await?;
+    Ok(())
+}
+
+/// Event messages go to the message channel, so it has to be in the server it is set for
+async fn check_channel(cmd: &CommandContext<'_>, guild_id: GuildId, channel_id: u64) -> CommandResult {
+    if cmd.transport.channel_in_guild(guild_id, ChannelId::new(channel_id)).await {
+        Ok(())
+    }
+    else {
+        Err(CommandError::User(String::from(cmd.text("channel_not_in_server"))))
+    }
+}
+
+/// "set message channel" uses the channel it was sent in, or a mentioned channel
+pub async fn set_message_channel(cmd: &CommandContext<'_>) -> CommandResult {
+    let Some(guild_id) = cmd.guild_id else {
+        return Err(CommandError::User(String::from(cmd.text("server_only"))));
+    };
+    let channel_id = match cmd.args.trim() {
+        "" => cmd.channel_id.get(),
+        channel => config::parse_id(channel).ok_or(CommandError::Usage)?
+    };
+    check_channel(cmd, guild_id, channel_id).await?;
+
+    let rw_lock = cmd.data.read().await;
+    let mut config_store = rw_lock.get::<GuildConfigs>().expect("GuildConfigs not in TypeMap.").lock().await;
+    config_store.get_mut(guild_id.get()).message_channel = Some(channel_id);
+    if let Err(e) = config_store.save().await {
+        error!("Error saving guild config: {e:?}");
+    }
+    drop(config_store); // drop mutex lock as soon as possible
+
+    cmd.reply(cmd.locale.format("message_channel_set", &[("channel", &channel_id.to_string())])).await?;
+    Ok(())
+}
+
+pub async fn remove_message_channel(cmd: &CommandContext<'_>) -> CommandResult {
+    let Some(guild_id) = cmd.guild_id else {
+        return Err(CommandError::User(String::from(cmd.text("server_only"))));
+    };
+
+    let rw_lock = cmd.data.read().await;
+    let mut config_store = rw_lock.get::<GuildConfigs>().expect("GuildConfigs not in TypeMap.").lock().await;
+    let guild_config = config_store.get_mut(guild_id.get());
+    let had_channel = guild_config.message_channel.take().is_some();
+    let fallback = guild_config.get("message channel fallback").unwrap_or_default();
+    if had_channel {
+        if let Err(e) = config_store.save().await {
+            error!("Error saving guild config: {e:?}");
+        }
+    }
+    drop(config_store); // drop mutex lock as soon as possible
+
+    let response = match (had_channel, fallback.as_str()) {
+        (false, _) => cmd.text("no_message_channel"),
+        (true, "silent") => cmd.text("message_channel_removed_silent"),
+        (true, _) => cmd.text("message_channel_removed")
+    };
+    cmd.reply(response).await?;
+    Ok(())
+}
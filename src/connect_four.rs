@@ -0,0 +1,173 @@
+use crate::games::TurnBasedGame;
+
+const COLUMNS: usize = 7;
+const ROWS: usize = 6;
+
+/// Columns are numbered 1-7, row 0 is the bottom
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct ConnectFour {
+    cells: [[Option<usize>; ROWS]; COLUMNS],
+    moves_played: usize
+}
+
+impl ConnectFour {
+    fn column_height(&self, column: usize) -> usize {
+        self.cells[column].iter().take_while(|cell| cell.is_some()).count()
+    }
+
+    /// Every line of four cells on the board
+    fn windows() -> impl Iterator<Item = [(usize, usize); 4]> {
+        let directions: [(isize, isize); 4] = [(1, 0), (0, 1), (1, 1), (1, -1)];
+        directions.into_iter().flat_map(|(column_step, row_step)| {
+            (0..COLUMNS).flat_map(move |column| (0..ROWS).filter_map(move |row| {
+                let mut window = [(0, 0); 4];
+                for (i, cell) in window.iter_mut().enumerate() {
+                    let cell_column = column as isize + column_step * i as isize;
+                    let cell_row = row as isize + row_step * i as isize;
+                    if !(0..COLUMNS as isize).contains(&cell_column) || !(0..ROWS as isize).contains(&cell_row) {
+                        return None;
+                    }
+                    *cell = (cell_column as usize, cell_row as usize);
+                }
+                Some(window)
+            }))
+        })
+    }
+}
+
+impl TurnBasedGame for ConnectFour {
+    type Move = usize;
+
+    const NAME: &'static str = "connect_four";
+    const PIECES: [&'static str; 2] = ["🔴", "🟡"];
+    const SEARCH_DEPTH: u32 = 6;
+
+    fn current_player(&self) -> usize {
+        self.moves_played % 2
+    }
+
+    fn legal_moves(&self) -> Vec<usize> {
+        if self.winner().is_some() {
+            return Vec::new();
+        }
+        //Middle columns first so the search prunes more
+        [3, 2, 4, 1, 5, 0, 6].into_iter().filter(|column| self.column_height(*column) < ROWS).collect()
+    }
+
+    fn parse_move(&self, input: &str) -> Result<usize, &'static str> {
+        let column = match input.trim().parse::<usize>() {
+            Ok(column @ 1..=COLUMNS) => column - 1,
+            _ => return Err("pick_column")
+        };
+        if self.column_height(column) == ROWS {
+            return Err("column_full");
+        }
+        Ok(column)
+    }
+
+    fn play(&mut self, selected_move: &usize) {
+        let row = self.column_height(*selected_move);
+        self.cells[*selected_move][row] = Some(self.current_player());
+        self.moves_played += 1;
+    }
+
+    fn winner(&self) -> Option<usize> {
+        Self::windows().find_map(|window| {
+            let first = self.cells[window[0].0][window[0].1]?;
+            window.iter().all(|(column, row)| self.cells[*column][*row] == Some(first)).then_some(first)
+        })
+    }
+
+    /// Rewards lines that only one player can still complete
+    fn evaluate(&self) -> i32 {
+        let me = self.current_player();
+        Self::windows().map(|window| {
+            let mine = window.iter().filter(|(column, row)| self.cells[*column][*row] == Some(me)).count();
+            let theirs = window.iter().filter(|(column, row)| self.cells[*column][*row] == Some(1 - me)).count();
+            match (mine, theirs) {
+                (count, 0) => [0, 1, 5, 50, 0][count],
+                (0, count) => -[0, 1, 5, 50, 0][count],
+                _ => 0
+            }
+        }).sum()
+    }
+
+    fn render(&self) -> String {
+        let mut result = String::from("1️⃣2️⃣3️⃣4️⃣5️⃣6️⃣7️⃣\n");
+        for row in (0..ROWS).rev() {
+            for column in 0..COLUMNS {
+                result += match self.cells[column][row] {
+                    Some(player) => Self::PIECES[player],
+                    None => "⚫"
+                };
+            }
+            result += "\n";
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Plays columns in order, numbered like the commands
+    fn play(columns: &[usize]) -> ConnectFour {
+        let mut game = ConnectFour::default();
+        for column in columns {
+            let selected_move = game.parse_move(&column.to_string()).unwrap();
+            game.play(&selected_move);
+        }
+        game
+    }
+
+    #[test]
+    fn horizontal_win() {
+        let game = play(&[1, 1, 2, 2, 3, 3, 4]);
+        assert_eq!(game.winner(), Some(0));
+        assert!(game.gameover());
+        assert!(game.legal_moves().is_empty());
+    }
+
+    #[test]
+    fn vertical_win() {
+        let game = play(&[1, 2, 1, 2, 1, 2, 7, 2]);
+        assert_eq!(game.winner(), Some(1));
+    }
+
+    #[test]
+    fn diagonal_wins() {
+        //Red climbs from column 1 to column 4
+        let game = play(&[1, 2, 2, 3, 3, 4, 3, 4, 4, 7, 4]);
+        assert_eq!(game.winner(), Some(0));
+        //and the same thing mirrored, from column 7 down to column 4
+        let game = play(&[7, 6, 6, 5, 5, 4, 5, 4, 4, 1, 4]);
+        assert_eq!(game.winner(), Some(0));
+        assert_eq!(play(&[7, 6, 6, 5, 5, 4, 5, 4, 4, 1]).winner(), None);
+    }
+
+    #[test]
+    fn full_board_is_a_draw() {
+        let mut game = ConnectFour::default();
+        for column in 0..COLUMNS {
+            for row in 0..ROWS {
+                //Pairs of rows swap colours, and so does every column, so nothing lines up
+                game.cells[column][row] = Some((column + row / 2) % 2);
+            }
+        }
+        game.moves_played = COLUMNS * ROWS;
+        assert_eq!(game.winner(), None);
+        assert!(game.legal_moves().is_empty());
+        assert!(game.gameover());
+    }
+
+    #[test]
+    fn bad_columns() {
+        let game = play(&[1, 1, 1, 1, 1, 1]);
+        assert_eq!(game.parse_move("1"), Err("column_full"));
+        assert_eq!(game.parse_move("0"), Err("pick_column"));
+        assert_eq!(game.parse_move("8"), Err("pick_column"));
+        assert_eq!(game.parse_move("two"), Err("pick_column"));
+        assert_eq!(game.parse_move(" 2 "), Ok(1));
+    }
+}
@@ -0,0 +1,386 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::Deserialize;
+use serenity::prelude::*;
+use tracing::{error, info};
+use crate::templates::Template;
+use crate::{jokes, quotes};
+
+const DEFAULT_PACKS_PATH: &str = "packs";
+/// Discord won't send more than this many fields in an embed
+const MAX_EMBED_FIELDS: usize = 25;
+const MAX_FIELD_NAME_LENGTH: usize = 256;
+const MAX_FIELD_VALUE_LENGTH: usize = 1024;
+
+/// The lists Hodgey picks things to say from. Each one is a table in a pack file under its `key`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum QuoteKind {
+    Mee6,
+    Busy,
+    Chess,
+    Checkers,
+    Books,
+    SongStarts,
+    Songs,
+    Movies,
+    Random,
+    NewChannel,
+    Decision,
+    ValAgents,
+    ClipReview,
+    BadSynonyms,
+    NewMember,
+    MemberLeft,
+    NewEmoji,
+    DeletedEmoji,
+    NewRole,
+    DeletedRole,
+    Ban,
+    Unban
+}
+
+impl QuoteKind {
+    pub const ALL: [Self; 22] = [Self::Mee6, Self::Busy, Self::Chess, Self::Checkers, Self::Books, Self::SongStarts, Self::Songs, Self::Movies, Self::Random, Self::NewChannel, Self::Decision, Self::ValAgents, Self::ClipReview, Self::BadSynonyms, Self::NewMember, Self::MemberLeft, Self::NewEmoji, Self::DeletedEmoji, Self::NewRole, Self::DeletedRole, Self::Ban, Self::Unban];
+
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Mee6 => "mee6",
+            Self::Busy => "busy",
+            Self::Chess => "chess",
+            Self::Checkers => "checkers",
+            Self::Books => "books",
+            Self::SongStarts => "song_starts",
+            Self::Songs => "songs",
+            Self::Movies => "movies",
+            Self::Random => "random",
+            Self::NewChannel => "new_channel",
+            Self::Decision => "decision",
+            Self::ValAgents => "val_agents",
+            Self::ClipReview => "clip_review",
+            Self::BadSynonyms => "bad_synonyms",
+            Self::NewMember => "new_member",
+            Self::MemberLeft => "member_left",
+            Self::NewEmoji => "new_emoji",
+            Self::DeletedEmoji => "deleted_emoji",
+            Self::NewRole => "new_role",
+            Self::DeletedRole => "deleted_role",
+            Self::Ban => "ban",
+            Self::Unban => "unban"
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.key() == key)
+    }
+
+    const fn built_in(self) -> &'static [&'static str] {
+        match self {
+            Self::Mee6 => quotes::MEE6,
+            Self::Busy => quotes::BUSY,
+            Self::Chess => quotes::CHESS,
+            Self::Checkers => quotes::CHECKERS,
+            Self::Books => quotes::BOOKS,
+            Self::SongStarts => quotes::SONG_STARTS,
+            Self::Songs => quotes::SONGS,
+            Self::Movies => quotes::MOVIES,
+            Self::Random => quotes::RANDOM,
+            Self::NewChannel => quotes::NEW_CHANNEL,
+            Self::Decision => quotes::DECISION,
+            Self::ValAgents => quotes::VAL_AGENTS,
+            Self::ClipReview => quotes::CLIP_REVIEW,
+            Self::BadSynonyms => quotes::BAD_SYNONYMS,
+            Self::NewMember => quotes::NEW_MEMBER,
+            Self::MemberLeft => quotes::MEMBER_LEFT,
+            Self::NewEmoji => quotes::NEW_EMOJI,
+            Self::DeletedEmoji => quotes::DELETED_EMOJI,
+            Self::NewRole => quotes::NEW_ROLE,
+            Self::DeletedRole => quotes::DELETED_ROLE,
+            Self::Ban => quotes::BAN,
+            Self::Unban => quotes::UNBAN
+        }
+    }
+
+    /// Commands that pick several at once need at least that many to choose from
+    const fn minimum(self) -> usize {
+        match self {
+            Self::ValAgents => 5,
+            Self::ClipReview => 7,
+            _ => 1
+        }
+    }
+
+    /// Text every entry has to contain, because it gets swapped out for something else
+    const fn placeholder(self) -> Option<&'static str> {
+        match self {
+            Self::SongStarts => Some("{song}"),
+            _ => None
+        }
+    }
+
+    /// Whether these are filled in as templates before being sent, see `templates::Template`.
+    /// The rest are either sent as they are or are what placeholders get swapped for.
+    pub const fn is_template(self) -> bool {
+        matches!(self, Self::Mee6 | Self::Busy | Self::Chess | Self::Checkers | Self::SongStarts | Self::Random | Self::Decision | Self::ClipReview | Self::NewMember | Self::MemberLeft | Self::NewEmoji | Self::DeletedEmoji | Self::NewRole | Self::DeletedRole | Self::Ban | Self::Unban)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct JokeField {
+    #[serde(default)]
+    pub name: String,
+    pub value: String,
+    #[serde(default)]
+    pub inline: bool
+}
+
+/// One embed per message, sent in order
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Joke {
+    pub messages: Vec<Vec<JokeField>>
+}
+
+/// What a pack file looks like:
+/// ```toml
+/// replace = ["songs"]        # optional, drops what earlier packs had for these instead of adding to them
+/// [quotes]
+/// songs = ["Basket Case", "American Idiot"]
+/// [[jokes]]
+/// messages = [[{ name = "Knock knock", value = "Who's there?" }]]
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Pack {
+    replace: Vec<String>,
+    quotes: HashMap<String, Vec<String>>,
+    jokes: Vec<Joke>
+}
+
+#[derive(Debug)]
+pub enum PackError {
+    Read(PathBuf, std::io::Error),
+    Parse(PathBuf, toml::de::Error),
+    Invalid(PathBuf, String)
+}
+
+impl fmt::Display for PackError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Read(path, e) => write!(f, "Couldn't read pack {}: {e}", path.display()),
+            Self::Parse(path, e) => write!(f, "Pack {} isn't valid: {e}", path.display()),
+            Self::Invalid(path, problem) => write!(f, "Pack {}: {problem}", path.display())
+        }
+    }
+}
+
+/// All the quotes and jokes in use, the built in ones plus any packs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Content {
+    quotes: HashMap<QuoteKind, Vec<String>>,
+    jokes: Vec<Joke>
+}
+
+impl Content {
+    /// Just what is compiled in, from quotes.rs and jokes.rs
+    pub fn built_in() -> Self {
+        let quotes = QuoteKind::ALL.into_iter()
+            .map(|kind| (kind, kind.built_in().iter().map(|quote| quote.to_string()).collect()))
+            .collect();
+        let jokes = jokes::JOKES.iter().map(|joke| Joke {
+            messages: joke.iter().map(|fields| fields.iter().map(|(name, value, inline)| JokeField {
+                name: name.to_string(),
+                value: value.to_string(),
+                inline: *inline
+            }).collect()).collect()
+        }).collect();
+        Self { quotes, jokes }
+    }
+
+    /// The built in content plus every .toml pack in `HODGEY_PACKS_PATH` (packs/ if that isn't set), in name order.
+    /// Packs with problems are reported and left out, the rest still load.
+    pub fn load() -> Self {
+        let dir = PathBuf::from(std::env::var("HODGEY_PACKS_PATH").unwrap_or_else(|_| String::from(DEFAULT_PACKS_PATH)));
+        let mut content = Self::built_in();
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            info!("No packs at {}, only using built in quotes and jokes", dir.display());
+            return content;
+        };
+
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|extension| extension == "toml"))
+            .collect();
+        paths.sort();
+        for path in paths {
+            match content.with_pack_file(&path) {
+                Ok(with_pack) => {
+                    info!("Loaded pack {}", path.display());
+                    content = with_pack;
+                },
+                Err(e) => error!("Skipping pack. {e}")
+            }
+        }
+        content
+    }
+
+    fn with_pack_file(&self, path: &Path) -> Result<Self, PackError> {
+        let contents = std::fs::read_to_string(path).map_err(|e| PackError::Read(path.to_path_buf(), e))?;
+        self.with_pack(path, &contents)
+    }
+
+    /// A copy with the pack added, as long as the pack is valid and leaves enough of everything
+    fn with_pack(&self, path: &Path, contents: &str) -> Result<Self, PackError> {
+        let invalid = |problem: String| PackError::Invalid(path.to_path_buf(), problem);
+        let pack: Pack = toml::from_str(contents).map_err(|e| PackError::Parse(path.to_path_buf(), e))?;
+        let mut content = self.clone();
+
+        for key in &pack.replace {
+            if key == "jokes" {
+                content.jokes.clear();
+                continue;
+            }
+            let kind = QuoteKind::from_key(key).ok_or_else(|| invalid(unknown_kind(key)))?;
+            content.quotes.insert(kind, Vec::new());
+        }
+        for (key, new_quotes) in pack.quotes {
+            let kind = QuoteKind::from_key(&key).ok_or_else(|| invalid(unknown_kind(&key)))?;
+            for quote in &new_quotes {
+                if quote.trim().is_empty() {
+                    return Err(invalid(format!("{key} has an empty quote")));
+                }
+                if kind.is_template() {
+                    Template::parse(quote).map_err(|e| invalid(format!("\"{quote}\" in {key} doesn't work, {e}")))?;
+                }
+                if let Some(placeholder) = kind.placeholder() {
+                    if !quote.contains(placeholder) {
+                        return Err(invalid(format!("\"{quote}\" in {key} needs {placeholder} in it")));
+                    }
+                }
+            }
+            content.quotes.entry(kind).or_default().extend(new_quotes);
+        }
+        for (number, joke) in pack.jokes.iter().enumerate() {
+            validate_joke(joke).map_err(|problem| invalid(format!("joke {}: {problem}", number + 1)))?;
+        }
+        content.jokes.extend(pack.jokes);
+
+        for kind in QuoteKind::ALL {
+            if content.quotes(kind).len() < kind.minimum() {
+                return Err(invalid(format!("{} needs at least {} left after replacing", kind.key(), kind.minimum())));
+            }
+        }
+        if content.jokes.is_empty() {
+            return Err(invalid(String::from("there has to be at least one joke left after replacing")));
+        }
+        Ok(content)
+    }
+
+    pub fn quotes(&self, kind: QuoteKind) -> &[String] {
+        self.quotes.get(&kind).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// A random quote of that kind. Every kind has at least one once loaded.
+    pub fn choose(&self, kind: QuoteKind, rng: &mut impl Rng) -> &str {
+        self.quotes(kind).choose(rng).map(String::as_str).unwrap_or_default()
+    }
+
+    pub fn choose_joke(&self, rng: &mut impl Rng) -> &Joke {
+        self.jokes.choose(rng).expect("There is always at least one joke")
+    }
+}
+
+fn unknown_kind(key: &str) -> String {
+    let keys: Vec<&str> = QuoteKind::ALL.iter().map(|kind| kind.key()).collect();
+    format!("there are no quotes called \"{key}\", the options are jokes, {}", keys.join(", "))
+}
+
+fn validate_joke(joke: &Joke) -> Result<(), String> {
+    if joke.messages.is_empty() {
+        return Err(String::from("has no messages"));
+    }
+    for fields in &joke.messages {
+        if fields.is_empty() || fields.len() > MAX_EMBED_FIELDS {
+            return Err(format!("each message needs between 1 and {MAX_EMBED_FIELDS} fields"));
+        }
+        for field in fields {
+            if field.name.chars().count() > MAX_FIELD_NAME_LENGTH {
+                return Err(format!("\"{}\" is longer than {MAX_FIELD_NAME_LENGTH} characters", field.name));
+            }
+            if field.value.trim().is_empty() || field.value.chars().count() > MAX_FIELD_VALUE_LENGTH {
+                return Err(format!("field values need between 1 and {MAX_FIELD_VALUE_LENGTH} characters"));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub struct Contents;
+
+impl TypeMapKey for Contents {
+    type Value = Arc<Content>;
+}
+
+pub async fn get(data: &RwLock<TypeMap>) -> Arc<Content> {
+    data.read().await.get::<Contents>().expect("Contents not in TypeMap.").clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_pack(contents: &str) -> Result<Content, PackError> {
+        Content::built_in().with_pack(Path::new("packs/test.toml"), contents)
+    }
+
+    #[test]
+    fn built_in_content_is_valid() {
+        let content = Content::built_in();
+        for kind in QuoteKind::ALL {
+            assert!(content.quotes(kind).len() >= kind.minimum(), "{}", kind.key());
+            if kind.is_template() {
+                for quote in content.quotes(kind) {
+                    assert!(Template::parse(quote).is_ok(), "{quote}");
+                }
+            }
+        }
+        assert_eq!(content.jokes.len(), jokes::JOKES.len());
+    }
+
+    #[test]
+    fn packs_add_to_what_is_there() {
+        let content = with_pack("[quotes]\nsongs = [\"Basket Case\"]\nbooks = [\"Carrie\", \"It\"]").unwrap();
+        assert_eq!(content.quotes(QuoteKind::Songs).len(), quotes::SONGS.len() + 1);
+        assert_eq!(content.quotes(QuoteKind::Books).last().unwrap(), "It");
+        assert_eq!(content.quotes(QuoteKind::Movies).len(), quotes::MOVIES.len());
+    }
+
+    #[test]
+    fn packs_can_replace() {
+        let content = with_pack("replace = [\"songs\"]\n[quotes]\nsongs = [\"Basket Case\"]").unwrap();
+        assert_eq!(content.quotes(QuoteKind::Songs), ["Basket Case"]);
+        let content = with_pack("replace = [\"jokes\"]\n[[jokes]]\nmessages = [[{ name = \"Knock knock\", value = \"Who's there?\" }]]").unwrap();
+        assert_eq!(content.jokes.len(), 1);
+        assert_eq!(content.jokes[0].messages[0][0], JokeField { name: String::from("Knock knock"), value: String::from("Who's there?"), inline: false });
+    }
+
+    #[test]
+    fn bad_packs_are_explained() {
+        let e = with_pack("[quotes]\nsong = [\"Basket Case\"]").unwrap_err();
+        assert!(e.to_string().starts_with("Pack packs/test.toml: there are no quotes called \"song\""), "{e}");
+        let e = with_pack("[quotes]\nsong_starts = [\"I like it\"]").unwrap_err();
+        assert_eq!(e.to_string(), "Pack packs/test.toml: \"I like it\" in song_starts needs {song} in it");
+        let e = with_pack("[quotes]\nsong_starts = [\"I like {song\"]").unwrap_err();
+        assert_eq!(e.to_string(), "Pack packs/test.toml: \"I like {song\" in song_starts doesn't work, a { is never closed, use \\{ for a plain {");
+        let e = with_pack("replace = [\"val_agents\"]\n[quotes]\nval_agents = [\"Jett\"]").unwrap_err();
+        assert_eq!(e.to_string(), "Pack packs/test.toml: val_agents needs at least 5 left after replacing");
+        let e = with_pack("[[jokes]]\nmessages = [[]]").unwrap_err();
+        assert_eq!(e.to_string(), "Pack packs/test.toml: joke 1: each message needs between 1 and 25 fields");
+        assert!(matches!(with_pack("[quotes]\nsongs = \"Basket Case\"").unwrap_err(), PackError::Parse(..)));
+        assert!(matches!(with_pack("colour = \"red\"").unwrap_err(), PackError::Parse(..)));
+    }
+}
@@ -0,0 +1,295 @@
+use std::fmt;
+use std::sync::OnceLock;
+use rand::seq::SliceRandom;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use serenity::prelude::*;
+use tracing::error;
+use crate::commands::{CommandContext, CommandError, CommandResult, Incoming};
+use crate::config::{GuildConfig, GuildConfigs};
+use crate::locale::Locale;
+use crate::rate_limit::RateLimiters;
+use crate::templates::{self, Template, TemplateError};
+use crate::{content, keywords, rng};
+use crate::transport::{Outgoing, Transport};
+
+/// Plenty for a server, and stops the config file growing forever
+const MAX_TRIGGERS: usize = 50;
+const MAX_RESPONSES: usize = 20;
+const MAX_PATTERN_LENGTH: usize = 200;
+/// Keeps compiled regexes small so a nasty pattern can't eat memory
+const REGEX_SIZE_LIMIT: usize = 1 << 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerKind {
+    /// The whole message
+    Exact,
+    /// Anywhere, even inside other words
+    Contains,
+    /// As a whole word or phrase, so "book" doesn't match "notebook"
+    Word,
+    Regex
+}
+
+impl TriggerKind {
+    const ALL: [Self; 4] = [Self::Exact, Self::Contains, Self::Word, Self::Regex];
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Exact => "exact",
+            Self::Contains => "contains",
+            Self::Word => "word",
+            Self::Regex => "regex"
+        }
+    }
+}
+
+/// A trigger a server's admins added themselves. One of the responses is picked at random when it matches.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CustomTrigger {
+    pub kind: TriggerKind,
+    pub pattern: String,
+    pub responses: Vec<String>,
+    /// Compiled when the trigger is added or loaded instead of for every message. `None` if a saved pattern doesn't compile.
+    #[serde(skip)]
+    regex: OnceLock<Option<Regex>>
+}
+
+//The compiled regex comes from the pattern, so it doesn't need comparing
+impl PartialEq for CustomTrigger {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.pattern == other.pattern && self.responses == other.responses
+    }
+}
+
+impl fmt::Display for CustomTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} \"{}\" => {}", self.kind.name(), self.pattern, self.responses.join(" | "))
+    }
+}
+
+/// Why a trigger can't be added
+#[derive(Debug)]
+pub enum TriggerError {
+    Length,
+    ResponseCount,
+    WordNeedsLetters,
+    BadResponse(TemplateError),
+    BadRegex(String)
+}
+
+impl TriggerError {
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            Self::Length => locale.format("trigger_length", &[("max", &MAX_PATTERN_LENGTH.to_string())]),
+            Self::ResponseCount => locale.format("response_count", &[("max", &MAX_RESPONSES.to_string())]),
+            Self::WordNeedsLetters => String::from(locale.text("word_trigger_letters")),
+            Self::BadResponse(e) => locale.format("bad_response", &[("problem", &e.message(locale))]),
+            Self::BadRegex(e) => locale.format("bad_regex", &[("problem", e)])
+        }
+    }
+}
+
+fn build_regex(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .build()
+}
+
+impl CustomTrigger {
+    /// Checks a trigger before it gets saved, with what is wrong for the admin if it won't work
+    pub fn new(kind: TriggerKind, pattern: &str, responses: Vec<String>) -> Result<Self, TriggerError> {
+        let pattern = pattern.trim();
+        if pattern.is_empty() || pattern.len() > MAX_PATTERN_LENGTH {
+            return Err(TriggerError::Length);
+        }
+        if responses.is_empty() || responses.len() > MAX_RESPONSES || responses.iter().any(|response| response.trim().is_empty()) {
+            return Err(TriggerError::ResponseCount);
+        }
+        if kind == TriggerKind::Word && keywords::tokenize(pattern).is_empty() {
+            return Err(TriggerError::WordNeedsLetters);
+        }
+        if let Some(e) = responses.iter().find_map(|response| Template::parse(response).err()) {
+            return Err(TriggerError::BadResponse(e));
+        }
+        let regex = OnceLock::new();
+        if kind == TriggerKind::Regex {
+            match build_regex(pattern) {
+                Ok(compiled) => regex.get_or_init(|| Some(compiled)),
+                Err(e) => return Err(TriggerError::BadRegex(e.to_string()))
+            };
+        }
+        let pattern = match kind {
+            TriggerKind::Regex => pattern.to_string(),
+            _ => pattern.to_lowercase()
+        };
+        Ok(Self { kind, pattern, responses, regex })
+    }
+
+    fn regex(&self) -> Option<&Regex> {
+        self.regex.get_or_init(|| {
+            build_regex(&self.pattern).map_err(|e| error!("Saved trigger regex \"{}\" doesn't compile: {e:?}", self.pattern)).ok()
+        }).as_ref()
+    }
+
+    /// Compiles a regex trigger loaded from the config file, so the first message doesn't have to
+    pub fn compile(&self) {
+        if self.kind == TriggerKind::Regex {
+            self.regex();
+        }
+    }
+
+    pub fn matches(&self, msg_lower: &str) -> bool {
+        match self.kind {
+            TriggerKind::Exact => msg_lower.trim() == self.pattern,
+            TriggerKind::Contains => msg_lower.contains(&self.pattern),
+            TriggerKind::Word => keywords::contains_keyword(msg_lower, &self.pattern, false, &[]),
+            TriggerKind::Regex => self.regex().is_some_and(|regex| regex.is_match(msg_lower))
+        }
+    }
+}
+
+/// Responds with the first of the server's triggers that matches. Returns whether one did.
+/// Shares the rate limits with commands, but goes quiet instead of warning like the built in responders.
+pub async fn dispatch(data: &RwLock<TypeMap>, transport: &dyn Transport, incoming: &Incoming, config: &GuildConfig) -> bool {
+    let msg_lower = incoming.content.to_lowercase();
+    let Some(trigger) = config.custom_triggers.iter().find(|trigger| trigger.matches(&msg_lower)) else {
+        return false;
+    };
+
+    let rw_lock = data.read().await;
+    let mut rate_limiter = rw_lock.get::<RateLimiters>().expect("RateLimiters not in TypeMap.").lock().await;
+    let rate_limit = rate_limiter.check(incoming.author_id, incoming.channel_id.get(), "custom trigger");
+    drop(rate_limiter); // drop mutex lock as soon as possible
+    drop(rw_lock);
+    if rate_limit.is_err() {
+        return true;
+    }
+
+    let mut rng = rng::fork(data).await;
+    let response = trigger.responses.choose(&mut rng).map(String::as_str).unwrap_or_default();
+    let content = content::get(data).await;
+    let response = templates::render(response, &content, transport, incoming.author_id, incoming.channel_id.get(), &mut rng).await;
+    if let Err(e) = transport.send(Outgoing::Say(response)).await {
+        error!("Error sending message: {e:?}");
+    }
+    true
+}
+
+/// "hodgey trigger add <exact|contains|word|regex> <trigger> => <response> | <response>", "hodgey trigger list" and "hodgey trigger remove <number>"
+pub async fn trigger(cmd: &CommandContext<'_>) -> CommandResult {
+    let Some(guild_id) = cmd.guild_id else {
+        return Err(CommandError::User(String::from(cmd.text("server_only"))));
+    };
+    let args = cmd.args.trim();
+    let (subcommand, rest) = args.split_once(' ').unwrap_or((args, ""));
+
+    let rw_lock = cmd.data.read().await;
+    let mut config_store = rw_lock.get::<GuildConfigs>().expect("GuildConfigs not in TypeMap.").lock().await;
+    let triggers = &mut config_store.get_mut(guild_id.get()).custom_triggers;
+
+    let (response, changed) = match subcommand.to_lowercase().as_str() {
+        "list" | "" => {
+            if triggers.is_empty() {
+                (String::from(cmd.text("no_triggers")), false)
+            }
+            else {
+                let list = triggers.iter().enumerate()
+                    .map(|(index, trigger)| format!("{}. {trigger}", index + 1))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                (cmd.locale.format("triggers_list", &[("list", &list)]), false)
+            }
+        },
+        "add" => {
+            let (kind_name, rest) = rest.trim().split_once(' ').ok_or(CommandError::Usage)?;
+            let kind = TriggerKind::ALL.into_iter()
+                .find(|kind| kind.name() == kind_name.to_lowercase())
+                .ok_or(CommandError::Usage)?;
+            let (pattern, responses) = rest.split_once("=>").ok_or(CommandError::Usage)?;
+            let responses = responses.split('|').map(|response| response.trim().to_string()).collect();
+            if triggers.len() >= MAX_TRIGGERS {
+                return Err(CommandError::User(cmd.locale.format("too_many_triggers", &[("max", &MAX_TRIGGERS.to_string())])));
+            }
+            let trigger = CustomTrigger::new(kind, pattern, responses).map_err(|e| CommandError::User(e.message(cmd.locale)))?;
+            let response = cmd.locale.format("trigger_added", &[("number", &(triggers.len() + 1).to_string()), ("trigger", &trigger.to_string())]);
+            triggers.push(trigger);
+            (response, true)
+        },
+        "remove" => {
+            let number = rest.trim().parse::<usize>().map_err(|_| CommandError::Usage)?;
+            if number == 0 || number > triggers.len() {
+                return Err(CommandError::User(cmd.locale.format("no_trigger", &[("number", &number.to_string())])));
+            }
+            let trigger = triggers.remove(number - 1);
+            (cmd.locale.format("trigger_removed", &[("trigger", &trigger.to_string())]), true)
+        },
+        _ => return Err(CommandError::Usage)
+    };
+
+    if changed {
+        if let Err(e) = config_store.save().await {
+            error!("Error saving guild config: {e:?}");
+        }
+    }
+    drop(config_store); // drop mutex lock as soon as possible
+
+    cmd.reply(response).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trigger(kind: TriggerKind, pattern: &str) -> CustomTrigger {
+        CustomTrigger::new(kind, pattern, vec![String::from("hi")]).unwrap()
+    }
+
+    #[test]
+    fn kinds_match_differently() {
+        let exact = trigger(TriggerKind::Exact, "Good Morning");
+        assert!(exact.matches("good morning"));
+        assert!(!exact.matches("good morning everyone"));
+
+        let contains = trigger(TriggerKind::Contains, "cat");
+        assert!(contains.matches("concatenate"));
+
+        let word = trigger(TriggerKind::Word, "cat");
+        assert!(word.matches("my cat, again"));
+        assert!(word.matches("cat"));
+        assert!(!word.matches("concatenate"));
+        assert!(!word.matches("cats"));
+
+        let regex = trigger(TriggerKind::Regex, r"^g+o+d*\b");
+        assert!(regex.matches("gggooood morning"));
+        assert!(!regex.matches("so good"));
+    }
+
+    #[test]
+    fn saved_regex_compiles_when_loaded() {
+        let saved = serde_json::to_string(&trigger(TriggerKind::Regex, r"^g+o+d*\b")).unwrap();
+        assert!(!saved.contains("\"regex\":"), "{saved}");
+        let loaded: CustomTrigger = serde_json::from_str(&saved).unwrap();
+        assert!(loaded.regex.get().is_none());
+        loaded.compile();
+        assert!(loaded.regex.get().is_some_and(Option::is_some));
+        assert!(loaded.clone().matches("good morning"));
+        assert_eq!(loaded, trigger(TriggerKind::Regex, r"^g+o+d*\b"));
+
+        //A pattern edited into the file by hand that doesn't compile never matches
+        let broken: CustomTrigger = serde_json::from_str(r#"{"kind":"regex","pattern":"(unclosed","responses":["hi"]}"#).unwrap();
+        assert!(!broken.matches("(unclosed"));
+    }
+
+    #[test]
+    fn bad_triggers_are_refused() {
+        assert!(CustomTrigger::new(TriggerKind::Regex, "(unclosed", vec![String::from("hi")]).unwrap_err().message(Locale::English).starts_with("That regex doesn't work"));
+        assert!(CustomTrigger::new(TriggerKind::Word, "  ", vec![String::from("hi")]).is_err());
+        assert!(CustomTrigger::new(TriggerKind::Word, "hello", vec![String::from("hi"), String::new()]).is_err());
+        assert!(CustomTrigger::new(TriggerKind::Word, "?!", vec![String::from("hi")]).is_err());
+        assert_eq!(CustomTrigger::new(TriggerKind::Word, "hello", vec![String::from("hi {user}")]).unwrap_err().message(Locale::English), "A response doesn't work, there is no placeholder called {user}, the options are {song}, {book}, {movie}, {bad}, {agent}, {author}, {channel}, {random_member}, {name}");
+    }
+}
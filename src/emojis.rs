@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+use serenity::all::{Emoji, EmojiId, GuildId};
+use serenity::prelude::*;
+use crate::content::QuoteKind;
+use crate::{message_channel, templates};
+
+/// The emojis each server had last time Hodgey looked, by name.
+/// The update event only has the new list, so this is what gets compared against.
+pub struct EmojiSnapshots;
+
+impl TypeMapKey for EmojiSnapshots {
+    type Value = Mutex<HashMap<u64, HashMap<EmojiId, String>>>;
+}
+
+fn names(emojis: &HashMap<EmojiId, Emoji>) -> HashMap<EmojiId, String> {
+    emojis.iter().map(|(id, emoji)| (*id, emoji.name.clone())).collect()
+}
+
+/// The ids of new emojis and the names of deleted ones, oldest first. Renamed emojis are neither.
+fn diff(old: &HashMap<EmojiId, String>, new: &HashMap<EmojiId, String>) -> (Vec<EmojiId>, Vec<String>) {
+    let mut added: Vec<EmojiId> = new.keys().filter(|id| !old.contains_key(id)).copied().collect();
+    added.sort_unstable();
+    let mut removed: Vec<(&EmojiId, &String)> = old.iter().filter(|(id, _)| !new.contains_key(id)).collect();
+    removed.sort_unstable();
+    (added, removed.into_iter().map(|(_, name)| name.clone()).collect())
+}
+
+/// Called from `guild_create` so there is something to compare the first update with
+pub async fn remember(data: &RwLock<TypeMap>, guild_id: GuildId, emojis: &HashMap<EmojiId, Emoji>) {
+    let rw_lock = data.read().await;
+    let mut snapshots = rw_lock.get::<EmojiSnapshots>().expect("EmojiSnapshots not in TypeMap.").lock().await;
+    snapshots.insert(guild_id.get(), names(emojis));
+}
+
+/// Gets mad about new emojis and sad about deleted ones
+pub async fn emojis_updated(ctx: &Context, guild_id: GuildId, current_state: &HashMap<EmojiId, Emoji>) {
+    let rw_lock = ctx.data.read().await;
+    let mut snapshots = rw_lock.get::<EmojiSnapshots>().expect("EmojiSnapshots not in TypeMap.").lock().await;
+    let previous = snapshots.insert(guild_id.get(), names(current_state));
+    drop(snapshots); // drop mutex lock as soon as possible
+    drop(rw_lock);
+
+    //Nothing to compare with, so there's no telling what changed
+    let Some(previous) = previous else {
+        return;
+    };
+    let (added, removed) = diff(&previous, &names(current_state));
+    for emoji in added.iter().filter_map(|id| current_state.get(id)) {
+        let quote = templates::event_quote(&ctx.data, QuoteKind::NewEmoji, &format!(":{}:", emoji.name)).await;
+        message_channel::announce(ctx, guild_id, "new emoji", format!("{quote}\n{emoji}")).await;
+    }
+    for name in removed {
+        let quote = templates::event_quote(&ctx.data, QuoteKind::DeletedEmoji, &format!(":{name}:")).await;
+        message_channel::announce(ctx, guild_id, "deleted emoji", quote).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn emojis(emojis: &[(u64, &str)]) -> HashMap<EmojiId, String> {
+        emojis.iter().map(|(id, name)| (EmojiId::new(*id), name.to_string())).collect()
+    }
+
+    #[test]
+    fn diff_finds_added_and_removed() {
+        let old = emojis(&[(1, "pog"), (2, "kekw"), (3, "sadge")]);
+        let new = emojis(&[(1, "pog"), (3, "very_sadge"), (5, "hodgey"), (4, "chess")]);
+        assert_eq!(diff(&old, &new), (vec![EmojiId::new(4), EmojiId::new(5)], vec![String::from("kekw")]));
+        assert_eq!(diff(&new, &new), (Vec::new(), Vec::new()));
+        assert_eq!(diff(&old, &HashMap::new()).1, ["pog", "kekw", "sadge"]);
+    }
+}
@@ -0,0 +1,264 @@
+use rand::{Rng, seq::SliceRandom};
+use serenity::prelude::*;
+use crate::checkers::{self, CheckersGame, CheckersGames};
+use crate::commands::{CommandContext, CommandError, CommandResult};
+use crate::connect_four::ConnectFour;
+use crate::games::{self, TurnBasedGame, TurnBasedGames, TurnBasedMatch};
+use crate::locale::Locale;
+use crate::tic_tac_toe::TicTacToe;
+use crate::settings;
+
+fn no_active_checkers_game(cmd: &CommandContext<'_>) -> CommandError {
+    CommandError::User(String::from(cmd.text("no_checkers_game")))
+}
+
+pub async fn checkers_resign(cmd: &CommandContext<'_>) -> CommandResult {
+    let bot_id = settings::get().bot_id;
+    let author_id = cmd.author_id;
+    let rw_lock = cmd.data.read().await;
+    let mut checkers_games = rw_lock.get::<CheckersGames>().expect("CheckersGames not in TypeMap.").lock().await;
+    let mut opponent_id: Option<u64> = None;
+    checkers_games.retain(|game| {
+        if game.has_user(author_id) {
+            if game.black_id == author_id {
+                opponent_id = Some(game.white_id)
+            }
+            else {
+                opponent_id = Some(game.black_id)
+            };
+        }
+        !game.has_user(author_id)
+    });
+    drop(checkers_games); // drop mutex lock as soon as possible
+
+    match opponent_id {
+        Some(opponent_id) if opponent_id == bot_id => cmd.say(cmd.text("i_win_checkers")).await?,
+        Some(opponent_id) => cmd.say(cmd.locale.format("player_wins", &[("player", &opponent_id.to_string())])).await?,
+        None => return Err(no_active_checkers_game(cmd))
+    };
+    Ok(())
+}
+
+pub async fn checkers_show(cmd: &CommandContext<'_>) -> CommandResult {
+    let rw_lock = cmd.data.read().await;
+    let checkers_games = rw_lock.get::<CheckersGames>().expect("CheckersGames not in TypeMap.").lock().await;
+    let Some(board) = checkers_games.iter().find(|game| game.has_user(cmd.author_id)).map(|game| game.render(cmd.locale)) else {
+        return Err(no_active_checkers_game(cmd));
+    };
+    drop(checkers_games); // drop mutex lock as soon as possible
+
+    cmd.reply(board).await?;
+    Ok(())
+}
+
+pub async fn checkers_new(cmd: &CommandContext<'_>) -> CommandResult {
+    let bot_id = settings::get().bot_id;
+    let author_id = cmd.author_id;
+    let mut rng = cmd.rng().await;
+    let opponent_id = match cmd.mentions.choose(&mut rng) {
+        Some(user_id) => *user_id,
+        None => bot_id
+    };
+
+    let mut new_game = CheckersGame::new_game_random_sides(author_id, opponent_id, &mut rng);
+    if new_game.id_to_move() == bot_id {
+        let selected_move = checkers::think(new_game.clone(), rng).await;
+        new_game.make_move_unchecked(selected_move);
+    }
+
+    let rw_lock = cmd.data.read().await;
+    let mut checkers_games = rw_lock.get::<CheckersGames>().expect("CheckersGames not in TypeMap.").lock().await;
+
+    //Check opponent isn't already in a game
+    if opponent_id != bot_id && checkers_games.iter().any(|game| game.has_user(opponent_id) && !game.has_user(author_id)) {
+        drop(checkers_games); // drop mutex lock as soon as possible
+        cmd.say(cmd.locale.format("opponent_busy", &[("command", "checkers")])).await?;
+        return Ok(());
+    }
+
+    checkers_games.retain(|game| !game.has_user(author_id));
+    checkers_games.push(new_game.clone());
+    drop(checkers_games); // drop mutex lock as soon as possible
+
+    cmd.reply(cmd.locale.format("checkers_new_game", &[("black", &new_game.black_id.to_string()), ("white", &new_game.white_id.to_string())])).await?;
+    cmd.say(new_game.render(cmd.locale)).await?;
+    Ok(())
+}
+
+pub async fn checkers_move(cmd: &CommandContext<'_>) -> CommandResult {
+    let bot_id = settings::get().bot_id;
+    let move_str: String = cmd.args.chars().filter(|c| !c.is_whitespace()).collect();
+    let author_id = cmd.author_id;
+    let rng = cmd.rng().await;
+
+    let rw_lock = cmd.data.read().await;
+    let mut checkers_games = rw_lock.get::<CheckersGames>().expect("CheckersGames not in TypeMap.").lock().await;
+    let Some(game) = checkers_games.iter_mut().find(|game| game.has_user(author_id)) else {
+        return Err(no_active_checkers_game(cmd));
+    };
+
+    if game.gameover() {
+        cmd.say(cmd.text("game_over")).await?;
+        return Ok(());
+    }
+
+    if game.id_to_move() != author_id {
+        return Err(CommandError::User(String::from(cmd.text("not_your_turn"))));
+    }
+
+    let legal_move = game.legal_move_from_string(&move_str).map_err(|move_error| CommandError::User(move_error.message(cmd.locale)))?;
+    game.make_move_unchecked(legal_move);
+
+    let game = game.clone();
+    drop(checkers_games); // drop mutex lock as soon as possible
+    drop(rw_lock);
+
+    let game = if !game.gameover() && game.id_to_move() == bot_id {
+        match checkers_bot_move(cmd.data, game, rng).await {
+            Some(game) => game,
+            None => return Ok(())
+        }
+    }
+    else {
+        game
+    };
+
+    let status = if game.gameover() {
+        game.get_gameover_message(cmd.locale).to_string()
+    }
+    else {
+        cmd.locale.format("your_turn", &[("player", &game.id_to_move().to_string())])
+    };
+    cmd.say(status).await?;
+    cmd.say(game.render(cmd.locale)).await?;
+    Ok(())
+}
+
+/// Hodgey searches without the games locked, then plays his move if the game is still where he left it.
+/// Returns the game after his move, or None if it was resigned or replaced while he was searching.
+async fn checkers_bot_move(data: &RwLock<TypeMap>, position: CheckersGame, rng: impl Rng + Send + 'static) -> Option<CheckersGame> {
+    let selected_move = checkers::think(position.clone(), rng).await;
+    let rw_lock = data.read().await;
+    let mut checkers_games = rw_lock.get::<CheckersGames>().expect("CheckersGames not in TypeMap.").lock().await;
+    let game = checkers_games.iter_mut().find(|game| game.same_position(&position))?;
+    game.make_move_unchecked(selected_move);
+    Some(game.clone())
+}
+
+pub async fn connect_four(cmd: &CommandContext<'_>) -> CommandResult {
+    turn_based_command::<ConnectFour>(cmd, "connect4").await
+}
+
+pub async fn tic_tac_toe(cmd: &CommandContext<'_>) -> CommandResult {
+    turn_based_command::<TicTacToe>(cmd, "tictactoe").await
+}
+
+/// Handles "<game> new/move/resign/show" for any game built on the turn based framework,
+/// following the same conventions as the chess commands.
+async fn turn_based_command<G: TurnBasedGame>(cmd: &CommandContext<'_>, command_name: &str) -> CommandResult {
+    let bot_id = settings::get().bot_id;
+    let author_id = cmd.author_id;
+    let command = cmd.args.trim().to_lowercase();
+    let (subcommand, argument) = command.split_once(' ').unwrap_or((&command, ""));
+    let mut rng = cmd.rng().await;
+    let no_game = cmd.locale.format("no_game", &[("game", cmd.text(G::NAME)), ("command", command_name)]);
+
+    let rw_lock = cmd.data.read().await;
+    let mut games = rw_lock.get::<TurnBasedGames<G>>().expect("TurnBasedGames not in TypeMap.").lock().await;
+    //The position Hodgey has to answer, and the new game message if he goes first
+    let mut bot_to_move: Option<(TurnBasedMatch<G>, Option<String>)> = None;
+    let mut responses: Vec<String> = match subcommand {
+        "new" => {
+            let opponent_id = if let Some(user_id) = cmd.mentions.choose(&mut rng) {
+                *user_id
+            }
+            else {
+                bot_id
+            };
+
+            //Check opponent isn't already in a game
+            if opponent_id != bot_id && games.iter().any(|game| game.has_user(opponent_id) && !game.has_user(author_id)) {
+                vec![cmd.locale.format("opponent_busy", &[("command", command_name)])]
+            }
+            else {
+                let new_game = TurnBasedMatch::<G>::new_game_random_sides(author_id, opponent_id, &mut rng);
+                let responses = if new_game.id_to_move() == bot_id {
+                    bot_to_move = Some((new_game.clone(), Some(new_game.players_message(cmd.locale))));
+                    Vec::new()
+                }
+                else {
+                    vec![new_game.players_message(cmd.locale), new_game.render()]
+                };
+                games.retain(|game| !game.has_user(author_id));
+                games.push(new_game);
+                responses
+            }
+        },
+        "move" => match games.iter_mut().find(|game| game.has_user(author_id)) {
+            None => vec![no_game],
+            Some(game) if game.gameover() => vec![String::from(cmd.text("game_over"))],
+            Some(game) if game.id_to_move() != author_id => vec![String::from(cmd.text("not_your_turn"))],
+            Some(game) => match game.make_move(argument) {
+                Err(move_error) => vec![String::from(cmd.text(move_error))],
+                Ok(()) if !game.gameover() && game.id_to_move() == bot_id => {
+                    bot_to_move = Some((game.clone(), None));
+                    Vec::new()
+                },
+                Ok(()) => vec![turn_based_status(game, cmd.locale), game.render()]
+            }
+        },
+        "resign" | "surrender" => match games.iter().position(|game| game.has_user(author_id)) {
+            None => vec![no_game],
+            Some(index) => {
+                let opponent_id = games.remove(index).opponent_of(author_id);
+                if opponent_id == bot_id {
+                    vec![String::from(cmd.text("i_win"))]
+                }
+                else {
+                    vec![cmd.locale.format("player_wins", &[("player", &opponent_id.to_string())])]
+                }
+            }
+        },
+        "show" => match games.iter().find(|game| game.has_user(author_id)) {
+            None => vec![no_game],
+            Some(game) => vec![game.render()]
+        },
+        _ => return Err(CommandError::Usage)
+    };
+    drop(games); // drop mutex lock as soon as possible
+    drop(rw_lock);
+
+    if let Some((position, players_message)) = bot_to_move {
+        let Some(game) = turn_based_bot_move(cmd.data, position, rng).await else {
+            return Ok(());
+        };
+        let first = players_message.unwrap_or_else(|| turn_based_status(&game, cmd.locale));
+        responses = vec![first, game.render()];
+    }
+
+    for response in responses {
+        cmd.say(response).await?;
+    }
+    Ok(())
+}
+
+/// Gameover message, or whose turn it is.
+fn turn_based_status<G: TurnBasedGame>(game: &TurnBasedMatch<G>, locale: Locale) -> String {
+    if game.gameover() {
+        game.get_gameover_message(locale)
+    }
+    else {
+        locale.format("your_turn", &[("player", &game.id_to_move().to_string())])
+    }
+}
+
+/// Hodgey searches without the games locked, then plays his move if the game is still where he left it.
+/// Returns the game after his move, or None if it was resigned or replaced while he was searching.
+async fn turn_based_bot_move<G: TurnBasedGame>(data: &RwLock<TypeMap>, position: TurnBasedMatch<G>, rng: impl Rng + Send + 'static) -> Option<TurnBasedMatch<G>> {
+    let selected_move = games::think(position.clone(), rng).await?;
+    let rw_lock = data.read().await;
+    let mut games = rw_lock.get::<TurnBasedGames<G>>().expect("TurnBasedGames not in TypeMap.").lock().await;
+    let game = games.iter_mut().find(|game| game.same_position(&position))?;
+    game.make_move_unchecked(&selected_move);
+    Some(game.clone())
+}
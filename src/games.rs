@@ -0,0 +1,226 @@
+use std::marker::PhantomData;
+use serenity::prelude::*;
+use rand::{Rng, seq::SliceRandom};
+use crate::locale::Locale;
+
+const WIN_SCORE: i32 = 100_000;
+
+/// A two player game where the players take turns. Player 0 always moves first.
+/// Implementing this is all a game needs to get challenges, resigning and a bot opponent.
+pub trait TurnBasedGame: Clone + Default + PartialEq + Send + Sync + 'static {
+    type Move: Clone + Send + 'static;
+
+    /// The locale key for its name in messages, e.g. "connect_four"
+    const NAME: &'static str;
+    /// How each player's pieces are shown, player 0 first
+    const PIECES: [&'static str; 2];
+    /// How many moves ahead the bot looks
+    const SEARCH_DEPTH: u32;
+
+    fn current_player(&self) -> usize;
+    fn legal_moves(&self) -> Vec<Self::Move>;
+    /// Turns user input into a legal move, or gives the locale key for what is wrong with it
+    fn parse_move(&self, input: &str) -> Result<Self::Move, &'static str>;
+    fn play(&mut self, selected_move: &Self::Move);
+    fn winner(&self) -> Option<usize>;
+    fn render(&self) -> String;
+
+    /// Heuristic score from the current player's point of view, used when the search runs out of depth
+    fn evaluate(&self) -> i32 {
+        0
+    }
+
+    fn gameover(&self) -> bool {
+        self.winner().is_some() || self.legal_moves().is_empty()
+    }
+}
+
+pub struct TurnBasedGames<G>(PhantomData<G>);
+
+impl<G: TurnBasedGame> TypeMapKey for TurnBasedGames<G> {
+    type Value = Mutex<Vec<TurnBasedMatch<G>>>;
+}
+
+#[derive(Clone)]
+pub struct TurnBasedMatch<G: TurnBasedGame> {
+    pub player_ids: [u64; 2],
+    state: G
+}
+
+impl<G: TurnBasedGame> TurnBasedMatch<G> {
+    pub fn new_game_random_sides(player1_id: u64, player2_id: u64, rng: &mut impl Rng) -> Self {
+        let player_ids = if rng.gen_bool(0.5) {
+            [player1_id, player2_id]
+        }
+        else {
+            [player2_id, player1_id]
+        };
+
+        Self {
+            player_ids,
+            state: G::default()
+        }
+    }
+
+    pub fn has_user(&self, id: u64) -> bool {
+        self.player_ids.contains(&id)
+    }
+
+    pub fn id_to_move(&self) -> u64 {
+        self.player_ids[self.state.current_player()]
+    }
+
+    pub fn opponent_of(&self, id: u64) -> u64 {
+        if self.player_ids[0] == id {
+            self.player_ids[1]
+        }
+        else {
+            self.player_ids[0]
+        }
+    }
+
+    pub fn make_move(&mut self, input: &str) -> Result<(), &'static str> {
+        let selected_move = self.state.parse_move(input)?;
+        self.state.play(&selected_move);
+        Ok(())
+    }
+
+    /// The same players and the same board, so a move found for one is legal in the other
+    pub fn same_position(&self, other: &Self) -> bool {
+        self.player_ids == other.player_ids && self.state == other.state
+    }
+
+    pub fn make_move_unchecked(&mut self, selected_move: &G::Move) {
+        self.state.play(selected_move);
+    }
+
+    /// None if there are no legal moves
+    pub fn find_best_move(&self, rng: &mut impl Rng) -> Option<G::Move> {
+        let mut moves = self.state.legal_moves();
+        //Shuffle so equally good moves don't always play out the same
+        moves.shuffle(rng);
+
+        let mut best_move = None;
+        let mut alpha = i32::MIN + 1;
+        for selected_move in moves {
+            let mut child = self.state.clone();
+            child.play(&selected_move);
+            let score = -negamax(&child, G::SEARCH_DEPTH.saturating_sub(1), i32::MIN + 1, -alpha);
+            if best_move.is_none() || score > alpha {
+                alpha = score;
+                best_move = Some(selected_move);
+            }
+        }
+        best_move
+    }
+
+    pub fn gameover(&self) -> bool {
+        self.state.gameover()
+    }
+
+    pub fn get_gameover_message(&self, locale: Locale) -> String {
+        match self.state.winner() {
+            Some(player) => locale.format("piece_wins", &[("piece", G::PIECES[player]), ("player", &self.player_ids[player].to_string())]),
+            None => String::from(locale.text("draw"))
+        }
+    }
+
+    pub fn players_message(&self, locale: Locale) -> String {
+        locale.format("turn_based_new_game", &[
+            ("game", locale.text(G::NAME)),
+            ("first_piece", G::PIECES[0]),
+            ("first", &self.player_ids[0].to_string()),
+            ("second_piece", G::PIECES[1]),
+            ("second", &self.player_ids[1].to_string())
+        ])
+    }
+
+    pub fn render(&self) -> String {
+        self.state.render()
+    }
+}
+
+/// `find_best_move` on a copy of the game, on a blocking thread so the bot keeps answering while it searches
+pub async fn think<G: TurnBasedGame>(game: TurnBasedMatch<G>, mut rng: impl Rng + Send + 'static) -> Option<G::Move> {
+    tokio::task::spawn_blocking(move || game.find_best_move(&mut rng)).await.expect("Turn based search panicked")
+}
+
+fn negamax<G: TurnBasedGame>(state: &G, depth: u32, mut alpha: i32, beta: i32) -> i32 {
+    if state.winner().is_some() {
+        //The winner made the last move, so the player to move has lost. Losing later is better than losing now.
+        return -WIN_SCORE - depth as i32;
+    }
+    let moves = state.legal_moves();
+    if moves.is_empty() {
+        return 0;
+    }
+    if depth == 0 {
+        return state.evaluate();
+    }
+
+    let mut best = i32::MIN + 1;
+    for selected_move in moves {
+        let mut child = state.clone();
+        child.play(&selected_move);
+        let score = -negamax(&child, depth - 1, -beta, -alpha);
+        best = best.max(score);
+        alpha = alpha.max(score);
+        if alpha >= beta {
+            break;
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+    use crate::connect_four::ConnectFour;
+    use crate::tic_tac_toe::TicTacToe;
+
+    fn game<G: TurnBasedGame>(moves: &[&str]) -> TurnBasedMatch<G> {
+        let mut game = TurnBasedMatch { player_ids: [1, 2], state: G::default() };
+        for input in moves {
+            game.make_move(input).unwrap();
+        }
+        game
+    }
+
+    fn make_best_move<G: TurnBasedGame>(game: &mut TurnBasedMatch<G>, rng: &mut StdRng) {
+        let best_move = game.find_best_move(rng).unwrap();
+        game.make_move_unchecked(&best_move);
+    }
+
+    #[test]
+    fn takes_a_win() {
+        for seed in 0..3 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            //❌ has 1 and 2, and could also block at 6
+            let mut tic_tac_toe = game::<TicTacToe>(&["1", "4", "2", "5"]);
+            make_best_move(&mut tic_tac_toe, &mut rng);
+            assert_eq!(tic_tac_toe.state.winner(), Some(0));
+
+            let mut connect_four = game::<ConnectFour>(&["1", "2", "1", "2", "1", "2"]);
+            make_best_move(&mut connect_four, &mut rng);
+            assert_eq!(connect_four.state.winner(), Some(0));
+        }
+    }
+
+    #[test]
+    fn blocks_a_win() {
+        for seed in 0..3 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut tic_tac_toe = game::<TicTacToe>(&["1", "5", "2"]);
+            make_best_move(&mut tic_tac_toe, &mut rng);
+            assert_eq!(tic_tac_toe.state.parse_move("3"), Err("square_taken"));
+
+            let mut connect_four = game::<ConnectFour>(&["1", "2", "1", "2", "1"]);
+            make_best_move(&mut connect_four, &mut rng);
+            //🔴 can't finish the column any more
+            let mut state = connect_four.state.clone();
+            state.play(&0);
+            assert_eq!(state.winner(), None);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use serenity::builder::CreateEmbed;
+use rand::{Rng, seq::SliceRandom};
+use crate::commands::{CommandContext, CommandError, CommandResult};
+use crate::transport::Reply;
+use crate::content::QuoteKind;
+use crate::{config, permissions, settings};
+
+pub async fn joke(cmd: &CommandContext<'_>) -> CommandResult {
+    let mut rng = cmd.rng().await;
+    let content = cmd.content().await;
+    let selected_joke = content.choose_joke(&mut rng);
+
+    for fields in &selected_joke.messages {
+        let colour = rng.gen_range(0..16777216);
+        let embed = CreateEmbed::new()
+            .title(cmd.text("joke_title"))
+            .url("https://youtu.be/dQw4w9WgXcQ")
+            .colour(colour)
+            .fields(fields.iter().map(|field| (field.name.clone(), field.value.clone(), field.inline)));
+
+        cmd.send(Reply { embeds: vec![embed], ..Default::default() }).await?;
+    }
+    Ok(())
+}
+
+pub async fn val_agent(cmd: &CommandContext<'_>) -> CommandResult {
+    let agent = cmd.quote(QuoteKind::ValAgents).await;
+    cmd.reply(agent).await?;
+    Ok(())
+}
+
+pub async fn val_squad(cmd: &CommandContext<'_>) -> CommandResult {
+    let squad = cmd.content().await.quotes(QuoteKind::ValAgents).choose_multiple(&mut cmd.rng().await, 5).fold(String::new(), |cur, nxt| cur + "- " + nxt + "\n");
+    cmd.reply(squad).await?;
+    Ok(())
+}
+
+pub async fn clip_review(cmd: &CommandContext<'_>) -> CommandResult {
+    let content = cmd.content().await;
+    let reviews: Vec<&String> = content.quotes(QuoteKind::ClipReview).choose_multiple(&mut cmd.rng().await, 7).collect();
+    let mut reply_string = cmd.render(cmd.text("clip_review")).await;
+    for review in reviews {
+        reply_string += "\n- ";
+        reply_string += &cmd.render(review).await;
+    }
+
+    cmd.reply(reply_string).await?;
+    Ok(())
+}
+
+pub async fn see(cmd: &CommandContext<'_>) -> CommandResult {
+    cmd.reply(cmd.text("see")).await?;
+    Ok(())
+}
+
+pub async fn one_plus_one(cmd: &CommandContext<'_>) -> CommandResult {
+    cmd.reply(cmd.text("one_plus_one")).await?;
+    Ok(())
+}
+
+pub async fn spam(cmd: &CommandContext<'_>) -> CommandResult {
+    if config::guild_config(cmd.data, cmd.guild_id).await.admin_only_spam && !permissions::is_admin(cmd).await {
+        return Err(CommandError::User(String::from(cmd.text("not_admin"))));
+    }
+    let (num_str, contents) = match cmd.args.split_once(' ') {
+        Some((num_str, contents)) => (num_str, Some(contents)),
+        None => (cmd.args, None)
+    };
+    let Ok(num_repeats) = num_str.parse::<usize>() else {
+        return Err(CommandError::Usage);
+    };
+    let spam_limit = settings::get().spam_limit;
+    if num_repeats > spam_limit {
+        cmd.say(cmd.locale.format("spam_limit", &[("limit", &spam_limit.to_string())])).await?;
+        return Ok(());
+    }
+    let Some(contents) = contents else {
+        cmd.say(cmd.text("spam_no_message")).await?;
+        return Ok(());
+    };
+    for _ in 0..num_repeats {
+        cmd.say(contents).await?;
+    }
+    Ok(())
+}
+
+pub async fn ping_everyone(cmd: &CommandContext<'_>) -> CommandResult {
+    cmd.reply(cmd.text("ping_everyone")).await?;
+    Ok(())
+}
+
+pub async fn ping_here(cmd: &CommandContext<'_>) -> CommandResult {
+    cmd.say(cmd.text("ping_here")).await?;
+    Ok(())
+}
+
+pub async fn someone(cmd: &CommandContext<'_>) -> CommandResult {
+    let humans = cmd.transport.human_member_ids().await?;
+    let Some(member_id) = humans.choose(&mut cmd.rng().await) else {
+        return Err(CommandError::User(String::from(cmd.text("no_one_here"))));
+    };
+    cmd.reply(format!("<@{member_id}>")).await?;
+    Ok(())
+}
+
+/// The text version's arguments are the whole message, which everyone can already see.
+/// A slash command's question is only shown to the person asking, so it gets quoted above the decision.
+pub async fn decide(cmd: &CommandContext<'_>) -> CommandResult {
+    let decision = cmd.quote(QuoteKind::Decision).await;
+    let question = cmd.args.trim();
+    //Only "/decide" has a question, the text version is a keyword anywhere in the message
+    if question.is_empty() {
+        cmd.say(decision).await?;
+    }
+    else {
+        cmd.say(format!("> {question}\n{decision}")).await?;
+    }
+    Ok(())
+}
+
+pub async fn chess_quote(cmd: &CommandContext<'_>) -> CommandResult {
+    let quote = cmd.quote(QuoteKind::Chess).await;
+    cmd.say(quote).await?;
+    Ok(())
+}
+
+pub async fn checkers_quote(cmd: &CommandContext<'_>) -> CommandResult {
+    let quote = cmd.quote(QuoteKind::Checkers).await;
+    cmd.say(quote).await?;
+    Ok(())
+}
+
+pub async fn horse(cmd: &CommandContext<'_>) -> CommandResult {
+    cmd.reply(cmd.text("horse")).await?;
+    Ok(())
+}
+
+pub async fn castle(cmd: &CommandContext<'_>) -> CommandResult {
+    cmd.reply(cmd.text("castle")).await?;
+    Ok(())
+}
+
+pub async fn book(cmd: &CommandContext<'_>) -> CommandResult {
+    let book = cmd.render(cmd.text("book")).await;
+    cmd.say(book).await?;
+    Ok(())
+}
+
+pub async fn music(cmd: &CommandContext<'_>) -> CommandResult {
+    let song = cmd.quote(QuoteKind::SongStarts).await;
+    cmd.say(song).await?;
+    Ok(())
+}
+
+pub async fn movie(cmd: &CommandContext<'_>) -> CommandResult {
+    let movie = cmd.render(cmd.text("movie")).await;
+    cmd.say(movie).await?;
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use serenity::all::{ButtonStyle, ComponentInteraction};
+use serenity::builder::{CreateActionRow, CreateButton, CreateEmbed, CreateEmbedFooter, CreateInteractionResponse, CreateInteractionResponseMessage};
+use serenity::prelude::*;
+use tracing::error;
+use crate::config;
+use crate::locale::Locale;
+use crate::permissions::{self, MemberInfo};
+use crate::transport::Reply;
+use crate::commands::{Category, Command, CommandContext, CommandError, CommandResult, Permission, COMMANDS};
+
+const HELP_COLOUR: u32 = 0x2ecc71;
+
+/// "hodgey val agent" -> "Hodgey Val Agent", the way the old help message wrote commands.
+fn title_case(text: &str) -> String {
+    text.split(' ').map(|word| {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new()
+        }
+    }).collect::<Vec<String>>().join(" ")
+}
+
+fn visible(command: &Command, show_admin: bool) -> bool {
+    command.permission == Permission::Everyone || show_admin
+}
+
+/// The categories that have something to show, one page each.
+fn visible_categories(show_admin: bool) -> Vec<Category> {
+    Category::ALL.into_iter()
+        .filter(|category| COMMANDS.iter().any(|command| command.category == *category && visible(command, show_admin)))
+        .collect()
+}
+
+fn category_page(category: Category, page: usize, page_count: usize, show_admin: bool, locale: Locale) -> CreateEmbed {
+    let fields = COMMANDS.iter()
+        .filter(|command| command.category == category && visible(command, show_admin))
+        .map(|command| (title_case(command.name), command.help_text(locale), false));
+
+    CreateEmbed::new()
+        .title(locale.format("help_title", &[("category", category.title(locale))]))
+        .description(locale.text("help_slash_note"))
+        .colour(HELP_COLOUR)
+        .fields(fields)
+        .footer(CreateEmbedFooter::new(locale.format("help_footer", &[("page", &(page + 1).to_string()), ("pages", &page_count.to_string())])))
+}
+
+fn command_page(command: &Command, locale: Locale) -> CreateEmbed {
+    let mut embed = CreateEmbed::new()
+        .title(title_case(command.name))
+        .description(command.help_text(locale))
+        .colour(HELP_COLOUR)
+        .field(locale.text("help_category"), command.category.title(locale), true);
+
+    let aliases: Vec<String> = command.triggers.iter().skip(1).map(|trigger| format!("\"{}\"", title_case(trigger.text().trim()))).collect();
+    if !aliases.is_empty() {
+        embed = embed.field(locale.text("help_aliases"), aliases.join(", "), true);
+    }
+    if command.permission == Permission::Admin {
+        embed = embed.field(locale.text("help_who"), locale.text("help_admins_only"), true);
+    }
+    embed
+}
+
+/// Previous/Next buttons. The page to go to, who asked and whether they saw admin commands are in the custom id
+/// so no state needs to be kept.
+fn page_buttons(page: usize, page_count: usize, owner_id: u64, show_admin: bool, locale: Locale) -> Vec<CreateActionRow> {
+    if page_count <= 1 {
+        return Vec::new();
+    }
+    vec![CreateActionRow::Buttons(vec![
+        CreateButton::new(format!("help_page:{}:{owner_id}:{show_admin}", page.saturating_sub(1)))
+            .label(locale.text("help_previous"))
+            .style(ButtonStyle::Secondary)
+            .disabled(page == 0),
+        CreateButton::new(format!("help_page:{}:{owner_id}:{show_admin}", page + 1))
+            .label(locale.text("help_next"))
+            .style(ButtonStyle::Secondary)
+            .disabled(page + 1 >= page_count),
+    ])]
+}
+
+/// Finds a command from what the user typed after "hodgey help", with or without the "hodgey".
+fn find_help_command(topic: &str) -> Option<&'static Command> {
+    COMMANDS.iter().find(|command| {
+        command.name == topic
+        || command.name.strip_prefix("hodgey ") == Some(topic)
+        || command.triggers.iter().any(|trigger| trigger.text().trim() == topic)
+    })
+}
+
+pub async fn help(cmd: &CommandContext<'_>) -> CommandResult {
+    let topic = cmd.args.trim().to_lowercase();
+    let show_admin = permissions::is_admin(cmd).await;
+    let categories = visible_categories(show_admin);
+
+    //Categories are checked first so "hodgey help chess" doesn't show the chess keyword response
+    let page = if topic.is_empty() {
+        Some(0)
+    }
+    else {
+        categories.iter().position(|category| category.keyword() == topic)
+    };
+
+    if let Some(page) = page {
+        let embed = category_page(categories[page], page, categories.len(), show_admin, cmd.locale);
+        let components = page_buttons(page, categories.len(), cmd.author_id, show_admin, cmd.locale);
+        cmd.send(Reply { embeds: vec![embed], components, ..Default::default() }).await?;
+        return Ok(());
+    }
+
+    match find_help_command(&topic) {
+        Some(command) if visible(command, show_admin) => {
+            cmd.send(Reply { embeds: vec![command_page(command, cmd.locale)], ..Default::default() }).await?;
+            Ok(())
+        },
+        _ => Err(CommandError::User(cmd.locale.format("help_unknown", &[("topic", &topic)])))
+    }
+}
+
+/// "help_page:<page>:<owner id>:<show admin>" from `page_buttons`
+fn parse_page_id(custom_id: &str) -> Option<(usize, u64, bool)> {
+    let mut parts = custom_id.strip_prefix("help_page:")?.split(':');
+    Some((parts.next()?.parse().ok()?, parts.next()?.parse().ok()?, parts.next()?.parse().ok()?))
+}
+
+pub async fn help_component_interaction(ctx: &Context, component: &ComponentInteraction) {
+    let user_id = component.user.id.get();
+    let config = config::guild_config(&ctx.data, component.guild_id).await;
+    let locale = Locale::from_code(&component.locale).unwrap_or(config.locale);
+
+    //The person who asked turns the pages of the message. Anyone else gets their own copy,
+    //with the pages they are allowed to see, so an admin's click never shows admin commands to everyone.
+    let parsed = parse_page_id(&component.data.custom_id);
+    let is_owner = parsed.is_some_and(|(_, owner_id, _)| owner_id == user_id);
+    let show_admin = match parsed {
+        Some((_, _, show_admin)) if is_owner => show_admin,
+        _ => component.member.as_ref()
+            .is_some_and(|member| permissions::is_bot_admin(&MemberInfo::from_interaction_member(&ctx.cache, component.guild_id, member), config.bot_admin_role))
+    };
+    let categories = visible_categories(show_admin);
+    let page = parsed.map(|(page, _, _)| page).unwrap_or_default().min(categories.len() - 1);
+
+    let message = CreateInteractionResponseMessage::new()
+        .embed(category_page(categories[page], page, categories.len(), show_admin, locale))
+        .components(page_buttons(page, categories.len(), user_id, show_admin, locale));
+    let response = if is_owner {
+        CreateInteractionResponse::UpdateMessage(message)
+    }
+    else {
+        CreateInteractionResponse::Message(message.ephemeral(true))
+    };
+
+    if let Err(e) = component.create_response(&ctx.http, response).await {
+        error!("Error sending interaction response: {e:?}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_ids_round_trip() {
+        let buttons = serde_json::to_value(page_buttons(1, 3, 111, true, Locale::English)).unwrap();
+        let ids: Vec<&str> = buttons[0]["components"].as_array().unwrap().iter().filter_map(|button| button["custom_id"].as_str()).collect();
+        assert_eq!(ids.into_iter().map(parse_page_id).collect::<Vec<_>>(), [Some((0, 111, true)), Some((2, 111, true))]);
+        //Buttons from before the owner was kept
+        assert_eq!(parse_page_id("help_page:2"), None);
+        assert_eq!(parse_page_id("help_page:x:111:true"), None);
+    }
+}
@@ -1,3 +1,6 @@
 //This file is a mess lol
 
-pub const JOKES: [&[&[(&str, &str, bool)]]; 14] = [&[&[("", "So, there was once a guy who really loved tractors, the only thing he loved more was his wife. One day his wife was killed by a tractor. So after a few years he finally got the courage to go on a date, but then the place started to fill up with smoke. His date was so scared, but then he sucked up all the smoke. His date asked him how he did it, he said im an ex-tractor fan. Get it???", false)]],&[&[("", "God said come forth and receive eternal life...\nbut John came fifth and won a toaster.", false)]],&[&[("", "A guy meets a hooker in a bar. She says, \"This is your lucky night. I’ve got a special game for you. I’ll do absolutely anything you want for $300, as long as you can say it in three words.\" The guy replies, \"Hey, why not?\" He pull his wallet out of his pocket, and one at a time lays three hundred-dollar bills on the bar, and says, slowly: \"Paint...my...house.\"", false)]],&[&[("", "Tired of constantly being broke and stuck in an unhappy marriage, a young husband decided to solve both problems by taking out a large insurance policy on his wife with himself as the beneficiary and then arranging to have her killed.\n\nA \"friend of a friend\" put him in touch with a nefarious dark-side underworld figure who went by the name of \"Artie.\" Artie explained to the husband that his going price for snuffing out a spouse was $5,000.\n\nThe husband said he was willing to pay that amount but that he wouldn't have any cash on hand until he could collect his wife's insurance money. Artie Insisted on being paid at least something up front, so the man opened his wallet, displaying the single dollar bill that rested inside. Artie sighed, rolled his eyes, and reluctantly agreed to accept the dollar as down payment for the dirty deed.", false),("", "A few days later Artie followed the man's wife to the local Super Wal-Mart store. There, he surprised her in the produce department and proceeded to strangle her with his gloved hands. As the poor unsuspecting woman drew her last breath and slumped to the floor, the manager of the produce department stumbled unexpectedly onto the murder scene. Unwilling to leave any living witnesses behind, Artie had no choice but to strangle the produce manager as well.\n\nHowever, unknown to Artie, the entire proceedings were captured by the hidden security cameras and observed by the store's security guard, who immediately called the police. Artie was caught and arrested before he could even leave the store.\n\nUnder intense questioning at the police station, Artie revealed the whole sordid plan, including his unusual financial arrangements with the hapless husband who was also quickly arrested.\n\nThe next day the newspaper headline declared...\n\n\n\n\n\n||\"ARTIE CHOKES 2 for $1.00 @ WALMART!\"||", false)]],&[&[("", "So the Pope is SUPER early for his flight.\n\nHe asks his driver on his way to the airport if he could drive around for a while because they have time to kill and he hasn't driven a car since becoming the pope.\n\nNaturally, he's a bit rusty, so he's driving poorly, when suddenly he sees police lights behind him. He pulls over and when the officer comes up to the window his eyes go wide. He says to the pope \"Hold on for a minute,\" and goes back to his car to radio the chief.\n\nCop: \"Chief we have a situation. I've pulled over an important figure.\"\n\nChief: \"How important? A governor or something?\"\n\nCop: \"No sir. He's bigger.\"\n\nChief: \"So, what? a celebrity or something?\"\n\nCop: \"More important, sir.\"\n\nChief: \"A major politician?\"\n\nCop: \"No sir, he's much more important.\"\n\nChief: \"WELL WHO IS IT!?\"\n\nCop: \"Well actually I'm not sure. But the pope's his driver.\"", false)]],&[&[("", "A blind man walks into a bar, then a chair, then a table.", false)]],&[&[("", "Did you know that 55% of statistics are made up on the spot?", false)]],&[&[("", "A man walks into a bar, ouch", false)]],&[&[("", "Dave was bragging to his boss one day, \"You know, I know everyone there is to know. Just name someone, anyone, and I know them.\"\n\nTired of his boasting, his boss called his bluff, \"OK, Dave, how about Tom Cruise?\"\n\n\"No dramas boss, Tom and I are old friends, and I can prove it.\" So Dave and his boss fly out to Hollywood and knock on Tom Cruise's door, and Tom Cruise shouts,\n\n\"Dave! What's happening? Great to see you! Come on in for a beer!\"\n\nAlthough impressed, Dave's boss is still skeptical. After they leave Cruise's house, he tells Dave that he thinks him knowing Cruise was just lucky.\n\n\"No, no, just name anyone else,\" Dave says.\n\n\"President Obama,\" his boss quickly retorts.\n\n\"Yup,\" Dave says, \"Old buddies, let's fly out to Washington,\" and off they go.\n\nAt the White House, Obama spots Dave on the tour and motions him and his boss over, saying, \"Dave, what a surprise, I was just on my way to a meeting, but you and your friend come on in and let's have a beer first and catch up.\"", false),("", "Well, the boss is very shaken by now but still not totally convinced. After they leave the White House grounds he expresses his doubts to Dave, who again implores him to name anyone else.\n\n\"Pope Francis,\" his boss replies.\n\n\"Sure!\" says Dave. \"I've known the Pope for years.\" So off they fly to Rome.\n\nDave and his boss are assembled with the masses at the Vatican's St. Peter's Square when Dave says, \"This will never work. I can't catch the Pope's eye among all these people. Tell you what, I know all the guards so let me just go upstairs and I'll come out on the balcony with the Pope.\" He disappears into the crowd headed towards the Vatican.\n\nSure enough, half an hour later Dave emerges with the Pope on the balcony, but by the time Dave returns, he finds that his boss has had a heart attack and is surrounded by paramedics.\n\nMaking his way to his boss' side, Dave asks him, \"What happened?\"", false),("", "His boss looks up and says, \"It was the final straw... you and the Pope came out on to the balcony and the man next to me said, 'Who the fuck is that on the balcony with Dave?'", false)]],&[&[("", "One day, Einstein has to speak at an important science conference.\n\nOn the way there, he tells his driver that looks a bit like him:\n\n\"I'm sick of all these conferences. I always say the same things over and over!\"\n\nThe driver agrees: \"You're right. As your driver, I attended all of them, and even though I don't know anything about science, I could give the conference in your place.\"\n\n\"That's a great idea!\" says Einstein. \"Let's switch places then!\"\n\nSo they switch clothes and as soon as they arrive, the driver dressed as Einstein goes on stage and starts giving the usual speech, while the real Einstein, dressed as the car driver, attends it.\n\nBut in the crowd, there is one scientist who wants to impress everyone and thinks of a very difficult question to ask Einstein, hoping he won't be able to respond. So this guy stands up and interrupts the conference by posing his very difficult question. The whole room goes silent, holding their breath, waiting for the response.", false),("", "The driver looks at him, dead in the eye, and says:\n\n\"Sir, your question is so easy to answer that I'm going to let my driver reply to it for me.\"", false)]],&[&[("", "There once was a conductor of a youth orchestra. He had trouble controlling his temper sometimes and would lash out violently. One day while in rehearsal, the first violin player was just playing out of turn for no reason. The conductor got so angry he kicked the violin player so hard that he died. The conductor was convicted and sentenced to death.\n\nFor his last meal the conductor ordered a dozen bananas. The guard was a bit purplexed, but hey, it's his last meal... The conductor ate the bananas and went on his way to the chair. They turned on the juice and let him fry, but the conductor lived. In this particular place, if you live through execution, you are set free.", false),("", "So the conductor went back to what he does best - conducting. Although, he still had violence issues. On another fateful day, the last chair flutist just couldn't play, and he got fed up and threw a music stand at her and killed her. Moving forward to death row, he had the same prison guard look after his last meal. Again, he ordered 12 bananas. The guard was really puzzled, but filled the order. Not only that, but the conductor lived through another electric chair!\n\nFor a third time the conductor was back on the podium when a snare drummer was just being loud. The conductor threw his baton at the poor drummer and she fell, dead, with a baton in her eye. The conductor found himself, once again, in that small cell, ordering his dozen bananas. This time the guard couldn't take it anymore.", false),("", "\"I've given you three last meals. Each time you order 12 bananas. I have also never seen anyone live through the electric chair. Do the bananas help that?\" asked the guard.\n\nThe conductor looked up from his seventh banana and replied, \"no, I just really like bananas.\"\n\nThe guard was shocked. \"Then how in the hell do you keep living through the electric chair!?\"\n\n||\"because I'm a bad conductor.\"||", false)]],&[&[("", "A bear walks into a bar and says, \"Give me a whiskey and … cola.\"\n\"Why the big pause?\" asks the bartender. The bear shrugged. \"I’m not sure; I was born with them.{\"", false)]],&[&[("", "Johnny is poor and has been all his life. He doesn't mind it much, except for the fact that every year when the circus comes to town, he never gets to see it. The years pass and every year he watches the circus come and go with a tear in his eye. Then one year as the circus is leaving, he snaps. \"Fuck it,\" he says.\" I'm going to get myself a job so I can see the circus.\"\n\nThe next day he applies for a job at a supermarket stacking shelves. He gets this job and works his heart out. He works every night stacking shelves, earning money. He spends very little, and saves heaps. He is the best worker the supermarket has ever seen. A year passes, and the circus comes to town.\n\nAs soon as the gates to the circus open, Johnny races up, first in line to buy a ticket. the excitement overwhelms him. He walks around the circus. He sees the animals, the freak show, buys a hot dog, plays on the clowns. And then he sees it, what he's been waiting for all these years...The Big Top.", false),("", "Johnny races into the tent and takes a seat. Pretty soon the tent fills up and the show begins. It's a packed house and the buzz is electric. The dancing horses come out, then the elephants, then everyone's favourite, the clowns. The clowns run around and do their act making everyone laugh. When all this is finished, the head clown picks up a microphone and says \"Now we'd like to pick a member of the audience to help with our show.\"\n\nAll the lights go out and a spotlight circles the crowd. and, as luck should have it, it lands on Johnny. Johnny is ecstatic, he nearly shits his pants with excitement. He can't believe his luck. The head clown comes up to him and says..\n\n\"Hey mister, are you the horse's head?\"\n\"No.\" Johnny replies.\n\"Are you the horse's ear?\"\n\"No\"\n\"Are you the horse's tail?\"\n\"No\"\n\"Then you must be the horse's ASS!!!!\"", false),("", "And then whole tent erupts into fits of laughter all of Johnny's expense. Everyone is laughing, except for Johnny. He's as pissed as fuck. He vows then and there that next year, when the circus comes to town, he'll get his revenge on the clown.\n\nAs he's walking home, still fuming from the humiliation that the clown caused, Johnny thinks of ways that he can get back at the clown. Death, violence, poisoning....and then it hits him. Johnny will give the clown a taste of his own medicine. Next year, Johnny will blast the clown with the biggest insult ever!\n\nThe next morning Johnny flips through the phonebook looking for someone who can help him with his revenge. Then he finds an ad.\n\n**INSULT SCHOOL**\nSick of being picked on? Come to our school and soon you'll be verbally attacking people with vigor!\n'This is just what I need!!!\" says Johnny. So he rings up the school and enrolls the next day.", false),("", "So every day Johnny goes to the insult school, studying hard so he can learn the best and most harsh insult so he can get back at the clown. On top of this, he still stacks shelves at the supermarket to get the money for the circus. Day in, day out Johnny works his ass off.\nThen his day arrives...\n\nAs soon as the circus opens it's gates Johnny barges to the front of the line, pushing people out of his way. No longer is he a kind, considerate man. He's a pissed off m.f. hell bent on revenge. He give the ticket seller the money, snatches the ticket and storms off.\n\nHe sees all the regular shit. The animals, the freaks, the clowns. He's so excited that he goes into the Big Top an hour before the show starts, just so he can get a good seat. The tent begins to fill up and the show starts...", false),("", "The dancing horses come out, Johnny yawns. Then the elephants, Johnny tries to stay awake. And then the act Johnny has waited a year for, the clowns. The clowns run around and do their act making everyone laugh. Johnny wonders why everyone is laughing, it's the same shit they did last year. When all this is finished, the head clown picks up a microphone, exactly the same as last year, and says \"Now we'd like to pick a member of the audience to help with our show.\"\n\nAll the lights go out and a spotlight circles the crowd. and, as luck should have it again, it lands on Johnny. Johnny is sits cool, calm and collected. The head clown comes up to him and says..", false),("", "\"Hey mister, are you the horse's head?\"\n\"No.\" Johnny replies.\n\"Are you the horse's ear?\"\n\"No\"\n\"Are you the horse's tail?\"\n\"No\"\n\"Then you must be the horse's ASS!!!!\"\n\nAnd again the whole tent erupts with laughter. Except for Johnny. He sits there staring straight at the clown, a look of pure evil and hate on his face. The laughter quickly dies down as everyone knows something is going to happen. The crowd watched. This is Johnny's moment. He takes a deep breath, looks at the clown and says...\n\n\n\n\n\n\n\n||\"FUCK YOU, CLOWN!\"||", false)]],&[&[("", "So, there's a man crawling through the desert.\n\nHe'd decided to try his SUV in a little bit of cross-country travel, had great fun zooming over the badlands and through the sand, got lost, hit a big rock, and then he couldn't get it started again. There were no cell phone towers anywhere near, so his cell phone was useless. He had no family, his parents had died a few years before in an auto accident, and his few friends had no idea he was out here.\n\nHe stayed with the car for a day or so, but his one bottle of water ran out and he was getting thirsty. He thought maybe he knew the direction back, now that he'd paid attention to the sun and thought he'd figured out which way was north, so he decided to start walking. He figured he only had to go about 30 miles or so and he'd be back to the small town he'd gotten gas in last.", false),("", "He thinks about walking at night to avoid the heat and sun, but based upon how dark it actually was the night before, and given that he has no flashlight, he's afraid that he'll break a leg or step on a rattlesnake. So, he puts on some sun block, puts the rest in his pocket for reapplication later, brings an umbrella he'd had in the back of the SUV with him to give him a little shade, pours the windshield wiper fluid into his water bottle in case he gets that desperate, brings his pocket knife in case he finds a cactus that looks like it might have water in it, and heads out in the direction he thinks is right.", false),("", "He walks for the entire day. By the end of the day he's really thirsty. He's been sweating all day, and his lips are starting to crack. He's reapplied the sunblock twice, and tried to stay under the umbrella, but he still feels sunburned. The windshield wiper fluid sloshing in the bottle in his pocket is really getting tempting now. He knows that it's mainly water and some ethanol and coloring, but he also knows that they add some kind of poison to it to keep people from drinking it. He wonders what the poison is, and whether the poison would be worse than dying of thirst.\n\nHe pushes on, trying to get to that small town before dark.", false),("", "By the end of the day he starts getting worried. He figures he's been walking at least 3 miles an hour, according to his watch for over 10 hours. That means that if his estimate was right that he should be close to the town. But he doesn't recognize any of this. He had to cross a dry creek bed a mile or two back, and he doesn't remember coming through it in the SUV. He figures that maybe he got his direction off just a little and that the dry creek bed was just off to one side of his path. He tells himself that he's close, and that after dark he'll start seeing the town lights over one of these hills, and that'll be all he needs.\n\nAs it gets dim enough that he starts stumbling over small rocks and things, he finds a spot and sits down to wait for full dark and the town lights.\n\nFull dark comes before he knows it. He must have dozed off. He stands back up and turns all the way around. He sees nothing but stars.", false),("", "He wakes up the next morning feeling absolutely lousy. His eyes are gummy and his mouth and nose feel like they're full of sand. He so thirsty that he can't even swallow. He barely got any sleep because it was so cold. He'd forgotten how cold it got at night in the desert and hadn't noticed it the night before because he'd been in his car.\n\nHe knows the Rule of Threes - three minutes without air, three days without water, three weeks without food - then you die. Some people can make it a little longer, in the best situations. But the desert heat and having to walk and sweat isn't the best situation to be without water. He figures, unless he finds water, this is his last day.", false),("", "He rinses his mouth out with a little of the windshield wiper fluid. He waits a while after spitting that little bit out, to see if his mouth goes numb, or he feels dizzy or something. Has his mouth gone numb? Is it just in his mind? He's not sure. He'll go a little farther, and if he still doesn't find water, he'll try drinking some of the fluid.\n\nThen he has to face his next, harder question - which way does he go from here? Does he keep walking the same way he was yesterday (assuming that he still knows which way that is), or does he try a new direction? He has no idea what to do.\n\nLooking at the hills and dunes around him, he thinks he knows the direction he was heading before. Just going by a feeling, he points himself somewhat to the left of that, and starts walking.", false),("", "As he walks, the day starts heating up. The desert, too cold just a couple of hours before, soon becomes an oven again. He sweats a little at first, and then stops. He starts getting worried at that - when you stop sweating he knows that means you're in trouble - usually right before heat stroke.\n\nHe decides that it's time to try the windshield wiper fluid. He can't wait any longer - if he passes out, he's dead. He stops in the shade of a large rock, takes the bottle out, opens it, and takes a mouthful. He slowly swallows it, making it last as long as he can. It feels so good in his dry and cracked throat that he doesn't even care about the nasty taste. He takes another mouthful, and makes it last too. Slowly, he drinks half the bottle. He figures that since he's drinking it, he might as well drink enough to make some difference and keep himself from passing out.", false),("", "He's quit worrying about the denaturing of the wiper fluid. If it kills him, it kills him - if he didn't drink it, he'd die anyway. Besides, he's pretty sure that whatever substance they denature the fluid with is just designed to make you sick - their way of keeping winos from buying cheap wiper fluid for the ethanol content. He can handle throwing up, if it comes to that.", false)],&[("", "He walks. He walks in the hot, dry, windless desert. Sand, rocks, hills, dunes, the occasional scrawny cactus or dried bush. No sign of water. Sometimes he'll see a little movement to one side or the other, but whatever moved is usually gone before he can focus his eyes on it. Probably birds, lizards, or mice. Maybe snakes, though they usually move more at night. He's careful to stay away from the movements.\n\nAfter a while, he begins to stagger. He's not sure if it's fatigue, heat stroke finally catching him, or maybe he was wrong and the denaturing of the wiper fluid was worse than he thought. He tries to steady himself, and keep going.", false),("", "After more walking, he comes to a large stretch of sand. This is good! He knows he passed over a stretch of sand in the SUV - he remembers doing donuts in it. Or at least he thinks he remembers it - he's getting woozy enough and tired enough that he's not sure what he remembers any more or if he's hallucinating. But he thinks he remembers it. So he heads off into it, trying to get to the other side, hoping that it gets him closer to the town.\n\nHe was heading for a town, wasn't he? He thinks he was. He isn't sure any more. He's not even sure how long he's been walking any more. Is it still morning? Or has it moved into afternoon and the sun is going down again? It must be afternoon - it seems like it's been too long since he started out.\n\nHe walks through the sand.\n\nAfter a while, he comes to a big dune in the sand. This is bad. He doesn't remember any dunes when driving over the sand in his SUV. Or at least he doesn't think he remembers any. This is bad.", false),("", "But, he has no other direction to go. Too late to turn back now. He figures that he'll get to the top of the dune and see if he can see anything from there that helps him find the town. He keeps going up the dune.\n\nHalfway up, he slips in the bad footing of the sand for the second or third time, and falls to his knees. He doesn't feel like getting back up - he'll just fall down again. So, he keeps going up the dune on his hand and knees.", false),("", "While crawling, if his throat weren't so dry, he'd laugh. He's finally gotten to the hackneyed image of a man lost in the desert - crawling through the sand on his hands and knees. If would be the perfect image, he imagines, if only his clothes were more ragged. The people crawling through the desert in the cartoons always had ragged clothes. But his have lasted without any rips so far. Somebody will probably find his dessicated corpse half buried in the sand years from now, and his clothes will still be in fine shape - shake the sand out, and a good wash, and they'd be wearable again. He wishes his throat were wet enough to laugh. He coughs a little instead, and it hurts.", false),("", "He finally makes it to the top of the sand dune. Now that he's at the top, he struggles a little, but manages to stand up and look around. All he sees is sand. Sand, and more sand. Behind him, about a mile away, he thinks he sees the rocky ground he left to head into this sand. Ahead of him, more dunes, more sand. This isn't where he drove his SUV. This is Hell. Or close enough.", false),("", "Again, he doesn't know what to do. He decides to drink the rest of the wiper fluid while figuring it out. He takes out the bottle, and is removing the cap, when he glances to the side and sees something. Something in the sand. At the bottom of the dune, off to the side, he sees something strange. It's a flat area, in the sand. He stops taking the cap of the bottle off, and tries to look closer. The area seems to be circular. And it's dark - darker than the sand. And, there seems to be something in the middle of it, but he can't tell what it is. He looks as hard as he can, and still can tell from here. He's going to have to go down there and look.", false),("", "He puts the bottle back in his pocket, and starts to stumble down the dune. After a few steps, he realizes that he's in trouble - he's not going to be able to keep his balance. After a couple of more sliding, tottering steps, he falls and starts to roll down the dune. The sand it so hot when his body hits it that for a minute he thinks he's caught fire on the way down - like a movie car wreck flashing into flames as it goes over the cliff, before it ever even hits the ground. He closes his eyes and mouth, covers his face with his hands, and waits to stop rolling.\n\nHe stops, at the bottom of the dune. After a minute or two, he finds enough energy to try to sit up and get the sand out of his face and clothes. When he clears his eyes enough, he looks around to make sure that the dark spot in the sand it still there and he hadn't just imagined it.", false),("", "So, seeing the large, flat, dark spot on the sand is still there, he begins to crawl towards it. He'd get up and walk towards it, but he doesn't seem to have the energy to get up and walk right now. He must be in the final stages of dehydration he figures, as he crawls. If this place in the sand doesn't have water, he'll likely never make it anywhere else. This is his last chance.\n\nHe gets closer and closer, but still can't see what's in the middle of the dark area. His eyes won't quite focus any more for some reason. And lifting his head up to look takes so much effort that he gives up trying. He just keeps crawling.", false),("", "Finally, he reaches the area he'd seen from the dune. It takes him a minute of crawling on it before he realizes that he's no longer on sand - he's now crawling on some kind of dark stone. Stone with some kind of marking on it - a pattern cut into the stone. He's too tired to stand up and try to see what the pattern is - so he just keeps crawling. He crawls towards the center, where his blurry eyes still see something in the middle of the dark stone area.", false)],&[("", "His mind, detached in a strange way, notes that either his hands and knees are so burnt by the sand that they no longer feel pain, or that this dark stone, in the middle of a burning desert with a pounding, punishing sun overhead, doesn't seem to be hot. It almost feels cool. He considers lying down on the nice cool surface.\n\nCool, dark stone. Not a good sign. He must be hallucinating this. He's probably in the middle of a patch of sand, already lying face down and dying, and just imagining this whole thing. A desert mirage. Soon the beautiful women carrying pitchers of water will come up and start giving him a drink. Then he'll know he's gone.\n\nHe decides against laying down on the cool stone. If he's going to die here in the middle of this hallucination, he at least wants to see what's in the center before he goes. He keeps crawling.", false),("", "It's the third time that he hears the voice before he realizes what he's hearing. He would swear that someone just said, \"Greetings, traveler. You do not look well. Do you hear me?\"\n\nHe stops crawling. He tries to look up from where he is on his hands and knees, but it's too much effort to lift his head. So he tries something different - he leans back and tries to sit up on the stone. After a few seconds, he catches his balance, avoids falling on his face, sits up, and tries to focus his eyes. Blurry. He rubs his eyes with the back of his hands and tries again. Better this time.\n\nYep. He can see. He's sitting in the middle of a large, flat, dark expanse of stone. Directly next to him, about three feet away, is a white post or pole about two inches in diameter and sticking up about four or five feet out of the stone, at an angle.", false),("", "And wrapped around this white rod, tail with rattle on it hovering and seeming to be ready to start rattling, is what must be a fifteen foot long desert diamondback rattlesnake, looking directly at him.\n\nHe stares at the snake in shock. He doesn't have the energy to get up and run away. He doesn't even have the energy to crawl away. This is it, his final resting place. No matter what happens, he's not going to be able to move from this spot.\n\nWell, at least dying of a bite from this monster should be quicker than dying of thirst. He'll face his end like a man. He struggles to sit up a little straighter. The snake keeps watching him. He lifts one hand and waves it in the snake's direction, feebly. The snake watches the hand for a moment, then goes back to watching the man, looking into his eyes.\n\nHmmm. Maybe the snake had no interest in biting him? It hadn't rattled yet - that was a good sign. Maybe he wasn't going to die of snake bite after all.", false),("", "He then remembers that he'd looked up when he'd reached the center here because he thought he'd heard a voice. He was still very woozy - he was likely to pass out soon, the sun still beat down on him even though he was now on cool stone. He still didn't have anything to drink. But maybe he had actually heard a voice. This stone didn't look natural. Nor did that white post sticking up out of the stone. Someone had to have built this. Maybe they were still nearby. Maybe that was who talked to him. Maybe this snake was even their pet, and that's why it wasn't biting.", false),("", "He tries to clear his throat to say, \"Hello,\" but his throat is too dry. All that comes out is a coughing or wheezing sound. There is no way he's going to be able to talk without something to drink. He feels his pocket, and the bottle with the wiper fluid is still there. He shakily pulls the bottle out, almost losing his balance and falling on his back in the process. This isn't good. He doesn't have much time left, by his reckoning, before he passes out.\n\nHe gets the lid off of the bottle, manages to get the bottle to his lips, and pours some of the fluid into his mouth. He sloshes it around, and then swallows it. He coughs a little. His throat feels better. Maybe he can talk now.\n\nHe tries again. Ignoring the snake, he turns to look around him, hoping to spot the owner of this place, and croaks out, \"Hello? Is there anyone here?\"\n\nHe hears, from his side, \"Greetings. What is it that you want?\"", false),("", "He turns his head, back towards the snake. That's where the sound had seemed to come from. The only thing he can think of is that there must be a speaker, hidden under the snake, or maybe built into that post. He decides to try asking for help.\n\n\"Please,\" he croaks again, suddenly feeling dizzy, \"I'd love to not be thirsty any more. I've been a long time without water. Can you help me?\"\n\nLooking in the direction of the snake, hoping to see where the voice was coming from this time, he is shocked to see the snake rear back, open its mouth, and speak. He hears it say, as the dizziness overtakes him and he falls forward, face first on the stone, \"Very well. Coming up.\"", false),("", "A piercing pain shoots through his shoulder. Suddenly he is awake. He sits up and grabs his shoulder, wincing at the throbbing pain. He's momentarily disoriented as he looks around, and then he remembers - the crawl across the sand, the dark area of stone, the snake. He sees the snake, still wrapped around the tilted white post, still looking at him.\n\nHe reaches up and feels his shoulder, where it hurts. It feels slightly wet. He pulls his fingers away and looks at them - blood. He feels his shoulder again - his shirt has what feels like two holes in it - two puncture holes - they match up with the two aching spots of pain on his shoulder. He had been bitten. By the snake.\n\n\"It'll feel better in a minute.\" He looks up - it's the snake talking. He hadn't dreamed it. Suddenly he notices - he's not dizzy any more. And more importantly, he's not thirsty any more - at all!\n\n\"Have I died? Is this the afterlife? Why are you biting me in the afterlife?\"", false),("", "\"Sorry about that, but I had to bite you,\" says the snake. \"That's the way I work. It all comes through the bite. Think of it as natural medicine.\"", false)],&[("", "\"You bit me to help me? Why aren't I thirsty any more? Did you give me a drink before you bit me? How did I drink enough while unconscious to not be thirsty any more? I haven't had a drink for over two days. Well, except for the windshield wiper fluid... hold it, how in the world does a snake talk? Are you real? Are you some sort of Disney animation?\"\n\n\"No,\" says the snake, \"I'm real. As real as you or anyone is, anyway. I didn't give you a drink. I bit you. That's how it works - it's what I do. I bite. I don't have hands to give you a drink, even if I had water just sitting around here.\"", false),("", "The man sat stunned for a minute. Here he was, sitting in the middle of the desert on some strange stone that should be hot but wasn't, talking to a snake that could talk back and had just bitten him. And he felt better. Not great - he was still starving and exhausted, but much better - he was no longer thirsty. He had started to sweat again, but only slightly. He felt hot, in this sun, but it was starting to get lower in the sky, and the cool stone beneath him was a relief he could notice now that he was no longer dying of thirst.\n\n\"I might suggest that we take care of that methanol you now have in your system with the next request,\" continued the snake. \"I can guess why you drank it, but I'm not sure how much you drank, or how much methanol was left in the wiper fluid. That stuff is nasty. It'll make you go blind in a day or two, if you drank enough of it.\"\n\n\"Ummm, n-next request?\" said the man. He put his hand back on his hurting shoulder and backed away from the snake a little.", false),("", "\"That's the way it works. If you like, that is,\" explained the snake. \"You get three requests. Call them wishes, if you wish.\" The snake grinned at his own joke, and the man drew back a little further from the show of fangs.\n\n\"But there are rules,\" the snake continued. \"The first request is free. The second requires an agreement of secrecy. The third requires the binding of responsibility.\" The snake looks at the man seriously.\n\n\"By the way,\" the snake says suddenly, \"my name is Nathan. Old Nathan, Samuel used to call me. He gave me the name. Before that, most of the Bound used to just call me 'Snake'. But that got old, and Samuel wouldn't stand for it. He said that anything that could talk needed a name. He was big into names. You can call me Nate, if you wish.\" Again, the snake grinned. \"Sorry if I don't offer to shake, but I think you can understand - my shake sounds somewhat threatening.\" The snake give his rattle a little shake.", false),("", "\"Umm, my name is Jack,\" said the man, trying to absorb all of this. \"Jack Samson.\n\n\"Can I ask you a question?\" Jack says suddenly. \"What happened to the poison...umm, in your bite. Why aren't I dying now? How did you do that? What do you mean by that's how you work?\"\n\n\"That's more than one question,\" grins Nate. \"But I'll still try to answer all of them. First, yes, you can ask me a question.\" The snake's grin gets wider. \"Second, the poison is in you. It changed you. You now no longer need to drink. That's what you asked for. Or, well, technically, you asked to not be thirsty any more - but 'any more' is such a vague term. I decided to make it permanent - now, as long as you live, you shouldn't need to drink much at all. Your body will conserve water very efficiently. You should be able to get enough just from the food you eat - much like a creature of the desert. You've been changed.", false),("", "\"For the third question,\" Nate continues, \"you are still dying. Besides the effects of that methanol in your system, you're a man - and men are mortal. In your current state, I give you no more than about another 50 years. Assuming you get out of this desert, alive, that is.\" Nate seemed vastly amused at his own humor, and continued his wide grin.\n\n\"As for the fourth question,\" Nate said, looking more serious as far as Jack could tell, as Jack was just now working on his ability to read talking-snake emotions from snake facial features, \"first you have to agree to make a second request and become bound by the secrecy, or I can't tell you.\"\n\n\"Wait,\" joked Jack, \"isn't this where you say you could tell me, but you'd have to kill me?\"\n\n\"I thought that was implied.\" Nate continued to look serious.", false),("", "\"Ummm...yeah.\" Jack leaned back a little as he remembered again that he was talking to a fifteen foot poisonous reptile with a reputation for having a nasty temper. \"So, what is this 'Bound by Secrecy' stuff, and can you really stop the effects of the methanol?\" Jack thought for a second. \"And, what do you mean methanol, anyway? I thought these days they use ethanol in wiper fluid, and just denature it?\"\n\n\"They may, I don't really know,\" said Nate. \"I haven't gotten out in a while. Maybe they do. All I know is that I smell methanol on your breath and on that bottle in your pocket. And the blue color of the liquid when you pulled it out to drink some let me guess that it was wiper fluid. I assume that they still color wiper fluid blue?\"\n\n\"Yeah, they do,\" said Jack.", false),("", "\"I figured,\" replied Nate. \"As for being bound by secrecy - with the fulfillment of your next request, you will be bound to say nothing about me, this place, or any of the information I will tell you after that, when you decide to go back out to your kind. You won't be allowed to talk about me, write about me, use sign language, charades, or even act in a way that will lead someone to guess correctly about me. You'll be bound to secrecy. Of course, I'll also ask you to promise not to give me away, and as I'm guessing that you're a man of your word, you'll never test the binding anyway, so you won't notice.\" Nate said the last part with utter confidence.\n\nJack, who had always prided himself on being a man of his word, felt a little nervous at this. \"Ummm, hey, Nate, who are you? How did you know that? Are you, umm, omniscient, or something?\"", false)],&[("", "Well, Jack,\" said Nate sadly, \"I can't tell you that, unless you make the second request.\" Nate looked away for a minute, then looked back.\n\n\"Umm, well, ok,\" said Jack, \"what is this about a second request? What can I ask for? Are you allowed to tell me that?\"\n\n\"Sure!\" said Nate, brightening. \"You're allowed to ask for changes. Changes to yourself. They're like wishes, but they can only affect you. Oh, and before you ask, I can't give you immortality. Or omniscience. Or omnipresence, for that matter. Though I might be able to make you gaseous and yet remain alive, and then you could spread through the atmosphere and sort of be omnipresent. But what good would that be - you still wouldn't be omniscient and thus still could only focus on one thing at a time. Not very useful, at least in my opinion.\" Nate stopped when he realized that Jack was staring at him.", false),("", "\"Well, anyway,\" continued Nate, \"I'd probably suggest giving you permanent good health. It would negate the methanol now in your system, you'd be immune to most poisons and diseases, and you'd tend to live a very long time, barring accident, of course. And you'll even have a tendency to recover from accidents well. It always seemed like a good choice for a request to me.\"\n\n\"Cure the methanol poisoning, huh?\" said Jack. \"And keep me healthy for a long time? Hmmm. It doesn't sound bad at that. And it has to be a request about a change to me? I can't ask to be rich, right? Because that's not really a change to me?\"\n\n\"Right,\" nodded Nate.\n\n\"Could I ask to be a genius and permanently healthy?\" Jack asked, hopefully.\n\n\"That takes two requests, Jack.\"\n\n\"Yeah, I figured so,\" said Jack. \"But I could ask to be a genius? I could become the smartest scientist in the world? Or the best athlete?\"", false),("", "\"Well, I could make you very smart,\" admitted Nate, \"but that wouldn't necessarily make you the best scientist in the world. Or, I could make you very athletic, but it wouldn't necessarily make you the best athlete either. You've heard the saying that 99% of genius is hard work? Well, there's some truth to that. I can give you the talent, but I can't make you work hard. It all depends on what you decide to do with it.\"\n\n\"Hmmm,\" said Jack. \"Ok, I think I understand. And I get a third request, after this one?\"\n\n\"Maybe,\" said Nate, \"it depends on what you decide then. There are more rules for the third request that I can only tell you about after the second request. You know how it goes.\" Nate looked like he'd shrug, if he had shoulders.\n\n\"Ok, well, since I'd rather not be blind in a day or two, and permanent health doesn't sound bad, then consider that my second request. Officially. Do I need to sign in blood or something?\"", false),("", "\"No,\" said Nate. \"Just hold out your hand. Or heel.\" Nate grinned. \"Or whatever part you want me to bite. I have to bite you again. Like I said, that's how it works - the poison, you know,\" Nate said apologetically.\n\nJack winced a little and felt his shoulder, where the last bite was. Hey, it didn't hurt any more. Just like Nate had said. That made Jack feel better about the biting business. But still, standing still while a fifteen foot snake sunk it's fangs into you. Jack stood up. Ignoring how good it felt to be able to stand again, and the hunger starting to gnaw at his stomach, Jack tried to decide where he wanted to get bitten. Despite knowing that it wouldn't hurt for long, Jack knew that this wasn't going to be easy.\n\n\"Hey, Jack,\" Nate suddenly said, looking past Jack towards the dunes behind him, \"is that someone else coming up over there?\"\n\nJack spun around and looked. Who else could be out here in the middle of nowhere? And did they bring food?", false),("", "Wait a minute, there was nobody over there. What was Nate...\n\nJack let out a bellow as he felt two fangs sink into his rear end, through his jeans...\n\nJack sat down carefully, favoring his more tender buttock. \"I would have decided, eventually, Nate. I was just thinking about it. You didn't have to hoodwink me like that.\"\n\n\"I've been doing this a long time, Jack,\" said Nate, confidently. \"You humans have a hard time sitting still and letting a snake bite you - especially one my size. And besides, admit it - it's only been a couple of minutes and it already doesn't hurt any more, does it? That's because of the health benefit with this one. I told you that you'd heal quickly now.\"\n\n\"Yeah, well, still,\" said Jack, \"it's the principle of the thing. And nobody likes being bitten in the butt! Couldn't you have gotten my calf or something instead?\"\n\n\"More meat in the typical human butt,\" replied Nate. \"And less chance you accidentally kick me or move at the last second.\"", false),("", "\"Yeah, right. So, tell me all of these wonderful secrets that I now qualify to hear,\" answered Jack.\n\n\"Ok,\" said Nate. \"Do you want to ask questions first, or do you want me to just start talking?\"\n\n\"Just talk,\" said Jack. \"I'll sit here and try to not think about food.\"\n\n\"We could go try to rustle up some food for you first, if you like,\" answered Nate.\n\n\"Hey! You didn't tell me you had food around here, Nate!\" Jack jumped up. \"What do we have? Am I in walking distance to town? Or can you magically whip up food along with your other powers?\" Jack was almost shouting with excitement. His stomach had been growling for hours.\n\n\"I was thinking more like I could flush something out of its hole and bite it for you, and you could skin it and eat it. Assuming you have a knife, that is,\" replied Nate, with the grin that Jack was starting to get used to.", false),("", "\"Ugh,\" said Jack, sitting back down. \"I think I'll pass. I can last a little longer before I get desperate enough to eat desert rat, or whatever else it is you find out here. And there's nothing to burn - I'd have to eat it raw. No thanks. Just talk.\"\n\n\"Ok,\" replied Nate, still grinning. \"But I'd better hurry, before you start looking at me as food.", false)],&[("", "Nate reared back a little, looked around for a second, and then continued. \"You, Jack, are sitting in the middle of the Garden of Eden.\"\n\nJack looked around at the sand and dunes and then looked back at Nate sceptically.\n\n\"Well, that's the best I can figure it, anyway, Jack,\" said Nate. \"Stand up and look at the symbol on the rock here.\" Nate gestured around the dark stone they were both sitting on with his nose.\n\nJack stood up and looked. Carved into the stone in a bas-relief was a representation of a large tree. The angled-pole that Nate was wrapped around was coming out of the trunk of the tree, right below where the main branches left the truck to reach out across the stone. It was very well done - it looked more like a tree had been reduced to almost two dimensions and embedded in the stone than it did like a carving.\n\nJack walked around and looked at the details in the fading light of the setting sun. He wished he'd looked at it while the sun was higher in the sky.", false),("", "Wait! The sun was setting! That meant he was going to have to spend another night out here! Arrrgh!\n\nJack looked out across the desert for a little bit, and then came back and stood next to Nate. \"In all the excitement, I almost forgot, Nate,\" said Jack. \"Which way is it back to town? And how far? I'm eventually going to have to head back - I'm not sure I'll be able to survive by eating raw desert critters for long. And even if I can, I'm not sure I'll want to.\"\n\n\"It's about 30 miles that way.\" Nate pointed, with the rattle on his tail this time. As far as Jack could tell, it was a direction at right angles to the way he'd been going when he was crawling here. \"But that's 30 miles by the way the crow flies. It's about 40 by the way a man walks. You should be able to do it in about half a day with your improved endurance, if you head out early tomorrow, Jack.\"", false),("", "Jack looked out the way the snake had pointed for a few seconds more, and then sat back down. It was getting dark. Not much he could do about heading out right now. And besides, Nate was just about to get to the interesting stuff. \"Garden of Eden? As best as you can figure it?\"\n\n\"Well, yeah, as best as I and Samuel could figure it anyway,\" said Nate. \"He figured that the story just got a little mixed up. You know, snake, in a 'tree', offering 'temptations', making bargains. That kind stuff. But he could never quite figure out how the Hebrews found out about this spot from across the ocean. He worried about that for a while.\"\n\n\"Garden of Eden, hunh?\" said Jack. \"How long have you been here, Nate?\"\n\n\"No idea, really,\" replied Nate. \"A long time. It never occurred to me to count years, until recently, and by then, of course, it was too late. But I do remember when this whole place was green, so I figure it's been thousands of years, at least.\"\n\n\"So, are you the snake that tempted Eve?\" said Jack.", false),("", "\"Beats me,\" said Nate. \"Maybe. I can't remember if the first one of your kind that I talked to was female or not, and I never got a name, but it could have been. And I suppose she could have considered my offer to grant requests a 'temptation', though I've rarely had refusals.\"\n\n\"Well, umm, how did you get here then? And why is that white pole stuck out of the stone there?\" asked Jack.\n\n\"Dad left me here. Or, I assume it was my dad. It was another snake - much bigger than I was back then. I remember talking to him, but I don't remember if it was in a language, or just kind of understanding what he wanted. But one day, he brought me to this stone, told me about it, and asked me to do something for him. I talked it over with him for a while, then agreed. I've been here ever since.\n\n\"What is this place?\" said Jack. \"And what did he ask you to do?\"", false),("", "\"Well, you see this pole here, sticking out of the stone?\" Nate loosened his coils around the tilted white pole and showed Jack where it descended into the stone. The pole was tilted at about a 45 degree angle and seemed to enter the stone in an eighteen inch slot cut into the stone. Jack leaned over and looked. The slot was dark and the pole went down into it as far as Jack could see in the dim light. Jack reached out to touch the pole, but Nate was suddenly there in the way.\n\n\"You can't touch that yet, Jack,\" said Nate.\n\n\"Why not?\" asked Jack.\n\n\"I haven't explained it to you yet,\" replied Nate.\n\n\"Well, it kinda looks like a lever or something,\" said Jack. \"You'd push it that way, and it would move in the slot.\"\n\n\"Yep, that's what it is,\" replied Nate.\n\n\"What does it do?\" asked Jack. \"End the world?\"", false),("", "\"Oh, no,\" said Nate. \"Nothing that drastic. It just ends humanity. I call it 'The Lever of Doom'.\" For the last few words Nate had used a deeper, ringing voice. He tried to look serious for a few seconds, and then gave up and grinned.\n\nJack was initially startled by Nate's pronouncement, but when Nate grinned Jack laughed. \"Ha! You almost had me fooled for a second there. What does it really do?\"\n\n\"Oh, it really ends humanity, like I said,\" smirked Nate. \"I just thought the voice I used was funny, didn't you?\"\n\nNate continued to grin.\n\n\"A lever to end humanity?\" asked Jack. \"What in the world is that for? Why would anyone need to end humanity?\"\n\n\"Well,\" replied Nate, \"I get the idea that maybe humanity was an experiment. Or maybe the Big Guy just thought, that if humanity started going really bad, there should be a way to end it. I'm not really sure. All I know are the rules, and the guesses that Samuel and I had about why it's here. I didn't think to ask back when I started here.\"", false),("", "\"Rules? What rules?\" asked Jack.\n\n\"The rules are that I can't tell anybody about it or let them touch it unless they agree to be bound to secrecy by a bite. And that only one human can be bound in that way at a time. That's it.\" explained Nate.", false)],&[("", "Jack looked somewhat shocked. \"You mean that I could pull the lever now? You'd let me end humanity?\"\n\n\"Yep,\" replied Nate, \"if you want to.\" Nate looked at Jack carefully. \"Do you want to, Jack?\"\n\n\"Umm, no.\" said Jack, stepping a little further back from the lever. \"Why in the world would anyone want to end humanity? It'd take a psychotic to want that! Or worse, a suicidal psychotic, because it would kill him too, wouldn't it?\"\n\n\"Yep,\" replied Nate, \"being as he'd be human too.\"\n\n\"Has anyone ever seriously considered it?\" asked Nate. \"Any of those bound to secrecy, that is?\"\n\n\"Well, of course, I think they've all seriously considered it at one time or another. Being given that kind of responsibility makes you sit down and think, or so I'm told. Samuel considered it several times. He'd often get disgusted with humanity, come out here, and just hold the lever for a while. But he never pulled it. Or you wouldn't be here.\" Nate grinned some more.", false),("", "Jack sat down, well back from the lever. He looked thoughtful and puzzled at the same time. After a bit, he said, \"So this makes me the Judge of humanity? I get to decide whether they keep going or just end? Me?\"\n\n\"That seems to be it,\" agreed Nate.\n\n\"What kind of criteria do I use to decide?\" said Jack. \"How do I make this decision? Am I supposed to decide if they're good? Or too many of them are bad? Or that they're going the wrong way? Is there a set of rules for that?\"\n\n\"Nope,\" replied Nate. \"You pretty much just have to decide on your own. It's up to you, however you want to decide it. I guess that you're just supposed to know.\"\n\n\"But what if I get mad at someone? Or some girl dumps me and I feel horrible? Couldn't I make a mistake? How do I know that I won't screw up?\" protested Jack.\n\nNate gave his kind of snake-like shrug again. \"You don't. You just have to try your best, Jack.\"\n\nJack sat there for a while, staring off into the desert that was rapidly getting dark, chewing on a fingernail.", false),("", "Suddenly, Jack turned around and looked at the snake. \"Nate, was Samuel the one bound to this before me?\"\n\n\"Yep,\" replied Nate. \"He was a good guy. Talked to me a lot. Taught me to read and brought me books. I think I still have a good pile of them buried in the sand around here somewhere. I still miss him. He died a few months ago.\"\n\n\"Sounds like a good guy,\" agreed Jack. \"How did he handle this, when you first told him. What did he do?\"\n\n\"Well,\" said Nate, \"he sat down for a while, thought about it for a bit, and then asked me some questions, much like you're doing.\"\n\n\"What did he ask you, if you're allowed to tell me?\" asked Jack.\n\n\"He asked me about the third request,\" replied Nate.\n\n\"Aha!\" It was Jack's turn to grin. \"And what did you tell him?\"", false),("", "\"I told him the rules for the third request. That to get the third request you have to agree to this whole thing. That if it ever comes to the point that you really think that humanity should be ended, that you'll come here and end it. You won't avoid it, and you won't wimp out.\" Nate looked serious again. \"And you'll be bound to do it too, Jack.\"\n\n\"Hmmm.\" Jack looked back out into the darkness for a while.\n\nNate watched him, waiting.\n\n\"Nate,\" continued Jack, quietly, eventually. \"What did Samuel ask for with his third request?\"\n\nNate sounded like he was grinning again as he replied, also quietly, \"Wisdom, Jack. He asked for wisdom. As much as I could give him.\"\n\n\"Ok,\" said Jack, suddenly, standing up and facing away from Nate, \"give it to me.\n\nNate looked at Jack's backside. \"Give you what, Jack?\"\n\n\"Give me that wisdom. The same stuff that Samuel asked for. If it helped him, maybe it'll help me too.\" Jack turned his head to look back over his shoulder at Nate. \"It did help him, right?\"", false),("", "\"He said it did,\" replied Nate. \"But he seemed a little quieter afterward. Like he had a lot to think about.\"\n\n\"Well, yeah, I can see that,\" said Jack. \"So, give it to me.\" Jack turned to face away from Nate again, bent over slightly and tensed up.\n\nNate watched Jack tense up with a little exasperation. If he bit Jack now, Jack would likely jump out of his skin and maybe hurt them both.\n\n\"You remember that you'll be bound to destroy humanity if it ever looks like it needs it, right Jack?\" asked Nate, shifting position.\n\n\"Yeah, yeah, I got that,\" replied Jack, eyes squeezed tightly shut and body tense, not noticing the change in direction of Nate's voice.\n\n\"And,\" continued Nate, from his new position, \"do you remember that you'll turn bright purple, and grow big horns and extra eyes?\"", false),("", "\"Yeah, yeah...Hey, wait a minute!\" said Jack, opening his eyes, straightening up and turning around. \"Purple?!\" He didn't see Nate there. With the moonlight Jack could see that the lever extended up from its slot in the rock without the snake wrapped around it.\n\nJack heard, from behind him, Nate's \"Just Kidding!\" right before he felt the now familiar piercing pain, this time in the other buttock.\n\nJack sat on the edge of the dark stone in the rapidly cooling air, his feet extending out into the sand. He stared out into the darkness, listening to the wind stir the sand, occasionally rubbing his butt where he'd been recently bitten.\n\nNate had left for a little while, had come back with a desert-rodent-shaped bulge somewhere in his middle, and was now wrapped back around the lever, his tongue flicking out into the desert night's air the only sign that he was still awake.\n\nOccasionally Jack, with his toes absentmindedly digging in the sand while he thought, would ask Nate a question without turning around.", false),("", "\"Nate, do accidents count?\"\n\nNate lifted his head a little bit. \"What do you mean, Jack?\"\n\nJack tilted his head back like he was looking at the stars. \"You know, accidents. If I accidentally fall on the lever, without meaning to, does that still wipe out humanity?\"\n\n\"Yeah, I'm pretty sure it does, Jack. I'd suggest you be careful about that if you start feeling wobbly,\" said Nate with some amusement.", false)],&[("", "A little later - \"Does it have to be me that pulls the lever?\" asked Jack.\n\n\"That's the rule, Jack. Nobody else can pull it,\" answered Nate.\n\n\"No,\" Jack shook his head, \"I meant does it have to be my hand? Could I pull the lever with a rope tied around it? Or push it with a stick? Or throw a rock?\"\n\n\"Yes, those should work,\" replied Nate. \"Though I'm not sure how complicated you could get. Samuel thought about trying to build some kind of remote control for it once, but gave it up. Everything he'd build would be gone by the next sunrise, if it was touching the stone, or over it. I told him that in the past others that had been bound had tried to bury the lever so they wouldn't be tempted to pull it, but every time the stones or sand or whatever had disappeared.\"\n\n\"Wow,\" said Jack, \"Cool.\" Jack leaned back until only his elbows kept him off of the stone and looked up into the sky.\n\n\"Nate, how long did Samuel live? One of his wishes was for health too, right?\" asked Jack.", false),("", "\"Yes,\" replied Nate, \"it was. He lived 167 years, Jack.\"\n\n\"Wow, 167 years. That's almost 140 more years I'll live if I live as long. Do you know what he died of, Nate?\"\n\n\"He died of getting tired of living, Jack,\" Nate said, sounding somewhat sad.\n\nJack turned his head to look at Nate in the starlight.\n\nNate looked back. \"Samuel knew he wasn't going to be able to stay in society. He figured that they'd eventually see him still alive and start questioning it, so he decided that he'd have to disappear after a while. He faked his death once, but changed his mind - he decided it was too early and he could stay for a little longer. He wasn't very fond of mankind, but he liked the attention. Most of the time, anyway.\n\n\"His daughter and then his wife dying almost did him in though. He didn't stay in society much longer after that. He eventually came out here to spend time talking to me and thinking about pulling the lever. A few months ago he told me he'd had enough. It was his time.\"", false),("", "\"And then he just died?\" asked Jack.\n\nNate shook his head a little. \"He made his forth request, Jack. There's only one thing you can ask for the fourth request. The last bite.\n\nAfter a bit Nate continued, \"He told me that he was tired, that it was his time. He reassured me that someone new would show up soon, like they always had.\n\nAfter another pause, Nate finished, \"Samuel's body disappeared off the stone with the sunrise.\"\n\nJack lay back down and looked at the sky, leaving Nate alone with his memories. It was a long time until Jack's breathing evened out into sleep.\n\nJack woke with the sunrise the next morning. He was a little chilled with the morning desert air, but overall was feeling pretty good. Well, except that his stomach was grumbling and he wasn't willing to eat raw desert rat.", false),("", "So, after getting directions to town from Nate, making sure he knew how to get back, and reassuring Nate that he'd be back soon, Jack started the long walk back to town. With his new health and Nate's good directions, he made it back easily.\n\nJack caught a bus back to the city, and showed up for work the next day, little worse for the wear and with a story about getting lost in the desert and walking back out. Within a couple of days Jack had talked a friend with a tow truck into going back out into the desert with him to fetch the SUV. They found it after a couple of hours of searching and towed it back without incident. Jack was careful not to even look in the direction of Nate's lever, though their path back didn't come within sight of it.\n\nBefore the next weekend, Jack had gone to a couple of stores, including a book store, and had gotten his SUV back from the mechanic, with a warning to avoid any more joyriding in the desert. On Saturday, Jack headed back to see Nate.", false),("", "Jack parked a little way out of the small town near Nate, loaded up his new backpack with camping gear and the things he was bringing for Nate, and then started walking. He figured that walking would leave the least trail, and he knew that while not many people camped in the desert, it wasn't unheard of, and shouldn't really raise suspicions.\n\nJack had brought more books for Nate - recent books, magazines, newspapers. Some things that would catch Nate up with what was happening in the world, others that were just good books to read. He spent the weekend with Nate, and then headed out again, telling Nate that he'd be back again soon, but that he had things to do first.", false),("", "Over four months later Jack was back to see Nate again. This time he brought a laptop with him - a specially modified laptop. It had a solar recharger, special filters and seals to keep out the sand, a satellite link-up, and a special keyboard and joystick that Jack hoped that a fifteen-foot rattlesnake would be able to use. And, it had been hacked to not give out its location to the satellite.\n\nAfter that Jack could e-mail Nate to keep in touch, but still visited him fairly regularly - at least once or twice a year.\n\nAfter the first year, Jack quit his job. For some reason, with the wisdom he 'd been given, and the knowledge that he could live for over 150 years, working in a nine to five job for someone else didn't seem that worthwhile any more. Jack went back to school.", false),("", "Eventually, Jack started writing. Perhaps because of the wisdom, or perhaps because of his new perspective, he wrote well. People liked what he wrote, and he became well known for it. After a time, Jack bought an RV and started traveling around the country for book signings and readings.\n\nBut, he still remembered to drop by and visit Nate occasionally.", false)],&[("", "On one of the visits Nate seemed quieter than usual. Not that Nate had been a fountain of joy lately. Jack's best guess was that Nate was still missing Samuel, and though Jack had tried, he still hadn't been able to replace Samuel in Nate's eyes. Nate had been getting quieter each visit. But on this visit Nate didn't even speak when Jack walked up to the lever. He nodded at Jack, and then went back to staring into the desert. Jack, respecting Nate's silence, sat down and waited.\n\nAfter a few minutes, Nate spoke. \"Jack, I have someone to introduce you to.\"\n\nJack looked surprised. \"Someone to introduce me to?\" Jack looked around, and then looked carefully back at Nate. \"This something to do with the Big Guy?\n\n\"No, no,\" replied Nate. \"This is more personal. I want you to meet my son.\" Nate looked over at the nearest sand dune. \"Sammy!\"\n\nJack watched as a four foot long desert rattlesnake crawled from behind the dune and up to the stone base of the lever.\n\n\"Yo, Jack,\" said the new, much smaller snake.", false),("", "\"Yo, Sammy\" replied Jack. Jack looked at Nate. \"Named after Samuel, I assume?\"\n\nNate nodded. \"Jack, I've got a favor to ask you. Could you show Sammy around for me?\" Nate unwrapped himself from the lever and slithered over to the edge of the stone and looked across the sands. \"When Samuel first told me about the world, and brought me books and pictures, I wished that I could go see it. I wanted to see the great forests, the canyons, the cities, even the other deserts, to see if they felt and smelled the same. I want my son to have that chance - to see the world. Before he becomes bound here like I have been.\n\n\"He's seen it in pictures, over the computer that you brought me. But I hear that it's not the same. That being there is different. I want him to have that. Think you can do that for me, Jack?\"", false),("", "Jack nodded. This was obviously very important to Nate, so Jack didn't even joke about taking a talking rattlesnake out to see the world. \"Yeah, I can do that for you, Nate. Is that all you need?\" Jack could sense that was something more.\n\nNate looked at Sammy. Sammy looked back at Nate for a second and then said, \"Oh, yeah. Ummm, I've gotta go pack. Back in a little bit Jack. Nice to meet ya!\" Sammy slithered back over the dune and out of sight.\n\nNate watched Sammy disappear and then looked back at Jack. \"Jack, this is my first son. My first offspring through all the years. You don't even want to know what it took for me to find a mate.\" Nate grinned to himself. \"But anyway, I had a son for a reason. I'm tired. I'm ready for it to be over. I needed a replacement.\"\n\nJack considered this for a minute. \"So, you're ready to come see the world, and you wanted him to watch the lever while you were gone?\"", false),("", "Nate shook his head. \"No, Jack - you're a better guesser than that. You've already figured out - I'm bound here - there's only one way for me to leave here. And I'm ready. It's my time to die.\"\n\nJack looked more closely at Nate. He could tell Nate had thought about this - probably for quite a while. Jack had trouble imagining what it would be like to be as old as Nate, but Jack could already tell that in another hundred or two hundred years, he might be getting tired of life himself. Jack could understand Samuel's decision, and now Nate's. So, all Jack said was, \"What do you want me to do?\"\n\nNate nodded. \"Thanks, Jack. I only want two things. One - show Sammy around the world - let him get his fill of it, until he's ready to come back here and take over. Two - give me the fourth request.", false),("", "\"I can't just decide to die, not any more than you can. I won't even die of old age like you eventually will, even though it'll be a long time from now. I need to be killed. Once Sammy is back here, ready to take over, I'll be able to die. And I need you to kill me.\n\n\"I've even thought about how. Poisons and other drugs won't work on me. And I've seen pictures of snakes that were shot - some of them live for days, so that's out too. So, I want you to bring back a sword.\n\nNate turned away to look back to the dune that Sammy had gone behind. \"I'd say an axe, but that's somewhat undignified - putting my head on the ground or a chopping block like that. No, I like a sword. A time-honored way of going out. A dignified way to die. And, most importantly, it should work, even on me.\n\n\"You willing to do that for me, Jack?\" Nate turned back to look at Jack.\n\n\"Yeah, Nate,\" replied Jack solemnly, \"I think I can handle that.\"", false),("", "Nate nodded. \"Good!\" He turned back toward the dune and shouted, \"Sammy! Jack's about ready to leave!\" Then quietly, \"Thanks, Jack.\"", false),("", "Jack didn't have anything to say to that, so he waited for Sammy to make it back to the lever, nodded to him, nodded a final time to Nate, and then headed into the desert with Sammy following. Over the next several years Sammy and Jack kept in touch with Nate through e-mail as they went about their adventures. They made a goal of visiting every country in the world, and did a respectable job of it. Sammy had a natural gift for languages, as Jack expected he would, and even ended up acting as a translator for Jack in a few of the countries. Jack managed to keep the talking rattlesnake hidden, even so, and by the time they were nearing the end of their tour of countries, Sammy had only been spotted a few times. While there were several people that had seen enough to startle them greatly, nobody had enough evidence to prove anything, and while a few wild rumors and storied followed Jack and Sammy around, nothing ever hit the newspapers or the public in general.", false)],&[("", "When they finished the tour of countries, Jack suggested that they try some undersea diving. They did. And spelunking. They did that too. Sammy finally drew the line at visiting Antarctica. He'd come to realize that Jack was stalling. After talking to his Dad about it over e-mail, he figured out that Jack probably didn't want to have to kill Nate. Nate told Sammy that humans could be squeamish about killing friends and acquaintances.", false),("", "So, Sammy eventually put his tail down (as he didn't have a foot) and told Jack that it was time - he was ready to go back and take up his duties from his dad. Jack, delayed it a little more by insisting that they go back to Japan and buy an appropriate sword. He even stretched it a little more by getting lessons in how to use the sword. But, eventually, he'd learned as much as he was likely to without dedicating his life to it, and was definitely competent enough to take the head off of a snake. It was time to head back and see Nate.\n\nWhen they got back to the US, Jack got the old RV out of storage where he and Sammy had left it after their tour of the fifty states, he loaded up Sammy and the sword, and they headed for the desert.", false),("", "When they got to the small town that Jack had been trying to find those years ago when he'd met Nate, Jack was in a funk. He didn't really feel like walking all of the way out there. Not only that, but he'd forgotten to figure the travel time correctly, and it was late afternoon. They'd either have to spend the night in town and walk out tomorrow, or walk in the dark.\n\nAs Jack was afraid that if he waited one more night he might lose his resolve, he decided that he'd go ahead and drive the RV out there. It was only going to be this once, and Jack would go back and cover the tracks afterward. They ought to be able to make it out there by nightfall if they drove, and then they could get it over tonight.\n\nJack told Sammy to e-mail Nate that they were coming as he drove out of sight of the town on the road. They then pulled off the road and headed out into the desert.", false),("", "Everything went well, until they got to the sand dunes. Jack had been nursing the RV along the whole time, over the rocks, through the creek beds, revving the engine the few times they almost got stuck. When they came to the dunes, Jack didn't really think about it, he just downshifted and headed up the first one. By the third dune, Jack started to regret that he'd decided to try driving on the sand. The RV was fishtailing and losing traction. Jack was having to work it up each dune slowly and was trying to keep from losing control each time they came over the top and slid down the other side. Sammy had come up to sit in the passenger seat, coiled up and laughing at Jack's driving.\n\nAs they came over the top of the fourth dune, the biggest one yet, Jack saw that this was the final dune - the stone, the lever, and somewhere Nate, waited below. Jack put on the brakes, but he'd gone a little too far. The RV started slipping down the other side.", false),("", "Jack tried turning the wheel, but he didn't have enough traction. He pumped the brakes - no response. They started sliding down the hill, faster and faster.\n\nJack felt a shock go through him as he suddenly realized that they were heading for the lever. He looked down - the RV was directly on course for it. If Jack didn't do something, the RV would hit it. He was about to end humanity.\n\nJack steered more frantically, trying to get traction. It still wasn't working. The dune was too steep, and the sand too loose. In a split second, Jack realized that his only chance would be once he hit the stone around the lever - he should have traction on the stone for just a second before he hit the lever - he wouldn't have time to stop, but he should be able to steer away.\n\nJack took a better grip on the steering wheel and tried to turn the RV a little bit - every little bit would help. He'd have to time his turn just right.", false),("", "The RV got to the bottom of the dune, sliding at an amazing speed in the sand. Just before they reached the stone Jack looked across it to check that they were still heading for the lever. They were. But Jack noticed something else that he hadn't seen from the top of the dune. Nate wasn't wrapped around the lever. He was off to the side of the lever, but still on the stone, waiting for them. The problem was, he was waiting on the same side of the lever that Jack had picked to steer towards to avoid the lever. The RV was already starting to drift that way a little in its mad rush across the sand and there was no way that Jack was going to be able to go around the lever to the other side.", false),("", "||Jack had an instant of realization. He was either going to have to hit the lever, or run over Nate. He glanced over at Sammy and saw that Sammy realized the same thing.||\n\n||Jack took a firmer grip on the steering wheel as the RV ran up on the stone. Shouting to Sammy as he pulled the steering wheel, \"BETTER NATE THAN LEVER,\" he ran over the snake.||\n\n\n||THE END||", false)]]];
\ No newline at end of file
+/// An embed field: name, value and whether it is inline
+pub type JokeField = (&'static str, &'static str, bool);
+
+pub const JOKES: &[&[&[JokeField]]] = &[&[&[("", "So, there was once a guy who really loved tractors, the only thing he loved more was his wife. One day his wife was killed by a tractor. So after a few years he finally got the courage to go on a date, but then the place started to fill up with smoke. His date was so scared, but then he sucked up all the smoke. His date asked him how he did it, he said im an ex-tractor fan. Get it???", false)]],&[&[("", "God said come forth and receive eternal life...\nbut John came fifth and won a toaster.", false)]],&[&[("", "A guy meets a hooker in a bar. She says, \"This is your lucky night. I’ve got a special game for you. I’ll do absolutely anything you want for $300, as long as you can say it in three words.\" The guy replies, \"Hey, why not?\" He pull his wallet out of his pocket, and one at a time lays three hundred-dollar bills on the bar, and says, slowly: \"Paint...my...house.\"", false)]],&[&[("", "Tired of constantly being broke and stuck in an unhappy marriage, a young husband decided to solve both problems by taking out a large insurance policy on his wife with himself as the beneficiary and then arranging to have her killed.\n\nA \"friend of a friend\" put him in touch with a nefarious dark-side underworld figure who went by the name of \"Artie.\" Artie explained to the husband that his going price for snuffing out a spouse was $5,000.\n\nThe husband said he was willing to pay that amount but that he wouldn't have any cash on hand until he could collect his wife's insurance money. Artie Insisted on being paid at least something up front, so the man opened his wallet, displaying the single dollar bill that rested inside. Artie sighed, rolled his eyes, and reluctantly agreed to accept the dollar as down payment for the dirty deed.", false),("", "A few days later Artie followed the man's wife to the local Super Wal-Mart store. There, he surprised her in the produce department and proceeded to strangle her with his gloved hands. As the poor unsuspecting woman drew her last breath and slumped to the floor, the manager of the produce department stumbled unexpectedly onto the murder scene. Unwilling to leave any living witnesses behind, Artie had no choice but to strangle the produce manager as well.\n\nHowever, unknown to Artie, the entire proceedings were captured by the hidden security cameras and observed by the store's security guard, who immediately called the police. Artie was caught and arrested before he could even leave the store.\n\nUnder intense questioning at the police station, Artie revealed the whole sordid plan, including his unusual financial arrangements with the hapless husband who was also quickly arrested.\n\nThe next day the newspaper headline declared...\n\n\n\n\n\n||\"ARTIE CHOKES 2 for $1.00 @ WALMART!\"||", false)]],&[&[("", "So the Pope is SUPER early for his flight.\n\nHe asks his driver on his way to the airport if he could drive around for a while because they have time to kill and he hasn't driven a car since becoming the pope.\n\nNaturally, he's a bit rusty, so he's driving poorly, when suddenly he sees police lights behind him. He pulls over and when the officer comes up to the window his eyes go wide. He says to the pope \"Hold on for a minute,\" and goes back to his car to radio the chief.\n\nCop: \"Chief we have a situation. I've pulled over an important figure.\"\n\nChief: \"How important? A governor or something?\"\n\nCop: \"No sir. He's bigger.\"\n\nChief: \"So, what? a celebrity or something?\"\n\nCop: \"More important, sir.\"\n\nChief: \"A major politician?\"\n\nCop: \"No sir, he's much more important.\"\n\nChief: \"WELL WHO IS IT!?\"\n\nCop: \"Well actually I'm not sure. But the pope's his driver.\"", false)]],&[&[("", "A blind man walks into a bar, then a chair, then a table.", false)]],&[&[("", "Did you know that 55% of statistics are made up on the spot?", false)]],&[&[("", "A man walks into a bar, ouch", false)]],&[&[("", "Dave was bragging to his boss one day, \"You know, I know everyone there is to know. Just name someone, anyone, and I know them.\"\n\nTired of his boasting, his boss called his bluff, \"OK, Dave, how about Tom Cruise?\"\n\n\"No dramas boss, Tom and I are old friends, and I can prove it.\" So Dave and his boss fly out to Hollywood and knock on Tom Cruise's door, and Tom Cruise shouts,\n\n\"Dave! What's happening? Great to see you! Come on in for a beer!\"\n\nAlthough impressed, Dave's boss is still skeptical. After they leave Cruise's house, he tells Dave that he thinks him knowing Cruise was just lucky.\n\n\"No, no, just name anyone else,\" Dave says.\n\n\"President Obama,\" his boss quickly retorts.\n\n\"Yup,\" Dave says, \"Old buddies, let's fly out to Washington,\" and off they go.\n\nAt the White House, Obama spots Dave on the tour and motions him and his boss over, saying, \"Dave, what a surprise, I was just on my way to a meeting, but you and your friend come on in and let's have a beer first and catch up.\"", false),("", "Well, the boss is very shaken by now but still not totally convinced. After they leave the White House grounds he expresses his doubts to Dave, who again implores him to name anyone else.\n\n\"Pope Francis,\" his boss replies.\n\n\"Sure!\" says Dave. \"I've known the Pope for years.\" So off they fly to Rome.\n\nDave and his boss are assembled with the masses at the Vatican's St. Peter's Square when Dave says, \"This will never work. I can't catch the Pope's eye among all these people. Tell you what, I know all the guards so let me just go upstairs and I'll come out on the balcony with the Pope.\" He disappears into the crowd headed towards the Vatican.\n\nSure enough, half an hour later Dave emerges with the Pope on the balcony, but by the time Dave returns, he finds that his boss has had a heart attack and is surrounded by paramedics.\n\nMaking his way to his boss' side, Dave asks him, \"What happened?\"", false),("", "His boss looks up and says, \"It was the final straw... you and the Pope came out on to the balcony and the man next to me said, 'Who the fuck is that on the balcony with Dave?'", false)]],&[&[("", "One day, Einstein has to speak at an important science conference.\n\nOn the way there, he tells his driver that looks a bit like him:\n\n\"I'm sick of all these conferences. I always say the same things over and over!\"\n\nThe driver agrees: \"You're right. As your driver, I attended all of them, and even though I don't know anything about science, I could give the conference in your place.\"\n\n\"That's a great idea!\" says Einstein. \"Let's switch places then!\"\n\nSo they switch clothes and as soon as they arrive, the driver dressed as Einstein goes on stage and starts giving the usual speech, while the real Einstein, dressed as the car driver, attends it.\n\nBut in the crowd, there is one scientist who wants to impress everyone and thinks of a very difficult question to ask Einstein, hoping he won't be able to respond. So this guy stands up and interrupts the conference by posing his very difficult question. The whole room goes silent, holding their breath, waiting for the response.", false),("", "The driver looks at him, dead in the eye, and says:\n\n\"Sir, your question is so easy to answer that I'm going to let my driver reply to it for me.\"", false)]],&[&[("", "There once was a conductor of a youth orchestra. He had trouble controlling his temper sometimes and would lash out violently. One day while in rehearsal, the first violin player was just playing out of turn for no reason. The conductor got so angry he kicked the violin player so hard that he died. The conductor was convicted and sentenced to death.\n\nFor his last meal the conductor ordered a dozen bananas. The guard was a bit purplexed, but hey, it's his last meal... The conductor ate the bananas and went on his way to the chair. They turned on the juice and let him fry, but the conductor lived. In this particular place, if you live through execution, you are set free.", false),("", "So the conductor went back to what he does best - conducting. Although, he still had violence issues. On another fateful day, the last chair flutist just couldn't play, and he got fed up and threw a music stand at her and killed her. Moving forward to death row, he had the same prison guard look after his last meal. Again, he ordered 12 bananas. The guard was really puzzled, but filled the order. Not only that, but the conductor lived through another electric chair!\n\nFor a third time the conductor was back on the podium when a snare drummer was just being loud. The conductor threw his baton at the poor drummer and she fell, dead, with a baton in her eye. The conductor found himself, once again, in that small cell, ordering his dozen bananas. This time the guard couldn't take it anymore.", false),("", "\"I've given you three last meals. Each time you order 12 bananas. I have also never seen anyone live through the electric chair. Do the bananas help that?\" asked the guard.\n\nThe conductor looked up from his seventh banana and replied, \"no, I just really like bananas.\"\n\nThe guard was shocked. \"Then how in the hell do you keep living through the electric chair!?\"\n\n||\"because I'm a bad conductor.\"||", false)]],&[&[("", "A bear walks into a bar and says, \"Give me a whiskey and … cola.\"\n\"Why the big pause?\" asks the bartender. The bear shrugged. \"I’m not sure; I was born with them.{\"", false)]],&[&[("", "Johnny is poor and has been all his life. He doesn't mind it much, except for the fact that every year when the circus comes to town, he never gets to see it. The years pass and every year he watches the circus come and go with a tear in his eye. Then one year as the circus is leaving, he snaps. \"Fuck it,\" he says.\" I'm going to get myself a job so I can see the circus.\"\n\nThe next day he applies for a job at a supermarket stacking shelves. He gets this job and works his heart out. He works every night stacking shelves, earning money. He spends very little, and saves heaps. He is the best worker the supermarket has ever seen. A year passes, and the circus comes to town.\n\nAs soon as the gates to the circus open, Johnny races up, first in line to buy a ticket. the excitement overwhelms him. He walks around the circus. He sees the animals, the freak show, buys a hot dog, plays on the clowns. And then he sees it, what he's been waiting for all these years...The Big Top.", false),("", "Johnny races into the tent and takes a seat. Pretty soon the tent fills up and the show begins. It's a packed house and the buzz is electric. The dancing horses come out, then the elephants, then everyone's favourite, the clowns. The clowns run around and do their act making everyone laugh. When all this is finished, the head clown picks up a microphone and says \"Now we'd like to pick a member of the audience to help with our show.\"\n\nAll the lights go out and a spotlight circles the crowd. and, as luck should have it, it lands on Johnny. Johnny is ecstatic, he nearly shits his pants with excitement. He can't believe his luck. The head clown comes up to him and says..\n\n\"Hey mister, are you the horse's head?\"\n\"No.\" Johnny replies.\n\"Are you the horse's ear?\"\n\"No\"\n\"Are you the horse's tail?\"\n\"No\"\n\"Then you must be the horse's ASS!!!!\"", false),("", "And then whole tent erupts into fits of laughter all of Johnny's expense. Everyone is laughing, except for Johnny. He's as pissed as fuck. He vows then and there that next year, when the circus comes to town, he'll get his revenge on the clown.\n\nAs he's walking home, still fuming from the humiliation that the clown caused, Johnny thinks of ways that he can get back at the clown. Death, violence, poisoning....and then it hits him. Johnny will give the clown a taste of his own medicine. Next year, Johnny will blast the clown with the biggest insult ever!\n\nThe next morning Johnny flips through the phonebook looking for someone who can help him with his revenge. Then he finds an ad.\n\n**INSULT SCHOOL**\nSick of being picked on? Come to our school and soon you'll be verbally attacking people with vigor!\n'This is just what I need!!!\" says Johnny. So he rings up the school and enrolls the next day.", false),("", "So every day Johnny goes to the insult school, studying hard so he can learn the best and most harsh insult so he can get back at the clown. On top of this, he still stacks shelves at the supermarket to get the money for the circus. Day in, day out Johnny works his ass off.\nThen his day arrives...\n\nAs soon as the circus opens it's gates Johnny barges to the front of the line, pushing people out of his way. No longer is he a kind, considerate man. He's a pissed off m.f. hell bent on revenge. He give the ticket seller the money, snatches the ticket and storms off.\n\nHe sees all the regular shit. The animals, the freaks, the clowns. He's so excited that he goes into the Big Top an hour before the show starts, just so he can get a good seat. The tent begins to fill up and the show starts...", false),("", "The dancing horses come out, Johnny yawns. Then the elephants, Johnny tries to stay awake. And then the act Johnny has waited a year for, the clowns. The clowns run around and do their act making everyone laugh. Johnny wonders why everyone is laughing, it's the same shit they did last year. When all this is finished, the head clown picks up a microphone, exactly the same as last year, and says \"Now we'd like to pick a member of the audience to help with our show.\"\n\nAll the lights go out and a spotlight circles the crowd. and, as luck should have it again, it lands on Johnny. Johnny is sits cool, calm and collected. The head clown comes up to him and says..", false),("", "\"Hey mister, are you the horse's head?\"\n\"No.\" Johnny replies.\n\"Are you the horse's ear?\"\n\"No\"\n\"Are you the horse's tail?\"\n\"No\"\n\"Then you must be the horse's ASS!!!!\"\n\nAnd again the whole tent erupts with laughter. Except for Johnny. He sits there staring straight at the clown, a look of pure evil and hate on his face. The laughter quickly dies down as everyone knows something is going to happen. The crowd watched. This is Johnny's moment. He takes a deep breath, looks at the clown and says...\n\n\n\n\n\n\n\n||\"FUCK YOU, CLOWN!\"||", false)]],&[&[("", "So, there's a man crawling through the desert.\n\nHe'd decided to try his SUV in a little bit of cross-country travel, had great fun zooming over the badlands and through the sand, got lost, hit a big rock, and then he couldn't get it started again. There were no cell phone towers anywhere near, so his cell phone was useless. He had no family, his parents had died a few years before in an auto accident, and his few friends had no idea he was out here.\n\nHe stayed with the car for a day or so, but his one bottle of water ran out and he was getting thirsty. He thought maybe he knew the direction back, now that he'd paid attention to the sun and thought he'd figured out which way was north, so he decided to start walking. He figured he only had to go about 30 miles or so and he'd be back to the small town he'd gotten gas in last.", false),("", "He thinks about walking at night to avoid the heat and sun, but based upon how dark it actually was the night before, and given that he has no flashlight, he's afraid that he'll break a leg or step on a rattlesnake. So, he puts on some sun block, puts the rest in his pocket for reapplication later, brings an umbrella he'd had in the back of the SUV with him to give him a little shade, pours the windshield wiper fluid into his water bottle in case he gets that desperate, brings his pocket knife in case he finds a cactus that looks like it might have water in it, and heads out in the direction he thinks is right.", false),("", "He walks for the entire day. By the end of the day he's really thirsty. He's been sweating all day, and his lips are starting to crack. He's reapplied the sunblock twice, and tried to stay under the umbrella, but he still feels sunburned. The windshield wiper fluid sloshing in the bottle in his pocket is really getting tempting now. He knows that it's mainly water and some ethanol and coloring, but he also knows that they add some kind of poison to it to keep people from drinking it. He wonders what the poison is, and whether the poison would be worse than dying of thirst.\n\nHe pushes on, trying to get to that small town before dark.", false),("", "By the end of the day he starts getting worried. He figures he's been walking at least 3 miles an hour, according to his watch for over 10 hours. That means that if his estimate was right that he should be close to the town. But he doesn't recognize any of this. He had to cross a dry creek bed a mile or two back, and he doesn't remember coming through it in the SUV. He figures that maybe he got his direction off just a little and that the dry creek bed was just off to one side of his path. He tells himself that he's close, and that after dark he'll start seeing the town lights over one of these hills, and that'll be all he needs.\n\nAs it gets dim enough that he starts stumbling over small rocks and things, he finds a spot and sits down to wait for full dark and the town lights.\n\nFull dark comes before he knows it. He must have dozed off. He stands back up and turns all the way around. He sees nothing but stars.", false),("", "He wakes up the next morning feeling absolutely lousy. His eyes are gummy and his mouth and nose feel like they're full of sand. He so thirsty that he can't even swallow. He barely got any sleep because it was so cold. He'd forgotten how cold it got at night in the desert and hadn't noticed it the night before because he'd been in his car.\n\nHe knows the Rule of Threes - three minutes without air, three days without water, three weeks without food - then you die. Some people can make it a little longer, in the best situations. But the desert heat and having to walk and sweat isn't the best situation to be without water. He figures, unless he finds water, this is his last day.", false),("", "He rinses his mouth out with a little of the windshield wiper fluid. He waits a while after spitting that little bit out, to see if his mouth goes numb, or he feels dizzy or something. Has his mouth gone numb? Is it just in his mind? He's not sure. He'll go a little farther, and if he still doesn't find water, he'll try drinking some of the fluid.\n\nThen he has to face his next, harder question - which way does he go from here? Does he keep walking the same way he was yesterday (assuming that he still knows which way that is), or does he try a new direction? He has no idea what to do.\n\nLooking at the hills and dunes around him, he thinks he knows the direction he was heading before. Just going by a feeling, he points himself somewhat to the left of that, and starts walking.", false),("", "As he walks, the day starts heating up. The desert, too cold just a couple of hours before, soon becomes an oven again. He sweats a little at first, and then stops. He starts getting worried at that - when you stop sweating he knows that means you're in trouble - usually right before heat stroke.\n\nHe decides that it's time to try the windshield wiper fluid. He can't wait any longer - if he passes out, he's dead. He stops in the shade of a large rock, takes the bottle out, opens it, and takes a mouthful. He slowly swallows it, making it last as long as he can. It feels so good in his dry and cracked throat that he doesn't even care about the nasty taste. He takes another mouthful, and makes it last too. Slowly, he drinks half the bottle. He figures that since he's drinking it, he might as well drink enough to make some difference and keep himself from passing out.", false),("", "He's quit worrying about the denaturing of the wiper fluid. If it kills him, it kills him - if he didn't drink it, he'd die anyway. Besides, he's pretty sure that whatever substance they denature the fluid with is just designed to make you sick - their way of keeping winos from buying cheap wiper fluid for the ethanol content. He can handle throwing up, if it comes to that.", false)],&[("", "He walks. He walks in the hot, dry, windless desert. Sand, rocks, hills, dunes, the occasional scrawny cactus or dried bush. No sign of water. Sometimes he'll see a little movement to one side or the other, but whatever moved is usually gone before he can focus his eyes on it. Probably birds, lizards, or mice. Maybe snakes, though they usually move more at night. He's careful to stay away from the movements.\n\nAfter a while, he begins to stagger. He's not sure if it's fatigue, heat stroke finally catching him, or maybe he was wrong and the denaturing of the wiper fluid was worse than he thought. He tries to steady himself, and keep going.", false),("", "After more walking, he comes to a large stretch of sand. This is good! He knows he passed over a stretch of sand in the SUV - he remembers doing donuts in it. Or at least he thinks he remembers it - he's getting woozy enough and tired enough that he's not sure what he remembers any more or if he's hallucinating. But he thinks he remembers it. So he heads off into it, trying to get to the other side, hoping that it gets him closer to the town.\n\nHe was heading for a town, wasn't he? He thinks he was. He isn't sure any more. He's not even sure how long he's been walking any more. Is it still morning? Or has it moved into afternoon and the sun is going down again? It must be afternoon - it seems like it's been too long since he started out.\n\nHe walks through the sand.\n\nAfter a while, he comes to a big dune in the sand. This is bad. He doesn't remember any dunes when driving over the sand in his SUV. Or at least he doesn't think he remembers any. This is bad.", false),("", "But, he has no other direction to go. Too late to turn back now. He figures that he'll get to the top of the dune and see if he can see anything from there that helps him find the town. He keeps going up the dune.\n\nHalfway up, he slips in the bad footing of the sand for the second or third time, and falls to his knees. He doesn't feel like getting back up - he'll just fall down again. So, he keeps going up the dune on his hand and knees.", false),("", "While crawling, if his throat weren't so dry, he'd laugh. He's finally gotten to the hackneyed image of a man lost in the desert - crawling through the sand on his hands and knees. If would be the perfect image, he imagines, if only his clothes were more ragged. The people crawling through the desert in the cartoons always had ragged clothes. But his have lasted without any rips so far. Somebody will probably find his dessicated corpse half buried in the sand years from now, and his clothes will still be in fine shape - shake the sand out, and a good wash, and they'd be wearable again. He wishes his throat were wet enough to laugh. He coughs a little instead, and it hurts.", false),("", "He finally makes it to the top of the sand dune. Now that he's at the top, he struggles a little, but manages to stand up and look around. All he sees is sand. Sand, and more sand. Behind him, about a mile away, he thinks he sees the rocky ground he left to head into this sand. Ahead of him, more dunes, more sand. This isn't where he drove his SUV. This is Hell. Or close enough.", false),("", "Again, he doesn't know what to do. He decides to drink the rest of the wiper fluid while figuring it out. He takes out the bottle, and is removing the cap, when he glances to the side and sees something. Something in the sand. At the bottom of the dune, off to the side, he sees something strange. It's a flat area, in the sand. He stops taking the cap of the bottle off, and tries to look closer. The area seems to be circular. And it's dark - darker than the sand. And, there seems to be something in the middle of it, but he can't tell what it is. He looks as hard as he can, and still can tell from here. He's going to have to go down there and look.", false),("", "He puts the bottle back in his pocket, and starts to stumble down the dune. After a few steps, he realizes that he's in trouble - he's not going to be able to keep his balance. After a couple of more sliding, tottering steps, he falls and starts to roll down the dune. The sand it so hot when his body hits it that for a minute he thinks he's caught fire on the way down - like a movie car wreck flashing into flames as it goes over the cliff, before it ever even hits the ground. He closes his eyes and mouth, covers his face with his hands, and waits to stop rolling.\n\nHe stops, at the bottom of the dune. After a minute or two, he finds enough energy to try to sit up and get the sand out of his face and clothes. When he clears his eyes enough, he looks around to make sure that the dark spot in the sand it still there and he hadn't just imagined it.", false),("", "So, seeing the large, flat, dark spot on the sand is still there, he begins to crawl towards it. He'd get up and walk towards it, but he doesn't seem to have the energy to get up and walk right now. He must be in the final stages of dehydration he figures, as he crawls. If this place in the sand doesn't have water, he'll likely never make it anywhere else. This is his last chance.\n\nHe gets closer and closer, but still can't see what's in the middle of the dark area. His eyes won't quite focus any more for some reason. And lifting his head up to look takes so much effort that he gives up trying. He just keeps crawling.", false),("", "Finally, he reaches the area he'd seen from the dune. It takes him a minute of crawling on it before he realizes that he's no longer on sand - he's now crawling on some kind of dark stone. Stone with some kind of marking on it - a pattern cut into the stone. He's too tired to stand up and try to see what the pattern is - so he just keeps crawling. He crawls towards the center, where his blurry eyes still see something in the middle of the dark stone area.", false)],&[("", "His mind, detached in a strange way, notes that either his hands and knees are so burnt by the sand that they no longer feel pain, or that this dark stone, in the middle of a burning desert with a pounding, punishing sun overhead, doesn't seem to be hot. It almost feels cool. He considers lying down on the nice cool surface.\n\nCool, dark stone. Not a good sign. He must be hallucinating this. He's probably in the middle of a patch of sand, already lying face down and dying, and just imagining this whole thing. A desert mirage. Soon the beautiful women carrying pitchers of water will come up and start giving him a drink. Then he'll know he's gone.\n\nHe decides against laying down on the cool stone. If he's going to die here in the middle of this hallucination, he at least wants to see what's in the center before he goes. He keeps crawling.", false),("", "It's the third time that he hears the voice before he realizes what he's hearing. He would swear that someone just said, \"Greetings, traveler. You do not look well. Do you hear me?\"\n\nHe stops crawling. He tries to look up from where he is on his hands and knees, but it's too much effort to lift his head. So he tries something different - he leans back and tries to sit up on the stone. After a few seconds, he catches his balance, avoids falling on his face, sits up, and tries to focus his eyes. Blurry. He rubs his eyes with the back of his hands and tries again. Better this time.\n\nYep. He can see. He's sitting in the middle of a large, flat, dark expanse of stone. Directly next to him, about three feet away, is a white post or pole about two inches in diameter and sticking up about four or five feet out of the stone, at an angle.", false),("", "And wrapped around this white rod, tail with rattle on it hovering and seeming to be ready to start rattling, is what must be a fifteen foot long desert diamondback rattlesnake, looking directly at him.\n\nHe stares at the snake in shock. He doesn't have the energy to get up and run away. He doesn't even have the energy to crawl away. This is it, his final resting place. No matter what happens, he's not going to be able to move from this spot.\n\nWell, at least dying of a bite from this monster should be quicker than dying of thirst. He'll face his end like a man. He struggles to sit up a little straighter. The snake keeps watching him. He lifts one hand and waves it in the snake's direction, feebly. The snake watches the hand for a moment, then goes back to watching the man, looking into his eyes.\n\nHmmm. Maybe the snake had no interest in biting him? It hadn't rattled yet - that was a good sign. Maybe he wasn't going to die of snake bite after all.", false),("", "He then remembers that he'd looked up when he'd reached the center here because he thought he'd heard a voice. He was still very woozy - he was likely to pass out soon, the sun still beat down on him even though he was now on cool stone. He still didn't have anything to drink. But maybe he had actually heard a voice. This stone didn't look natural. Nor did that white post sticking up out of the stone. Someone had to have built this. Maybe they were still nearby. Maybe that was who talked to him. Maybe this snake was even their pet, and that's why it wasn't biting.", false),("", "He tries to clear his throat to say, \"Hello,\" but his throat is too dry. All that comes out is a coughing or wheezing sound. There is no way he's going to be able to talk without something to drink. He feels his pocket, and the bottle with the wiper fluid is still there. He shakily pulls the bottle out, almost losing his balance and falling on his back in the process. This isn't good. He doesn't have much time left, by his reckoning, before he passes out.\n\nHe gets the lid off of the bottle, manages to get the bottle to his lips, and pours some of the fluid into his mouth. He sloshes it around, and then swallows it. He coughs a little. His throat feels better. Maybe he can talk now.\n\nHe tries again. Ignoring the snake, he turns to look around him, hoping to spot the owner of this place, and croaks out, \"Hello? Is there anyone here?\"\n\nHe hears, from his side, \"Greetings. What is it that you want?\"", false),("", "He turns his head, back towards the snake. That's where the sound had seemed to come from. The only thing he can think of is that there must be a speaker, hidden under the snake, or maybe built into that post. He decides to try asking for help.\n\n\"Please,\" he croaks again, suddenly feeling dizzy, \"I'd love to not be thirsty any more. I've been a long time without water. Can you help me?\"\n\nLooking in the direction of the snake, hoping to see where the voice was coming from this time, he is shocked to see the snake rear back, open its mouth, and speak. He hears it say, as the dizziness overtakes him and he falls forward, face first on the stone, \"Very well. Coming up.\"", false),("", "A piercing pain shoots through his shoulder. Suddenly he is awake. He sits up and grabs his shoulder, wincing at the throbbing pain. He's momentarily disoriented as he looks around, and then he remembers - the crawl across the sand, the dark area of stone, the snake. He sees the snake, still wrapped around the tilted white post, still looking at him.\n\nHe reaches up and feels his shoulder, where it hurts. It feels slightly wet. He pulls his fingers away and looks at them - blood. He feels his shoulder again - his shirt has what feels like two holes in it - two puncture holes - they match up with the two aching spots of pain on his shoulder. He had been bitten. By the snake.\n\n\"It'll feel better in a minute.\" He looks up - it's the snake talking. He hadn't dreamed it. Suddenly he notices - he's not dizzy any more. And more importantly, he's not thirsty any more - at all!\n\n\"Have I died? Is this the afterlife? Why are you biting me in the afterlife?\"", false),("", "\"Sorry about that, but I had to bite you,\" says the snake. \"That's the way I work. It all comes through the bite. Think of it as natural medicine.\"", false)],&[("", "\"You bit me to help me? Why aren't I thirsty any more? Did you give me a drink before you bit me? How did I drink enough while unconscious to not be thirsty any more? I haven't had a drink for over two days. Well, except for the windshield wiper fluid... hold it, how in the world does a snake talk? Are you real? Are you some sort of Disney animation?\"\n\n\"No,\" says the snake, \"I'm real. As real as you or anyone is, anyway. I didn't give you a drink. I bit you. That's how it works - it's what I do. I bite. I don't have hands to give you a drink, even if I had water just sitting around here.\"", false),("", "The man sat stunned for a minute. Here he was, sitting in the middle of the desert on some strange stone that should be hot but wasn't, talking to a snake that could talk back and had just bitten him. And he felt better. Not great - he was still starving and exhausted, but much better - he was no longer thirsty. He had started to sweat again, but only slightly. He felt hot, in this sun, but it was starting to get lower in the sky, and the cool stone beneath him was a relief he could notice now that he was no longer dying of thirst.\n\n\"I might suggest that we take care of that methanol you now have in your system with the next request,\" continued the snake. \"I can guess why you drank it, but I'm not sure how much you drank, or how much methanol was left in the wiper fluid. That stuff is nasty. It'll make you go blind in a day or two, if you drank enough of it.\"\n\n\"Ummm, n-next request?\" said the man. He put his hand back on his hurting shoulder and backed away from the snake a little.", false),("", "\"That's the way it works. If you like, that is,\" explained the snake. \"You get three requests. Call them wishes, if you wish.\" The snake grinned at his own joke, and the man drew back a little further from the show of fangs.\n\n\"But there are rules,\" the snake continued. \"The first request is free. The second requires an agreement of secrecy. The third requires the binding of responsibility.\" The snake looks at the man seriously.\n\n\"By the way,\" the snake says suddenly, \"my name is Nathan. Old Nathan, Samuel used to call me. He gave me the name. Before that, most of the Bound used to just call me 'Snake'. But that got old, and Samuel wouldn't stand for it. He said that anything that could talk needed a name. He was big into names. You can call me Nate, if you wish.\" Again, the snake grinned. \"Sorry if I don't offer to shake, but I think you can understand - my shake sounds somewhat threatening.\" The snake give his rattle a little shake.", false),("", "\"Umm, my name is Jack,\" said the man, trying to absorb all of this. \"Jack Samson.\n\n\"Can I ask you a question?\" Jack says suddenly. \"What happened to the poison...umm, in your bite. Why aren't I dying now? How did you do that? What do you mean by that's how you work?\"\n\n\"That's more than one question,\" grins Nate. \"But I'll still try to answer all of them. First, yes, you can ask me a question.\" The snake's grin gets wider. \"Second, the poison is in you. It changed you. You now no longer need to drink. That's what you asked for. Or, well, technically, you asked to not be thirsty any more - but 'any more' is such a vague term. I decided to make it permanent - now, as long as you live, you shouldn't need to drink much at all. Your body will conserve water very efficiently. You should be able to get enough just from the food you eat - much like a creature of the desert. You've been changed.", false),("", "\"For the third question,\" Nate continues, \"you are still dying. Besides the effects of that methanol in your system, you're a man - and men are mortal. In your current state, I give you no more than about another 50 years. Assuming you get out of this desert, alive, that is.\" Nate seemed vastly amused at his own humor, and continued his wide grin.\n\n\"As for the fourth question,\" Nate said, looking more serious as far as Jack could tell, as Jack was just now working on his ability to read talking-snake emotions from snake facial features, \"first you have to agree to make a second request and become bound by the secrecy, or I can't tell you.\"\n\n\"Wait,\" joked Jack, \"isn't this where you say you could tell me, but you'd have to kill me?\"\n\n\"I thought that was implied.\" Nate continued to look serious.", false),("", "\"Ummm...yeah.\" Jack leaned back a little as he remembered again that he was talking to a fifteen foot poisonous reptile with a reputation for having a nasty temper. \"So, what is this 'Bound by Secrecy' stuff, and can you really stop the effects of the methanol?\" Jack thought for a second. \"And, what do you mean methanol, anyway? I thought these days they use ethanol in wiper fluid, and just denature it?\"\n\n\"They may, I don't really know,\" said Nate. \"I haven't gotten out in a while. Maybe they do. All I know is that I smell methanol on your breath and on that bottle in your pocket. And the blue color of the liquid when you pulled it out to drink some let me guess that it was wiper fluid. I assume that they still color wiper fluid blue?\"\n\n\"Yeah, they do,\" said Jack.", false),("", "\"I figured,\" replied Nate. \"As for being bound by secrecy - with the fulfillment of your next request, you will be bound to say nothing about me, this place, or any of the information I will tell you after that, when you decide to go back out to your kind. You won't be allowed to talk about me, write about me, use sign language, charades, or even act in a way that will lead someone to guess correctly about me. You'll be bound to secrecy. Of course, I'll also ask you to promise not to give me away, and as I'm guessing that you're a man of your word, you'll never test the binding anyway, so you won't notice.\" Nate said the last part with utter confidence.\n\nJack, who had always prided himself on being a man of his word, felt a little nervous at this. \"Ummm, hey, Nate, who are you? How did you know that? Are you, umm, omniscient, or something?\"", false)],&[("", "Well, Jack,\" said Nate sadly, \"I can't tell you that, unless you make the second request.\" Nate looked away for a minute, then looked back.\n\n\"Umm, well, ok,\" said Jack, \"what is this about a second request? What can I ask for? Are you allowed to tell me that?\"\n\n\"Sure!\" said Nate, brightening. \"You're allowed to ask for changes. Changes to yourself. They're like wishes, but they can only affect you. Oh, and before you ask, I can't give you immortality. Or omniscience. Or omnipresence, for that matter. Though I might be able to make you gaseous and yet remain alive, and then you could spread through the atmosphere and sort of be omnipresent. But what good would that be - you still wouldn't be omniscient and thus still could only focus on one thing at a time. Not very useful, at least in my opinion.\" Nate stopped when he realized that Jack was staring at him.", false),("", "\"Well, anyway,\" continued Nate, \"I'd probably suggest giving you permanent good health. It would negate the methanol now in your system, you'd be immune to most poisons and diseases, and you'd tend to live a very long time, barring accident, of course. And you'll even have a tendency to recover from accidents well. It always seemed like a good choice for a request to me.\"\n\n\"Cure the methanol poisoning, huh?\" said Jack. \"And keep me healthy for a long time? Hmmm. It doesn't sound bad at that. And it has to be a request about a change to me? I can't ask to be rich, right? Because that's not really a change to me?\"\n\n\"Right,\" nodded Nate.\n\n\"Could I ask to be a genius and permanently healthy?\" Jack asked, hopefully.\n\n\"That takes two requests, Jack.\"\n\n\"Yeah, I figured so,\" said Jack. \"But I could ask to be a genius? I could become the smartest scientist in the world? Or the best athlete?\"", false),("", "\"Well, I could make you very smart,\" admitted Nate, \"but that wouldn't necessarily make you the best scientist in the world. Or, I could make you very athletic, but it wouldn't necessarily make you the best athlete either. You've heard the saying that 99% of genius is hard work? Well, there's some truth to that. I can give you the talent, but I can't make you work hard. It all depends on what you decide to do with it.\"\n\n\"Hmmm,\" said Jack. \"Ok, I think I understand. And I get a third request, after this one?\"\n\n\"Maybe,\" said Nate, \"it depends on what you decide then. There are more rules for the third request that I can only tell you about after the second request. You know how it goes.\" Nate looked like he'd shrug, if he had shoulders.\n\n\"Ok, well, since I'd rather not be blind in a day or two, and permanent health doesn't sound bad, then consider that my second request. Officially. Do I need to sign in blood or something?\"", false),("", "\"No,\" said Nate. \"Just hold out your hand. Or heel.\" Nate grinned. \"Or whatever part you want me to bite. I have to bite you again. Like I said, that's how it works - the poison, you know,\" Nate said apologetically.\n\nJack winced a little and felt his shoulder, where the last bite was. Hey, it didn't hurt any more. Just like Nate had said. That made Jack feel better about the biting business. But still, standing still while a fifteen foot snake sunk it's fangs into you. Jack stood up. Ignoring how good it felt to be able to stand again, and the hunger starting to gnaw at his stomach, Jack tried to decide where he wanted to get bitten. Despite knowing that it wouldn't hurt for long, Jack knew that this wasn't going to be easy.\n\n\"Hey, Jack,\" Nate suddenly said, looking past Jack towards the dunes behind him, \"is that someone else coming up over there?\"\n\nJack spun around and looked. Who else could be out here in the middle of nowhere? And did they bring food?", false),("", "Wait a minute, there was nobody over there. What was Nate...\n\nJack let out a bellow as he felt two fangs sink into his rear end, through his jeans...\n\nJack sat down carefully, favoring his more tender buttock. \"I would have decided, eventually, Nate. I was just thinking about it. You didn't have to hoodwink me like that.\"\n\n\"I've been doing this a long time, Jack,\" said Nate, confidently. \"You humans have a hard time sitting still and letting a snake bite you - especially one my size. And besides, admit it - it's only been a couple of minutes and it already doesn't hurt any more, does it? That's because of the health benefit with this one. I told you that you'd heal quickly now.\"\n\n\"Yeah, well, still,\" said Jack, \"it's the principle of the thing. And nobody likes being bitten in the butt! Couldn't you have gotten my calf or something instead?\"\n\n\"More meat in the typical human butt,\" replied Nate. \"And less chance you accidentally kick me or move at the last second.\"", false),("", "\"Yeah, right. So, tell me all of these wonderful secrets that I now qualify to hear,\" answered Jack.\n\n\"Ok,\" said Nate. \"Do you want to ask questions first, or do you want me to just start talking?\"\n\n\"Just talk,\" said Jack. \"I'll sit here and try to not think about food.\"\n\n\"We could go try to rustle up some food for you first, if you like,\" answered Nate.\n\n\"Hey! You didn't tell me you had food around here, Nate!\" Jack jumped up. \"What do we have? Am I in walking distance to town? Or can you magically whip up food along with your other powers?\" Jack was almost shouting with excitement. His stomach had been growling for hours.\n\n\"I was thinking more like I could flush something out of its hole and bite it for you, and you could skin it and eat it. Assuming you have a knife, that is,\" replied Nate, with the grin that Jack was starting to get used to.", false),("", "\"Ugh,\" said Jack, sitting back down. \"I think I'll pass. I can last a little longer before I get desperate enough to eat desert rat, or whatever else it is you find out here. And there's nothing to burn - I'd have to eat it raw. No thanks. Just talk.\"\n\n\"Ok,\" replied Nate, still grinning. \"But I'd better hurry, before you start looking at me as food.", false)],&[("", "Nate reared back a little, looked around for a second, and then continued. \"You, Jack, are sitting in the middle of the Garden of Eden.\"\n\nJack looked around at the sand and dunes and then looked back at Nate sceptically.\n\n\"Well, that's the best I can figure it, anyway, Jack,\" said Nate. \"Stand up and look at the symbol on the rock here.\" Nate gestured around the dark stone they were both sitting on with his nose.\n\nJack stood up and looked. Carved into the stone in a bas-relief was a representation of a large tree. The angled-pole that Nate was wrapped around was coming out of the trunk of the tree, right below where the main branches left the truck to reach out across the stone. It was very well done - it looked more like a tree had been reduced to almost two dimensions and embedded in the stone than it did like a carving.\n\nJack walked around and looked at the details in the fading light of the setting sun. He wished he'd looked at it while the sun was higher in the sky.", false),("", "Wait! The sun was setting! That meant he was going to have to spend another night out here! Arrrgh!\n\nJack looked out across the desert for a little bit, and then came back and stood next to Nate. \"In all the excitement, I almost forgot, Nate,\" said Jack. \"Which way is it back to town? And how far? I'm eventually going to have to head back - I'm not sure I'll be able to survive by eating raw desert critters for long. And even if I can, I'm not sure I'll want to.\"\n\n\"It's about 30 miles that way.\" Nate pointed, with the rattle on his tail this time. As far as Jack could tell, it was a direction at right angles to the way he'd been going when he was crawling here. \"But that's 30 miles by the way the crow flies. It's about 40 by the way a man walks. You should be able to do it in about half a day with your improved endurance, if you head out early tomorrow, Jack.\"", false),("", "Jack looked out the way the snake had pointed for a few seconds more, and then sat back down. It was getting dark. Not much he could do about heading out right now. And besides, Nate was just about to get to the interesting stuff. \"Garden of Eden? As best as you can figure it?\"\n\n\"Well, yeah, as best as I and Samuel could figure it anyway,\" said Nate. \"He figured that the story just got a little mixed up. You know, snake, in a 'tree', offering 'temptations', making bargains. That kind stuff. But he could never quite figure out how the Hebrews found out about this spot from across the ocean. He worried about that for a while.\"\n\n\"Garden of Eden, hunh?\" said Jack. \"How long have you been here, Nate?\"\n\n\"No idea, really,\" replied Nate. \"A long time. It never occurred to me to count years, until recently, and by then, of course, it was too late. But I do remember when this whole place was green, so I figure it's been thousands of years, at least.\"\n\n\"So, are you the snake that tempted Eve?\" said Jack.", false),("", "\"Beats me,\" said Nate. \"Maybe. I can't remember if the first one of your kind that I talked to was female or not, and I never got a name, but it could have been. And I suppose she could have considered my offer to grant requests a 'temptation', though I've rarely had refusals.\"\n\n\"Well, umm, how did you get here then? And why is that white pole stuck out of the stone there?\" asked Jack.\n\n\"Dad left me here. Or, I assume it was my dad. It was another snake - much bigger than I was back then. I remember talking to him, but I don't remember if it was in a language, or just kind of understanding what he wanted. But one day, he brought me to this stone, told me about it, and asked me to do something for him. I talked it over with him for a while, then agreed. I've been here ever since.\n\n\"What is this place?\" said Jack. \"And what did he ask you to do?\"", false),("", "\"Well, you see this pole here, sticking out of the stone?\" Nate loosened his coils around the tilted white pole and showed Jack where it descended into the stone. The pole was tilted at about a 45 degree angle and seemed to enter the stone in an eighteen inch slot cut into the stone. Jack leaned over and looked. The slot was dark and the pole went down into it as far as Jack could see in the dim light. Jack reached out to touch the pole, but Nate was suddenly there in the way.\n\n\"You can't touch that yet, Jack,\" said Nate.\n\n\"Why not?\" asked Jack.\n\n\"I haven't explained it to you yet,\" replied Nate.\n\n\"Well, it kinda looks like a lever or something,\" said Jack. \"You'd push it that way, and it would move in the slot.\"\n\n\"Yep, that's what it is,\" replied Nate.\n\n\"What does it do?\" asked Jack. \"End the world?\"", false),("", "\"Oh, no,\" said Nate. \"Nothing that drastic. It just ends humanity. I call it 'The Lever of Doom'.\" For the last few words Nate had used a deeper, ringing voice. He tried to look serious for a few seconds, and then gave up and grinned.\n\nJack was initially startled by Nate's pronouncement, but when Nate grinned Jack laughed. \"Ha! You almost had me fooled for a second there. What does it really do?\"\n\n\"Oh, it really ends humanity, like I said,\" smirked Nate. \"I just thought the voice I used was funny, didn't you?\"\n\nNate continued to grin.\n\n\"A lever to end humanity?\" asked Jack. \"What in the world is that for? Why would anyone need to end humanity?\"\n\n\"Well,\" replied Nate, \"I get the idea that maybe humanity was an experiment. Or maybe the Big Guy just thought, that if humanity started going really bad, there should be a way to end it. I'm not really sure. All I know are the rules, and the guesses that Samuel and I had about why it's here. I didn't think to ask back when I started here.\"", false),("", "\"Rules? What rules?\" asked Jack.\n\n\"The rules are that I can't tell anybody about it or let them touch it unless they agree to be bound to secrecy by a bite. And that only one human can be bound in that way at a time. That's it.\" explained Nate.", false)],&[("", "Jack looked somewhat shocked. \"You mean that I could pull the lever now? You'd let me end humanity?\"\n\n\"Yep,\" replied Nate, \"if you want to.\" Nate looked at Jack carefully. \"Do you want to, Jack?\"\n\n\"Umm, no.\" said Jack, stepping a little further back from the lever. \"Why in the world would anyone want to end humanity? It'd take a psychotic to want that! Or worse, a suicidal psychotic, because it would kill him too, wouldn't it?\"\n\n\"Yep,\" replied Nate, \"being as he'd be human too.\"\n\n\"Has anyone ever seriously considered it?\" asked Nate. \"Any of those bound to secrecy, that is?\"\n\n\"Well, of course, I think they've all seriously considered it at one time or another. Being given that kind of responsibility makes you sit down and think, or so I'm told. Samuel considered it several times. He'd often get disgusted with humanity, come out here, and just hold the lever for a while. But he never pulled it. Or you wouldn't be here.\" Nate grinned some more.", false),("", "Jack sat down, well back from the lever. He looked thoughtful and puzzled at the same time. After a bit, he said, \"So this makes me the Judge of humanity? I get to decide whether they keep going or just end? Me?\"\n\n\"That seems to be it,\" agreed Nate.\n\n\"What kind of criteria do I use to decide?\" said Jack. \"How do I make this decision? Am I supposed to decide if they're good? Or too many of them are bad? Or that they're going the wrong way? Is there a set of rules for that?\"\n\n\"Nope,\" replied Nate. \"You pretty much just have to decide on your own. It's up to you, however you want to decide it. I guess that you're just supposed to know.\"\n\n\"But what if I get mad at someone? Or some girl dumps me and I feel horrible? Couldn't I make a mistake? How do I know that I won't screw up?\" protested Jack.\n\nNate gave his kind of snake-like shrug again. \"You don't. You just have to try your best, Jack.\"\n\nJack sat there for a while, staring off into the desert that was rapidly getting dark, chewing on a fingernail.", false),("", "Suddenly, Jack turned around and looked at the snake. \"Nate, was Samuel the one bound to this before me?\"\n\n\"Yep,\" replied Nate. \"He was a good guy. Talked to me a lot. Taught me to read and brought me books. I think I still have a good pile of them buried in the sand around here somewhere. I still miss him. He died a few months ago.\"\n\n\"Sounds like a good guy,\" agreed Jack. \"How did he handle this, when you first told him. What did he do?\"\n\n\"Well,\" said Nate, \"he sat down for a while, thought about it for a bit, and then asked me some questions, much like you're doing.\"\n\n\"What did he ask you, if you're allowed to tell me?\" asked Jack.\n\n\"He asked me about the third request,\" replied Nate.\n\n\"Aha!\" It was Jack's turn to grin. \"And what did you tell him?\"", false),("", "\"I told him the rules for the third request. That to get the third request you have to agree to this whole thing. That if it ever comes to the point that you really think that humanity should be ended, that you'll come here and end it. You won't avoid it, and you won't wimp out.\" Nate looked serious again. \"And you'll be bound to do it too, Jack.\"\n\n\"Hmmm.\" Jack looked back out into the darkness for a while.\n\nNate watched him, waiting.\n\n\"Nate,\" continued Jack, quietly, eventually. \"What did Samuel ask for with his third request?\"\n\nNate sounded like he was grinning again as he replied, also quietly, \"Wisdom, Jack. He asked for wisdom. As much as I could give him.\"\n\n\"Ok,\" said Jack, suddenly, standing up and facing away from Nate, \"give it to me.\n\nNate looked at Jack's backside. \"Give you what, Jack?\"\n\n\"Give me that wisdom. The same stuff that Samuel asked for. If it helped him, maybe it'll help me too.\" Jack turned his head to look back over his shoulder at Nate. \"It did help him, right?\"", false),("", "\"He said it did,\" replied Nate. \"But he seemed a little quieter afterward. Like he had a lot to think about.\"\n\n\"Well, yeah, I can see that,\" said Jack. \"So, give it to me.\" Jack turned to face away from Nate again, bent over slightly and tensed up.\n\nNate watched Jack tense up with a little exasperation. If he bit Jack now, Jack would likely jump out of his skin and maybe hurt them both.\n\n\"You remember that you'll be bound to destroy humanity if it ever looks like it needs it, right Jack?\" asked Nate, shifting position.\n\n\"Yeah, yeah, I got that,\" replied Jack, eyes squeezed tightly shut and body tense, not noticing the change in direction of Nate's voice.\n\n\"And,\" continued Nate, from his new position, \"do you remember that you'll turn bright purple, and grow big horns and extra eyes?\"", false),("", "\"Yeah, yeah...Hey, wait a minute!\" said Jack, opening his eyes, straightening up and turning around. \"Purple?!\" He didn't see Nate there. With the moonlight Jack could see that the lever extended up from its slot in the rock without the snake wrapped around it.\n\nJack heard, from behind him, Nate's \"Just Kidding!\" right before he felt the now familiar piercing pain, this time in the other buttock.\n\nJack sat on the edge of the dark stone in the rapidly cooling air, his feet extending out into the sand. He stared out into the darkness, listening to the wind stir the sand, occasionally rubbing his butt where he'd been recently bitten.\n\nNate had left for a little while, had come back with a desert-rodent-shaped bulge somewhere in his middle, and was now wrapped back around the lever, his tongue flicking out into the desert night's air the only sign that he was still awake.\n\nOccasionally Jack, with his toes absentmindedly digging in the sand while he thought, would ask Nate a question without turning around.", false),("", "\"Nate, do accidents count?\"\n\nNate lifted his head a little bit. \"What do you mean, Jack?\"\n\nJack tilted his head back like he was looking at the stars. \"You know, accidents. If I accidentally fall on the lever, without meaning to, does that still wipe out humanity?\"\n\n\"Yeah, I'm pretty sure it does, Jack. I'd suggest you be careful about that if you start feeling wobbly,\" said Nate with some amusement.", false)],&[("", "A little later - \"Does it have to be me that pulls the lever?\" asked Jack.\n\n\"That's the rule, Jack. Nobody else can pull it,\" answered Nate.\n\n\"No,\" Jack shook his head, \"I meant does it have to be my hand? Could I pull the lever with a rope tied around it? Or push it with a stick? Or throw a rock?\"\n\n\"Yes, those should work,\" replied Nate. \"Though I'm not sure how complicated you could get. Samuel thought about trying to build some kind of remote control for it once, but gave it up. Everything he'd build would be gone by the next sunrise, if it was touching the stone, or over it. I told him that in the past others that had been bound had tried to bury the lever so they wouldn't be tempted to pull it, but every time the stones or sand or whatever had disappeared.\"\n\n\"Wow,\" said Jack, \"Cool.\" Jack leaned back until only his elbows kept him off of the stone and looked up into the sky.\n\n\"Nate, how long did Samuel live? One of his wishes was for health too, right?\" asked Jack.", false),("", "\"Yes,\" replied Nate, \"it was. He lived 167 years, Jack.\"\n\n\"Wow, 167 years. That's almost 140 more years I'll live if I live as long. Do you know what he died of, Nate?\"\n\n\"He died of getting tired of living, Jack,\" Nate said, sounding somewhat sad.\n\nJack turned his head to look at Nate in the starlight.\n\nNate looked back. \"Samuel knew he wasn't going to be able to stay in society. He figured that they'd eventually see him still alive and start questioning it, so he decided that he'd have to disappear after a while. He faked his death once, but changed his mind - he decided it was too early and he could stay for a little longer. He wasn't very fond of mankind, but he liked the attention. Most of the time, anyway.\n\n\"His daughter and then his wife dying almost did him in though. He didn't stay in society much longer after that. He eventually came out here to spend time talking to me and thinking about pulling the lever. A few months ago he told me he'd had enough. It was his time.\"", false),("", "\"And then he just died?\" asked Jack.\n\nNate shook his head a little. \"He made his forth request, Jack. There's only one thing you can ask for the fourth request. The last bite.\n\nAfter a bit Nate continued, \"He told me that he was tired, that it was his time. He reassured me that someone new would show up soon, like they always had.\n\nAfter another pause, Nate finished, \"Samuel's body disappeared off the stone with the sunrise.\"\n\nJack lay back down and looked at the sky, leaving Nate alone with his memories. It was a long time until Jack's breathing evened out into sleep.\n\nJack woke with the sunrise the next morning. He was a little chilled with the morning desert air, but overall was feeling pretty good. Well, except that his stomach was grumbling and he wasn't willing to eat raw desert rat.", false),("", "So, after getting directions to town from Nate, making sure he knew how to get back, and reassuring Nate that he'd be back soon, Jack started the long walk back to town. With his new health and Nate's good directions, he made it back easily.\n\nJack caught a bus back to the city, and showed up for work the next day, little worse for the wear and with a story about getting lost in the desert and walking back out. Within a couple of days Jack had talked a friend with a tow truck into going back out into the desert with him to fetch the SUV. They found it after a couple of hours of searching and towed it back without incident. Jack was careful not to even look in the direction of Nate's lever, though their path back didn't come within sight of it.\n\nBefore the next weekend, Jack had gone to a couple of stores, including a book store, and had gotten his SUV back from the mechanic, with a warning to avoid any more joyriding in the desert. On Saturday, Jack headed back to see Nate.", false),("", "Jack parked a little way out of the small town near Nate, loaded up his new backpack with camping gear and the things he was bringing for Nate, and then started walking. He figured that walking would leave the least trail, and he knew that while not many people camped in the desert, it wasn't unheard of, and shouldn't really raise suspicions.\n\nJack had brought more books for Nate - recent books, magazines, newspapers. Some things that would catch Nate up with what was happening in the world, others that were just good books to read. He spent the weekend with Nate, and then headed out again, telling Nate that he'd be back again soon, but that he had things to do first.", false),("", "Over four months later Jack was back to see Nate again. This time he brought a laptop with him - a specially modified laptop. It had a solar recharger, special filters and seals to keep out the sand, a satellite link-up, and a special keyboard and joystick that Jack hoped that a fifteen-foot rattlesnake would be able to use. And, it had been hacked to not give out its location to the satellite.\n\nAfter that Jack could e-mail Nate to keep in touch, but still visited him fairly regularly - at least once or twice a year.\n\nAfter the first year, Jack quit his job. For some reason, with the wisdom he 'd been given, and the knowledge that he could live for over 150 years, working in a nine to five job for someone else didn't seem that worthwhile any more. Jack went back to school.", false),("", "Eventually, Jack started writing. Perhaps because of the wisdom, or perhaps because of his new perspective, he wrote well. People liked what he wrote, and he became well known for it. After a time, Jack bought an RV and started traveling around the country for book signings and readings.\n\nBut, he still remembered to drop by and visit Nate occasionally.", false)],&[("", "On one of the visits Nate seemed quieter than usual. Not that Nate had been a fountain of joy lately. Jack's best guess was that Nate was still missing Samuel, and though Jack had tried, he still hadn't been able to replace Samuel in Nate's eyes. Nate had been getting quieter each visit. But on this visit Nate didn't even speak when Jack walked up to the lever. He nodded at Jack, and then went back to staring into the desert. Jack, respecting Nate's silence, sat down and waited.\n\nAfter a few minutes, Nate spoke. \"Jack, I have someone to introduce you to.\"\n\nJack looked surprised. \"Someone to introduce me to?\" Jack looked around, and then looked carefully back at Nate. \"This something to do with the Big Guy?\n\n\"No, no,\" replied Nate. \"This is more personal. I want you to meet my son.\" Nate looked over at the nearest sand dune. \"Sammy!\"\n\nJack watched as a four foot long desert rattlesnake crawled from behind the dune and up to the stone base of the lever.\n\n\"Yo, Jack,\" said the new, much smaller snake.", false),("", "\"Yo, Sammy\" replied Jack. Jack looked at Nate. \"Named after Samuel, I assume?\"\n\nNate nodded. \"Jack, I've got a favor to ask you. Could you show Sammy around for me?\" Nate unwrapped himself from the lever and slithered over to the edge of the stone and looked across the sands. \"When Samuel first told me about the world, and brought me books and pictures, I wished that I could go see it. I wanted to see the great forests, the canyons, the cities, even the other deserts, to see if they felt and smelled the same. I want my son to have that chance - to see the world. Before he becomes bound here like I have been.\n\n\"He's seen it in pictures, over the computer that you brought me. But I hear that it's not the same. That being there is different. I want him to have that. Think you can do that for me, Jack?\"", false),("", "Jack nodded. This was obviously very important to Nate, so Jack didn't even joke about taking a talking rattlesnake out to see the world. \"Yeah, I can do that for you, Nate. Is that all you need?\" Jack could sense that was something more.\n\nNate looked at Sammy. Sammy looked back at Nate for a second and then said, \"Oh, yeah. Ummm, I've gotta go pack. Back in a little bit Jack. Nice to meet ya!\" Sammy slithered back over the dune and out of sight.\n\nNate watched Sammy disappear and then looked back at Jack. \"Jack, this is my first son. My first offspring through all the years. You don't even want to know what it took for me to find a mate.\" Nate grinned to himself. \"But anyway, I had a son for a reason. I'm tired. I'm ready for it to be over. I needed a replacement.\"\n\nJack considered this for a minute. \"So, you're ready to come see the world, and you wanted him to watch the lever while you were gone?\"", false),("", "Nate shook his head. \"No, Jack - you're a better guesser than that. You've already figured out - I'm bound here - there's only one way for me to leave here. And I'm ready. It's my time to die.\"\n\nJack looked more closely at Nate. He could tell Nate had thought about this - probably for quite a while. Jack had trouble imagining what it would be like to be as old as Nate, but Jack could already tell that in another hundred or two hundred years, he might be getting tired of life himself. Jack could understand Samuel's decision, and now Nate's. So, all Jack said was, \"What do you want me to do?\"\n\nNate nodded. \"Thanks, Jack. I only want two things. One - show Sammy around the world - let him get his fill of it, until he's ready to come back here and take over. Two - give me the fourth request.", false),("", "\"I can't just decide to die, not any more than you can. I won't even die of old age like you eventually will, even though it'll be a long time from now. I need to be killed. Once Sammy is back here, ready to take over, I'll be able to die. And I need you to kill me.\n\n\"I've even thought about how. Poisons and other drugs won't work on me. And I've seen pictures of snakes that were shot - some of them live for days, so that's out too. So, I want you to bring back a sword.\n\nNate turned away to look back to the dune that Sammy had gone behind. \"I'd say an axe, but that's somewhat undignified - putting my head on the ground or a chopping block like that. No, I like a sword. A time-honored way of going out. A dignified way to die. And, most importantly, it should work, even on me.\n\n\"You willing to do that for me, Jack?\" Nate turned back to look at Jack.\n\n\"Yeah, Nate,\" replied Jack solemnly, \"I think I can handle that.\"", false),("", "Nate nodded. \"Good!\" He turned back toward the dune and shouted, \"Sammy! Jack's about ready to leave!\" Then quietly, \"Thanks, Jack.\"", false),("", "Jack didn't have anything to say to that, so he waited for Sammy to make it back to the lever, nodded to him, nodded a final time to Nate, and then headed into the desert with Sammy following. Over the next several years Sammy and Jack kept in touch with Nate through e-mail as they went about their adventures. They made a goal of visiting every country in the world, and did a respectable job of it. Sammy had a natural gift for languages, as Jack expected he would, and even ended up acting as a translator for Jack in a few of the countries. Jack managed to keep the talking rattlesnake hidden, even so, and by the time they were nearing the end of their tour of countries, Sammy had only been spotted a few times. While there were several people that had seen enough to startle them greatly, nobody had enough evidence to prove anything, and while a few wild rumors and storied followed Jack and Sammy around, nothing ever hit the newspapers or the public in general.", false)],&[("", "When they finished the tour of countries, Jack suggested that they try some undersea diving. They did. And spelunking. They did that too. Sammy finally drew the line at visiting Antarctica. He'd come to realize that Jack was stalling. After talking to his Dad about it over e-mail, he figured out that Jack probably didn't want to have to kill Nate. Nate told Sammy that humans could be squeamish about killing friends and acquaintances.", false),("", "So, Sammy eventually put his tail down (as he didn't have a foot) and told Jack that it was time - he was ready to go back and take up his duties from his dad. Jack, delayed it a little more by insisting that they go back to Japan and buy an appropriate sword. He even stretched it a little more by getting lessons in how to use the sword. But, eventually, he'd learned as much as he was likely to without dedicating his life to it, and was definitely competent enough to take the head off of a snake. It was time to head back and see Nate.\n\nWhen they got back to the US, Jack got the old RV out of storage where he and Sammy had left it after their tour of the fifty states, he loaded up Sammy and the sword, and they headed for the desert.", false),("", "When they got to the small town that Jack had been trying to find those years ago when he'd met Nate, Jack was in a funk. He didn't really feel like walking all of the way out there. Not only that, but he'd forgotten to figure the travel time correctly, and it was late afternoon. They'd either have to spend the night in town and walk out tomorrow, or walk in the dark.\n\nAs Jack was afraid that if he waited one more night he might lose his resolve, he decided that he'd go ahead and drive the RV out there. It was only going to be this once, and Jack would go back and cover the tracks afterward. They ought to be able to make it out there by nightfall if they drove, and then they could get it over tonight.\n\nJack told Sammy to e-mail Nate that they were coming as he drove out of sight of the town on the road. They then pulled off the road and headed out into the desert.", false),("", "Everything went well, until they got to the sand dunes. Jack had been nursing the RV along the whole time, over the rocks, through the creek beds, revving the engine the few times they almost got stuck. When they came to the dunes, Jack didn't really think about it, he just downshifted and headed up the first one. By the third dune, Jack started to regret that he'd decided to try driving on the sand. The RV was fishtailing and losing traction. Jack was having to work it up each dune slowly and was trying to keep from losing control each time they came over the top and slid down the other side. Sammy had come up to sit in the passenger seat, coiled up and laughing at Jack's driving.\n\nAs they came over the top of the fourth dune, the biggest one yet, Jack saw that this was the final dune - the stone, the lever, and somewhere Nate, waited below. Jack put on the brakes, but he'd gone a little too far. The RV started slipping down the other side.", false),("", "Jack tried turning the wheel, but he didn't have enough traction. He pumped the brakes - no response. They started sliding down the hill, faster and faster.\n\nJack felt a shock go through him as he suddenly realized that they were heading for the lever. He looked down - the RV was directly on course for it. If Jack didn't do something, the RV would hit it. He was about to end humanity.\n\nJack steered more frantically, trying to get traction. It still wasn't working. The dune was too steep, and the sand too loose. In a split second, Jack realized that his only chance would be once he hit the stone around the lever - he should have traction on the stone for just a second before he hit the lever - he wouldn't have time to stop, but he should be able to steer away.\n\nJack took a better grip on the steering wheel and tried to turn the RV a little bit - every little bit would help. He'd have to time his turn just right.", false),("", "The RV got to the bottom of the dune, sliding at an amazing speed in the sand. Just before they reached the stone Jack looked across it to check that they were still heading for the lever. They were. But Jack noticed something else that he hadn't seen from the top of the dune. Nate wasn't wrapped around the lever. He was off to the side of the lever, but still on the stone, waiting for them. The problem was, he was waiting on the same side of the lever that Jack had picked to steer towards to avoid the lever. The RV was already starting to drift that way a little in its mad rush across the sand and there was no way that Jack was going to be able to go around the lever to the other side.", false),("", "||Jack had an instant of realization. He was either going to have to hit the lever, or run over Nate. He glanced over at Sammy and saw that Sammy realized the same thing.||\n\n||Jack took a firmer grip on the steering wheel as the RV ran up on the stone. Shouting to Sammy as he pulled the steering wheel, \"BETTER NATE THAN LEVER,\" he ran over the snake.||\n\n\n||THE END||", false)]]];
\ No newline at end of file
@@ -0,0 +1,176 @@
+/// A word or phrase that sets off a responder when it turns up anywhere in a message,
+/// but only as whole words, so "book" doesn't go off for "Facebook" or "notebook".
+pub struct Keyword {
+    /// Lowercase, can be more than one word
+    pub text: &'static str,
+    /// Also match "books", "book's" and so on
+    pub plurals: bool,
+    /// Phrases where the keyword means something else, e.g. "book a table". The keyword is only ignored inside them.
+    pub ignore: &'static [&'static str]
+}
+
+impl Keyword {
+    pub const fn new(text: &'static str) -> Self {
+        Self { text, plurals: false, ignore: &[] }
+    }
+
+    pub const fn with_plurals(self) -> Self {
+        Self { plurals: true, ..self }
+    }
+
+    pub const fn ignoring(self, ignore: &'static [&'static str]) -> Self {
+        Self { ignore, ..self }
+    }
+
+    pub fn matches(&self, message: &str) -> bool {
+        contains_keyword(message, self.text, self.plurals, self.ignore)
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '@' || c == '\'' || c == '’'
+}
+
+/// Splits a message into lowercase words. "@" stays attached so "@everyone" is one word,
+/// and apostrophes stay inside words but not around them.
+pub fn tokenize(message: &str) -> Vec<String> {
+    message
+        .split(|c: char| !is_word_char(c))
+        .map(|token| token.trim_matches(|c| c == '\'' || c == '’').to_lowercase())
+        .filter(|token| !token.is_empty())
+        .collect()
+}
+
+/// "book" matches "books", "book's", "books'", and "box" matches "boxes"
+fn is_form_of(token: &str, word: &str, plurals: bool) -> bool {
+    if token == word {
+        return true;
+    }
+    if !plurals {
+        return false;
+    }
+    match token.strip_prefix(word) {
+        Some(ending) => ["s", "es", "'s", "’s", "s'", "s’"].contains(&ending),
+        None => false
+    }
+}
+
+/// Everywhere the phrase starts in the tokens. Plurals only apply to its last word, like "board games".
+fn phrase_positions(tokens: &[String], phrase: &[String], plurals: bool) -> Vec<usize> {
+    if phrase.is_empty() || phrase.len() > tokens.len() {
+        return Vec::new();
+    }
+    (0..=tokens.len() - phrase.len()).filter(|&start| {
+        phrase.iter().enumerate().all(|(offset, word)| {
+            let is_last = offset == phrase.len() - 1;
+            is_form_of(&tokens[start + offset], word, plurals && is_last)
+        })
+    }).collect()
+}
+
+/// Whether the keyword appears as whole words anywhere other than inside one of the ignored phrases.
+pub fn contains_keyword(message: &str, keyword: &str, plurals: bool, ignore: &[&str]) -> bool {
+    let tokens = tokenize(message);
+    let keyword = tokenize(keyword);
+    let ignored_ranges: Vec<(usize, usize)> = ignore.iter().flat_map(|phrase| {
+        let phrase = tokenize(phrase);
+        phrase_positions(&tokens, &phrase, false).into_iter().map(move |start| (start, start + phrase.len()))
+    }).collect();
+
+    phrase_positions(&tokens, &keyword, plurals).into_iter().any(|start| {
+        let end = start + keyword.len();
+        !ignored_ranges.iter().any(|&(ignore_start, ignore_end)| ignore_start <= start && end <= ignore_end)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::find_command;
+
+    /// Which command a message sets off, the same way `commands::dispatch` picks
+    fn command_for(message: &str) -> Option<&'static str> {
+        find_command(&message.to_lowercase()).map(|(command, _)| command.name)
+    }
+
+    #[test]
+    fn tokenize_keeps_mentions_and_apostrophes() {
+        assert_eq!(tokenize("Hey @everyone, it's Hodgey's 'book'!"), ["hey", "@everyone", "it's", "hodgey's", "book"]);
+        assert_eq!(tokenize("notebook/Facebook...songbird"), ["notebook", "facebook", "songbird"]);
+        assert!(tokenize("?! ...").is_empty());
+    }
+
+    #[test]
+    fn whole_words_only() {
+        assert!(contains_keyword("i read a book", "book", false, &[]));
+        assert!(contains_keyword("BOOK.", "book", false, &[]));
+        assert!(!contains_keyword("check my facebook", "book", false, &[]));
+        assert!(!contains_keyword("where's my notebook", "book", false, &[]));
+        assert!(!contains_keyword("a bookworm", "book", false, &[]));
+    }
+
+    #[test]
+    fn plurals_and_possessives() {
+        assert!(contains_keyword("so many books", "book", true, &[]));
+        assert!(contains_keyword("the book's cover", "book", true, &[]));
+        assert!(contains_keyword("the horses’ field", "horse", true, &[]));
+        assert!(contains_keyword("two boxes", "box", true, &[]));
+        assert!(!contains_keyword("so many books", "book", false, &[]));
+        assert!(!contains_keyword("booking", "book", true, &[]));
+        assert!(!contains_keyword("bookshelf", "book", true, &[]));
+    }
+
+    #[test]
+    fn phrases() {
+        assert!(contains_keyword("ok hodgey decide for me", "hodgey decide", false, &[]));
+        assert!(!contains_keyword("hodgey, please decide", "hodgey decide", false, &[]));
+        assert!(contains_keyword("we played board games", "board game", true, &[]));
+    }
+
+    #[test]
+    fn ignored_phrases() {
+        let ignore = ["book a", "book an"];
+        assert!(!contains_keyword("can you book a table", "book", true, &ignore));
+        assert!(contains_keyword("what a good book", "book", true, &ignore));
+        //Only the use inside the phrase is ignored
+        assert!(contains_keyword("book a table and bring a book", "book", true, &ignore));
+    }
+
+    #[test]
+    fn realistic_messages() {
+        let cases = [
+            ("anyone up for chess later?", Some("chess")),
+            ("Chess.com is down again", Some("chess")),
+            ("I'd rather play checkers", Some("checkers")),
+            ("look at that horse!", Some("horse")),
+            ("my horses are hungry", Some("horse")),
+            ("Let's go to the castle", Some("castle")),
+            ("Newcastle won last night", None),
+            ("castles in the sky", Some("castle")),
+            ("reading a book", Some("book")),
+            ("Facebook is down", None),
+            ("left my notebook at home", None),
+            ("can you book a table for 6", None),
+            ("put on some music", Some("music")),
+            ("musical theatre", None),
+            ("this song slaps", Some("music")),
+            ("a songbird outside", None),
+            ("new songs dropped", Some("music")),
+            ("movie night?", Some("movie")),
+            ("moviegoers", None),
+            ("hey @everyone", Some("@everyone")),
+            ("@here meeting now", Some("@here")),
+            ("ping @someone please", Some("@someone")),
+            ("hodgey decide pizza or pasta", Some("hodgey decide")),
+            ("somewhere over there", None),
+            ("this is a checkered past", None),
+            ("Who's there?", None),
+            //Commands still win over keywords
+            ("hodgey help chess", Some("hodgey help")),
+            ("chess new", Some("chess new"))
+        ];
+        for (message, expected) in cases {
+            assert_eq!(command_for(message), expected, "{message}");
+        }
+    }
+}
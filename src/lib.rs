@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use serenity::all::{ChannelPinsUpdateEvent, Emoji, EmojiId, Guild, GuildChannel, GuildId, Interaction, Member, Role, RoleId, User};
+use serenity::async_trait;
+use serenity::client::ClientBuilder;
+use serenity::model::channel::Message;
+use serenity::model::gateway::Ready;
+use serenity::prelude::*;
+use tracing::{error, info};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+pub mod chess;
+use chess::ChessGames;
+pub mod chess_commands;
+pub mod checkers;
+use checkers::CheckersGames;
+pub mod games;
+use games::TurnBasedGames;
+pub mod connect_four;
+use connect_four::ConnectFour;
+pub mod tic_tac_toe;
+use tic_tac_toe::TicTacToe;
+pub mod game_commands;
+pub mod commands;
+use commands::Incoming;
+pub mod config;
+pub mod content;
+use content::{Content, Contents, QuoteKind};
+use config::{ConfigStore, GuildConfig, GuildConfigs};
+pub mod config_commands;
+pub mod custom_triggers;
+pub mod keywords;
+pub mod templates;
+pub mod locale;
+pub mod general_commands;
+pub mod transport;
+use transport::{MessageTransport, Transport};
+pub mod rate_limit;
+use rate_limit::{RateLimiter, RateLimiters, SystemClock};
+pub mod message_channel;
+pub mod emojis;
+use emojis::EmojiSnapshots;
+pub mod roles;
+use roles::RoleNames;
+pub mod rng;
+use rng::SharedRng;
+pub mod permissions;
+pub mod settings;
+pub mod help;
+pub mod slash_commands;
+pub mod quotes;
+pub mod jokes;
+#[cfg(test)]
+mod command_tests;
+
+pub const HODGEY_BOT_ID: u64 = 873373606900559943;
+
+/// Decides what events the bot will be notified about
+pub fn intents() -> GatewayIntents {
+    GatewayIntents::GUILD_MESSAGES | GatewayIntents::MESSAGE_CONTENT
+    | GatewayIntents::GUILD_MEMBERS //| GatewayIntents::GUILD_PRESENCES
+    | GatewayIntents::GUILDS | GatewayIntents::DIRECT_MESSAGES | GatewayIntents::GUILD_EMOJIS_AND_STICKERS | GatewayIntents::GUILD_MODERATION
+}
+
+/// The client setup shared by every way of running the bot, the caller just has to build it and start it.
+pub fn client_builder(token: &str, config_store: ConfigStore, content: Content, seed: u64) -> ClientBuilder {
+    Client::builder(token, intents())
+        .event_handler(Bot)
+        .type_map(bot_state(config_store, content, seed))
+}
+
+/// Everything the bot keeps between messages, for `ClientBuilder::type_map`.
+pub fn bot_state(config_store: ConfigStore, content: Content, seed: u64) -> TypeMap {
+    let mut data = TypeMap::new();
+    data.insert::<GuildConfigs>(Mutex::new(config_store));
+    data.insert::<Contents>(Arc::new(content));
+    data.insert::<SharedRng>(Mutex::new(StdRng::seed_from_u64(seed)));
+    data.insert::<RateLimiters>(Mutex::new(RateLimiter::from_settings(SystemClock)));
+    data.insert::<ChessGames>(Mutex::new(Vec::new()));
+    data.insert::<CheckersGames>(Mutex::new(Vec::new()));
+    data.insert::<TurnBasedGames<ConnectFour>>(Mutex::new(Vec::new()));
+    data.insert::<TurnBasedGames<TicTacToe>>(Mutex::new(Vec::new()));
+    data.insert::<EmojiSnapshots>(Mutex::new(HashMap::new()));
+    data.insert::<RoleNames>(Mutex::new(HashMap::new()));
+    data
+}
+
+/// What happens to a message from a person in a server, whether it came from Discord or the console.
+/// Runs whatever command or custom trigger it sets off, otherwise sometimes picks a random quote for the caller to post.
+pub async fn respond(data: &RwLock<TypeMap>, transport: &dyn Transport, incoming: &Incoming, config: &GuildConfig) -> Option<String> {
+    //Admins can always change settings, otherwise they could lock themselves out
+    if !config.channel_allowed(incoming.channel_id.get()) && !incoming.content.to_lowercase().starts_with("hodgey config") {
+        return None;
+    }
+
+    if commands::dispatch(data, transport, incoming, config).await || custom_triggers::dispatch(data, transport, incoming, config).await {
+        return None;
+    }
+
+    let mut rng = rng::fork(data).await;
+    if rng.gen_bool(config.random_reply_chance()) {
+        Some(templates::quote(data, QuoteKind::Random, transport, incoming.author_id, incoming.channel_id.get()).await)
+    }
+    else {
+        None
+    }
+}
+
+pub struct Bot;
+
+#[async_trait]
+impl EventHandler for Bot {
+    async fn message(&self, ctx: Context, msg: Message) {
+        let config = config::guild_config(&ctx.data, msg.guild_id).await;
+
+        let transport = MessageTransport { http: &ctx.http, cache: &ctx.cache, msg: &msg };
+
+        //Get mad at MEE6, or whichever bot the server picked
+        if config.rival_bot_id() == Some(msg.author.id.get()) {
+            let quote = templates::quote(&ctx.data, QuoteKind::Mee6, &transport, msg.author.id.get(), msg.channel_id.get()).await;
+            if let Err(e) = msg.reply(&ctx.http, quote).await {
+                error!("Error sending message: {e:?}");
+            }
+        }
+        //Dont respond to bots
+        if msg.author.bot {
+            return;
+        }
+
+        if msg.channel(&ctx).await.unwrap().guild().is_none() {
+            let busy = templates::quote(&ctx.data, QuoteKind::Busy, &transport, msg.author.id.get(), msg.channel_id.get()).await;
+            if let Err(e) = msg.channel_id.say(&ctx.http, config.locale.format("busy", &[("busy", &busy)])).await {
+                error!("Error sending message: {e:?}");
+            }
+        }
+
+        if let Some(quote) = respond(&ctx.data, &transport, &Incoming::from_message(&msg), &config).await {
+            message_channel::say(&ctx, msg.guild_id, msg.channel_id, quote).await;
+        }
+    }
+    
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        match interaction {
+            Interaction::Component(component) if component.data.custom_id.starts_with("chess_") => {
+                chess_commands::chess_component_interaction(&ctx, &component).await;
+            },
+            Interaction::Component(component) if component.data.custom_id.starts_with("help_") => {
+                help::help_component_interaction(&ctx, &component).await;
+            },
+            Interaction::Command(command) => slash_commands::slash_command_interaction(&ctx, &command).await,
+            Interaction::Autocomplete(autocomplete) if autocomplete.data.name == "chess" => {
+                chess_commands::move_autocomplete(&ctx, &autocomplete).await;
+            },
+            _ => ()
+        }
+    }
+
+    async fn channel_create(&self, ctx: Context, ch: GuildChannel) {
+        let quote = content::get(&ctx.data).await.choose(QuoteKind::NewChannel, &mut rng::fork(&ctx.data).await).to_string();
+        message_channel::say(&ctx, Some(ch.guild_id), ch.id, quote).await;
+    }
+
+    async fn guild_member_addition(&self, ctx: Context, new_member: Member) {
+        if new_member.user.bot {
+            return;
+        }
+        let quote = templates::event_quote(&ctx.data, QuoteKind::NewMember, &format!("<@{}>", new_member.user.id)).await;
+        message_channel::announce(&ctx, new_member.guild_id, "member join", quote).await;
+    }
+
+    async fn guild_member_removal(&self, ctx: Context, guild_id: GuildId, user: User, _member_data_if_available: Option<Member>) {
+        if user.bot {
+            return;
+        }
+        //They can't be mentioned any more, so use their name
+        let quote = templates::event_quote(&ctx.data, QuoteKind::MemberLeft, &user.name).await;
+        message_channel::announce(&ctx, guild_id, "member leave", quote).await;
+    }
+
+    async fn channel_delete(&self, ctx: Context, ch: GuildChannel, _messages: Option<Vec<Message>>) {
+        message_channel::channel_deleted(&ctx, ch.guild_id, ch.id).await;
+    }
+
+    async fn channel_pins_update(&self, ctx: Context, pin: ChannelPinsUpdateEvent) {
+        let locale = config::guild_config(&ctx.data, pin.guild_id).await.locale;
+        message_channel::say(&ctx, pin.guild_id, pin.channel_id, locale.text("pins")).await;
+    }
+
+    async fn guild_create(&self, ctx: Context, guild: Guild, _is_new: Option<bool>) {
+        emojis::remember(&ctx.data, guild.id, &guild.emojis).await;
+        roles::remember(&ctx.data, guild.id, &guild.roles).await;
+    }
+
+    async fn guild_emojis_update(&self, ctx: Context, guild_id: GuildId, current_state: HashMap<EmojiId, Emoji>) {
+        emojis::emojis_updated(&ctx, guild_id, &current_state).await;
+    }
+
+    async fn guild_role_create(&self, ctx: Context, new: Role) {
+        roles::role_created(&ctx, &new).await;
+    }
+
+    async fn guild_role_update(&self, ctx: Context, _old_data_if_available: Option<Role>, new: Role) {
+        roles::role_updated(&ctx.data, &new).await;
+    }
+
+    async fn guild_role_delete(&self, ctx: Context, guild_id: GuildId, removed_role_id: RoleId, _removed_role_data_if_available: Option<Role>) {
+        roles::role_deleted(&ctx, guild_id, removed_role_id).await;
+    }
+
+    async fn guild_ban_addition(&self, ctx: Context, guild_id: GuildId, banned_user: User) {
+        let quote = templates::event_quote(&ctx.data, QuoteKind::Ban, &banned_user.name).await;
+        message_channel::announce(&ctx, guild_id, "ban", quote).await;
+    }
+
+    async fn guild_ban_removal(&self, ctx: Context, guild_id: GuildId, unbanned_user: User) {
+        let quote = templates::event_quote(&ctx.data, QuoteKind::Unban, &unbanned_user.name).await;
+        message_channel::announce(&ctx, guild_id, "unban", quote).await;
+    }
+
+    async fn ready(&self, ctx: Context, ready: Ready) {
+        info!("{} is connected!", ready.user.name);
+        ctx.set_activity(Some(serenity::gateway::ActivityData::playing("Hodgey Help")));
+
+        //Text commands keep working, these are just the built in discord / versions
+        if let Err(e) = serenity::all::Command::set_global_commands(&ctx.http, slash_commands::create_commands()).await {
+            error!("Error registering slash commands: {e:?}");
+        }
+    }
+}
@@ -0,0 +1,555 @@
+use serde::{Deserialize, Serialize};
+use tracing::error;
+
+/// A language Hodgey can reply in. Servers pick one with "hodgey config language", slash commands use the user's Discord language.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "es")]
+    Spanish
+}
+
+impl Locale {
+    pub const ALL: [Self; 2] = [Self::English, Self::Spanish];
+
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::Spanish => "es"
+        }
+    }
+
+    /// What the language calls itself
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::Spanish => "Español"
+        }
+    }
+
+    /// Discord's names for the language, used to translate slash commands
+    pub const fn discord_codes(self) -> &'static [&'static str] {
+        match self {
+            Self::English => &["en-US", "en-GB"],
+            Self::Spanish => &["es-ES", "es-419"]
+        }
+    }
+
+    /// "es", "Español" and Discord's locales like "en-US" and "es-419" all work
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim().to_lowercase();
+        let language = code.split(['-', '_']).next().unwrap_or_default();
+        Self::ALL.into_iter().find(|locale| locale.code() == language || locale.name().to_lowercase() == code)
+    }
+
+    const fn messages(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::English => ENGLISH,
+            Self::Spanish => SPANISH
+        }
+    }
+
+    /// The message in this language. Falls back to English so a missing translation still says something.
+    pub fn text(self, key: &'static str) -> &'static str {
+        let find = |locale: Self| locale.messages().iter().find(|(message_key, _)| *message_key == key).map(|(_, text)| *text);
+        match find(self).or_else(|| find(Self::English)) {
+            Some(text) => text,
+            None => {
+                error!("There is no message called \"{key}\"");
+                key
+            }
+        }
+    }
+
+    /// The message with each "{name}" swapped for its value. Braces that aren't arguments are left for `templates`.
+    pub fn format(self, key: &'static str, args: &[(&str, &str)]) -> String {
+        args.iter().fold(self.text(key).to_string(), |text, (name, value)| text.replace(&format!("{{{name}}}"), value))
+    }
+}
+
+const ENGLISH: &[(&str, &str)] = &[
+    ("not_admin", "You need to be an admin to do that"),
+    ("usage", "Usage: {help}"),
+    ("cooldown_one", "Slow down! Try again in a second."),
+    ("cooldown", "Slow down! Try again in {seconds} seconds."),
+    ("channel_not_allowed", "I'm not allowed to talk in this channel"),
+    ("busy", "Stop messaging me, I'm {busy}!"),
+    ("pins", "Who is messing with the pinned messages?"),
+    ("see", "said the blind man"),
+    ("one_plus_one", "Two!"),
+    ("spam_limit", "You are limited to {limit}"),
+    ("spam_no_message", "I don't understand your message"),
+    ("ping_everyone", "Wow, you would ping @everyone!"),
+    ("ping_here", "Wow, you would ping @here!"),
+    ("no_one_here", "There is no one else here"),
+    ("horse", "It's not a horse, it's a knight"),
+    ("castle", "It's not a castle, it's a rook"),
+    ("book", "Have you read {book}?"),
+    ("movie", "Have you seen {movie}?"),
+    ("clip_review", "This clip is {bad}."),
+    ("joke_title", "Hodgey Joke"),
+    //Help
+    ("help_slash_note", "Most commands also work as built in / commands, try typing /chess."),
+    ("help_title", "Hodgey Bot Commands - {category}"),
+    ("help_footer", "Page {page}/{pages} - \"Hodgey Help <command>\" for more detail"),
+    ("help_category", "Category"),
+    ("help_aliases", "Also works with"),
+    ("help_who", "Who can use it"),
+    ("help_admins_only", "Admins only"),
+    ("help_previous", "Previous"),
+    ("help_next", "Next"),
+    ("help_unknown", "I don't have a command called \"{topic}\". Try \"Hodgey Help\" for the list."),
+    ("category_general", "General"),
+    ("category_chess", "Chess"),
+    ("category_checkers", "Checkers"),
+    ("category_games", "Connect Four & Tic-Tac-Toe"),
+    ("category_responses", "Things Hodgey Reacts To"),
+    ("help_help", "Gives the list of commands for Hodgey bot. Add a command or category for more detail, e.g. \"Hodgey Help Spam\" or \"Hodgey Help Chess\"."),
+    ("help_trigger", "Adds your own responses to things people say. \"Hodgey Trigger Add <exact|contains|word|regex> <trigger> => <response> | <another response>\", \"Hodgey Trigger List\" or \"Hodgey Trigger Remove <number>\". Responses can use {author}, {channel}, {random_member}, {song} and random choices like {hi|hello}."),
+    ("help_config", "Shows or changes Hodgey's settings for this server. \"Hodgey Config Get <setting>\", \"Hodgey Config Set <setting> <value>\" or \"Hodgey Config Reset <setting>\". Leave out the setting to reset everything."),
+    ("help_set_message_channel", "Sets the channel for my random messages and reactions to things happening in the server. Uses the channel you send it in unless you mention one."),
+    ("help_remove_message_channel", "Removes the channel for my random messages. What happens next depends on the \"message channel fallback\" setting."),
+    ("help_joke", "Hodgey bot tell's you a joke."),
+    ("help_val_agent", "Picks a Valorant agent for you."),
+    ("help_val_squad", "Picks five Valorant agents for your squad."),
+    ("help_clip_review", "Gives your clip an honest review."),
+    ("help_see", "Said the blind man."),
+    ("help_maths", "Hodgey does maths."),
+    ("help_chess_resign", "You will surrender your current chess game. \"Chess Surrender\" also works."),
+    ("help_toggle_coordinates", "Disables or enables coordinates."),
+    ("help_toggle_board_flip", "Disables or enables the board flipping on black's turn."),
+    ("help_toggle_text_board", "Switches between the chess.com picture and a text board for when the picture won't load."),
+    ("help_chess_show", "Shows your current chess game."),
+    ("help_chess_board", "Shows your current chess game with menus to pick your move and buttons to resign, offer a draw or flip the board."),
+    ("help_chess_new", "Starts a new chess game. To play against another person ping them at the end of the message."),
+    ("help_move", "Lets you make a move. Examples: \"Move e4\", \"Move Nc6\", \"Move e2 e4\", \"Move Ng1-f3\", \"Move ♘f3\". To promote a piece add the piece you want such as \"Move e8=Q\" or \"Move e7e8q\"."),
+    ("help_checkers_resign", "You will surrender your current checkers game. \"Checkers Surrender\" also works."),
+    ("help_checkers_show", "Shows your current checkers game."),
+    ("help_checkers_new", "Starts a new checkers game. To play against another person ping them at the end of the message."),
+    ("help_checkers_move", "Lets you make a move using the square numbers on the board. Examples: \"Checkers Move 11-15\", \"Checkers Move 22x15x8\". Captures are forced."),
+    ("help_connect4", "Play Connect Four with \"Connect4 New\", \"Connect4 Move 4\", \"Connect4 Show\" and \"Connect4 Resign\"."),
+    ("help_tictactoe", "Play Tic-Tac-Toe with \"TicTacToe New\", \"TicTacToe Move 5\", \"TicTacToe Show\" and \"TicTacToe Resign\"."),
+    ("help_spam", "Repeats your message. Example: \"Spam 3 hello\". You are limited to 5 unless the bot is set up differently. Admins only unless the server turns off \"admin only spam\"."),
+    ("help_everyone", "Hodgey judges you for pinging everyone."),
+    ("help_here", "Hodgey judges you for pinging here."),
+    ("help_someone", "If used anywhere in the message a random user will be pinged."),
+    ("help_decide", "Hodgey makes the decision for you."),
+    ("help_chess", "Hodgey has opinions on chess."),
+    ("help_checkers", "Hodgey has opinions on checkers."),
+    ("help_horse", "It's not a horse."),
+    ("help_castle", "It's not a castle."),
+    ("help_book", "Hodgey recommends a book."),
+    ("help_music", "Hodgey recommends a song."),
+    ("help_movie", "Hodgey recommends a movie."),
+    //Slash command descriptions, Discord allows 100 characters
+    ("slash_help", "Gives the list of commands for Hodgey bot"),
+    ("slash_help_topic", "A category or command, e.g. chess or spam"),
+    ("slash_joke", "Hodgey bot tell's you a joke"),
+    ("slash_val", "Valorant picks"),
+    ("slash_val_agent", "Picks a Valorant agent for you"),
+    ("slash_val_squad", "Picks five Valorant agents for your squad"),
+    ("slash_clip_review", "Gives your clip an honest review"),
+    ("slash_decide", "Hodgey makes the decision for you"),
+    ("slash_decide_question", "What needs deciding"),
+    ("slash_chess", "Play chess against Hodgey or a friend"),
+    ("slash_new", "Starts a new game, against me unless you pick an opponent"),
+    ("slash_opponent", "Who you want to play against"),
+    ("slash_move", "Makes a move in your current game"),
+    ("slash_chess_move", "For example e4, Nf3 or e7e8q"),
+    ("slash_resign", "Surrenders your current game"),
+    ("slash_show", "Shows your current game"),
+    ("slash_chess_board", "Shows your current game with menus to pick your move"),
+    ("slash_chess_toggle", "Changes how your board is shown"),
+    ("slash_chess_toggle_setting", "What to toggle"),
+    ("choice_coordinates", "Coordinates"),
+    ("choice_board_flip", "Board flip"),
+    ("choice_text_board", "Text board"),
+    ("slash_checkers", "Play checkers, if you really have to"),
+    ("slash_checkers_move", "Square numbers, for example 11-15 or 22x15x8"),
+    ("slash_connect4", "Play Connect Four"),
+    ("slash_connect4_move", "Drops a piece in a column"),
+    ("slash_connect4_column", "Column from 1 to 7"),
+    ("slash_tictactoe", "Play Tic-Tac-Toe"),
+    ("slash_tictactoe_move", "Takes a square"),
+    ("slash_tictactoe_square", "Square from 1 to 9"),
+    //Every game
+    ("opponent_busy", "Opponent is in another game. If they wish to leave they can resign with \"{command} resign\""),
+    ("game_over", "The game has ended."),
+    ("not_your_turn", "It is not your turn"),
+    ("your_turn", "Your turn <@{player}>!"),
+    ("i_win", "I WIN!"),
+    ("player_wins", "<@{player}> wins!"),
+    ("last_move", "Last move: {move}"),
+    ("illegal_move", "That's an illegal move"),
+    ("ambiguous_move", "That move is ambiguous, did you mean {moves}?"),
+    ("or", " or "),
+    //Chess
+    ("no_chess_game", "You don't have an active chess game. Start a new one with \"Chess New\""),
+    ("chess_new_game", "New game created!\nWhite: <@{white}>\nBlack: <@{black}>"),
+    ("in_check", "You are in check <@{player}>!"),
+    ("coordinates_on", "Coordinates enabled."),
+    ("coordinates_off", "Coordinates disabled."),
+    ("board_flip_on", "Board flip enabled."),
+    ("board_flip_off", "Board flip disabled."),
+    ("text_board_on", "Text board enabled."),
+    ("text_board_off", "Text board disabled."),
+    ("chess_invalid_move", "I don't understand the move you are trying to make"),
+    ("wrong_piece", "That piece can't move there. Moves to that square: {moves}"),
+    ("pinned_piece", "That piece is pinned to your king"),
+    ("king_in_check", "You need to get out of check! Your legal moves: {moves}"),
+    ("promotion_missing", "You need to pick a piece to promote to: {moves}"),
+    ("checkmate", "Checkmate!"),
+    ("insufficient_material", "Stalemate! Insufficient material."),
+    ("fifty_moves", "Draw by 50 move rule!"),
+    ("stalemate", "Stalemate!"),
+    ("white_to_move", "White to move"),
+    ("black_to_move", "Black to move"),
+    ("move_menu", "{pieces} moves"),
+    ("role_pawn", "Pawn"),
+    ("role_knight", "Knight"),
+    ("role_bishop", "Bishop"),
+    ("role_rook", "Rook"),
+    ("role_queen", "Queen"),
+    ("role_king", "King"),
+    ("button_resign", "Resign"),
+    ("button_draw", "Draw"),
+    ("button_flip", "Flip"),
+    ("game_no_longer_active", "This game is no longer active."),
+    ("not_your_game", "This isn't your game."),
+    ("move_no_longer_legal", "That move is no longer legal"),
+    ("no_draws", "I don't do draws, play on!"),
+    ("draw_agreed", "Draw agreed!"),
+    ("draw_already_offered", "You have already offered a draw."),
+    ("draw_offer", "<@{player}> offers a draw. <@{opponent}> can press Draw to accept."),
+    ("unknown_button", "I don't understand that button"),
+    //Checkers
+    ("no_checkers_game", "You don't have an active checkers game. Start a new one with \"Checkers New\""),
+    ("checkers_new_game", "New checkers game created!\nBlack: <@{black}>\nWhite: <@{white}>"),
+    ("i_win_checkers", "I WIN! Still a bad game though."),
+    ("checkers_invalid_move", "I don't understand the move you are trying to make. Moves look like \"11-15\" or \"22x15x8\""),
+    ("capture_required", "You have to capture! Your captures: {moves}"),
+    ("checkers_draw", "Draw! 40 moves without a capture or a crowning."),
+    ("black_stuck", "Black can't move, White wins!"),
+    ("white_stuck", "White can't move, Black wins!"),
+    ("checkers_black_to_move", "Black (b) to move"),
+    ("checkers_white_to_move", "White (w) to move"),
+    //Connect Four and Tic-Tac-Toe
+    ("connect_four", "Connect Four"),
+    ("tic_tac_toe", "Tic-Tac-Toe"),
+    ("no_game", "You don't have an active {game} game. Start a new one with \"{command} new\""),
+    ("turn_based_new_game", "New {game} game created!\n{first_piece}: <@{first}>\n{second_piece}: <@{second}>"),
+    ("piece_wins", "{piece} <@{player}> wins!"),
+    ("draw", "It's a draw!"),
+    ("pick_column", "Pick a column from 1 to 7"),
+    ("column_full", "That column is full"),
+    ("pick_square", "Pick a square from 1 to 9"),
+    ("square_taken", "That square is already taken"),
+    //Settings
+    ("server_only", "Settings only work in a server"),
+    ("settings_list", "## Hodgey Settings\n{settings}\nChange one with \"Hodgey Config Set <setting> <value>\""),
+    ("setting_now", "**{key}** is now {value}"),
+    ("setting_reset", "**{key}** is back to {value}"),
+    ("settings_reset", "All settings are back to normal."),
+    ("unknown_setting", "There is no setting called \"{key}\". Use \"Hodgey Config\" to see them all."),
+    ("invalid_value", "That value doesn't work, {expected}."),
+    ("expected_chance", "use a chance between 0% and 100%"),
+    ("expected_channels", "mention the channels or use all"),
+    ("expected_bot", "mention the bot or use none"),
+    ("expected_role", "mention the role or use none"),
+    ("expected_channel", "mention the channel or use none"),
+    ("expected_fallback", "use event channel or silent"),
+    ("expected_language", "use English (en) or Español (es)"),
+    ("expected_toggle", "use on or off"),
+    ("message_channel_set", "I'll send my random messages to <#{channel}> from now on."),
+    ("channel_not_in_server", "That channel isn't in this server"),
+    ("no_message_channel", "There is no message channel to remove."),
+    ("message_channel_removed_silent", "Message channel removed. I'll keep quiet unless someone talks to me."),
+    ("message_channel_removed", "Message channel removed. I'll talk wherever things happen."),
+    //Custom triggers
+    ("no_triggers", "There are no custom triggers yet. Add one with \"Hodgey Trigger Add\"."),
+    ("triggers_list", "## Custom Triggers\n{list}"),
+    ("too_many_triggers", "You can only have {max} custom triggers, remove one first"),
+    ("trigger_added", "Added trigger {number}. {trigger}"),
+    ("no_trigger", "There is no trigger {number}, \"Hodgey Trigger List\" shows them all"),
+    ("trigger_removed", "Removed {trigger}"),
+    ("trigger_length", "The trigger needs to be between 1 and {max} characters"),
+    ("response_count", "There need to be between 1 and {max} responses, separated by |"),
+    ("word_trigger_letters", "A word trigger needs letters or numbers in it, use contains for anything else"),
+    ("bad_response", "A response doesn't work, {problem}"),
+    ("bad_regex", "That regex doesn't work: {problem}"),
+    ("template_unclosed", "a { is never closed, use \\{ for a plain {"),
+    ("template_unexpected_close", "a } has nothing to close, use \\} for a plain }"),
+    ("template_unknown_placeholder", "there is no placeholder called {placeholder}, the options are {options}"),
+    ("template_too_deep", "choices can only be nested {depth} deep")
+];
+
+const SPANISH: &[(&str, &str)] = &[
+    ("not_admin", "Tienes que ser admin para hacer eso"),
+    ("usage", "Uso: {help}"),
+    ("cooldown_one", "¡Más despacio! Inténtalo de nuevo en un segundo."),
+    ("cooldown", "¡Más despacio! Inténtalo de nuevo en {seconds} segundos."),
+    ("channel_not_allowed", "No me dejan hablar en este canal"),
+    ("busy", "¡Deja de escribirme, estoy {busy}!"),
+    ("pins", "¿Quién está tocando los mensajes fijados?"),
+    ("see", "dijo el ciego"),
+    ("one_plus_one", "¡Dos!"),
+    ("spam_limit", "Tu límite es {limit}"),
+    ("spam_no_message", "No entiendo tu mensaje"),
+    ("ping_everyone", "¡Vaya, mencionarías a @everyone!"),
+    ("ping_here", "¡Vaya, mencionarías a @here!"),
+    ("no_one_here", "No hay nadie más aquí"),
+    ("horse", "No es un caballo, es un caballero"),
+    ("castle", "No es un castillo, es una torre"),
+    ("book", "¿Has leído {book}?"),
+    ("movie", "¿Has visto {movie}?"),
+    ("clip_review", "Este clip es {bad}."),
+    ("joke_title", "Chiste de Hodgey"),
+    //Help
+    ("help_slash_note", "La mayoría de los comandos también funcionan como comandos / de Discord, prueba a escribir /chess."),
+    ("help_title", "Comandos de Hodgey Bot - {category}"),
+    ("help_footer", "Página {page}/{pages} - \"Hodgey Help <comando>\" para más detalle"),
+    ("help_category", "Categoría"),
+    ("help_aliases", "También funciona con"),
+    ("help_who", "Quién puede usarlo"),
+    ("help_admins_only", "Solo admins"),
+    ("help_previous", "Anterior"),
+    ("help_next", "Siguiente"),
+    ("help_unknown", "No tengo ningún comando llamado \"{topic}\". Prueba \"Hodgey Help\" para ver la lista."),
+    ("category_general", "General"),
+    ("category_chess", "Ajedrez"),
+    ("category_checkers", "Damas"),
+    ("category_games", "Conecta 4 y Tres en Raya"),
+    ("category_responses", "Cosas a las que Hodgey reacciona"),
+    ("help_help", "Da la lista de comandos de Hodgey bot. Añade un comando o una categoría para más detalle, p. ej. \"Hodgey Help Spam\" o \"Hodgey Help Chess\"."),
+    ("help_trigger", "Añade tus propias respuestas a lo que dice la gente. \"Hodgey Trigger Add <exact|contains|word|regex> <trigger> => <respuesta> | <otra respuesta>\", \"Hodgey Trigger List\" o \"Hodgey Trigger Remove <número>\". Las respuestas pueden usar {author}, {channel}, {random_member}, {song} y opciones al azar como {hi|hello}."),
+    ("help_config", "Muestra o cambia los ajustes de Hodgey para este servidor. \"Hodgey Config Get <ajuste>\", \"Hodgey Config Set <ajuste> <valor>\" o \"Hodgey Config Reset <ajuste>\". Sin el ajuste se restablece todo."),
+    ("help_set_message_channel", "Elige el canal para mis mensajes al azar y mis reacciones a lo que pasa en el servidor. Usa el canal en el que lo envías salvo que menciones otro."),
+    ("help_remove_message_channel", "Quita el canal de mis mensajes al azar. Lo que pasa después depende del ajuste \"message channel fallback\"."),
+    ("help_joke", "Hodgey bot te cuenta un chiste."),
+    ("help_val_agent", "Elige un agente de Valorant para ti."),
+    ("help_val_squad", "Elige cinco agentes de Valorant para tu equipo."),
+    ("help_clip_review", "Da una crítica sincera de tu clip."),
+    ("help_see", "Dijo el ciego."),
+    ("help_maths", "Hodgey hace matemáticas."),
+    ("help_chess_resign", "Te rindes en tu partida de ajedrez actual. \"Chess Surrender\" también funciona."),
+    ("help_toggle_coordinates", "Activa o desactiva las coordenadas."),
+    ("help_toggle_board_flip", "Activa o desactiva el giro del tablero en el turno de las negras."),
+    ("help_toggle_text_board", "Cambia entre la imagen de chess.com y un tablero de texto para cuando la imagen no carga."),
+    ("help_chess_show", "Muestra tu partida de ajedrez actual."),
+    ("help_chess_board", "Muestra tu partida de ajedrez actual con menús para elegir tu jugada y botones para rendirte, ofrecer tablas o girar el tablero."),
+    ("help_chess_new", "Empieza una partida de ajedrez nueva. Para jugar contra otra persona menciónala al final del mensaje."),
+    ("help_move", "Te deja hacer una jugada. Ejemplos: \"Move e4\", \"Move Nc6\", \"Move e2 e4\", \"Move Ng1-f3\", \"Move ♘f3\". Para coronar añade la pieza que quieras, como \"Move e8=Q\" o \"Move e7e8q\"."),
+    ("help_checkers_resign", "Te rindes en tu partida de damas actual. \"Checkers Surrender\" también funciona."),
+    ("help_checkers_show", "Muestra tu partida de damas actual."),
+    ("help_checkers_new", "Empieza una partida de damas nueva. Para jugar contra otra persona menciónala al final del mensaje."),
+    ("help_checkers_move", "Te deja hacer una jugada con los números de las casillas del tablero. Ejemplos: \"Checkers Move 11-15\", \"Checkers Move 22x15x8\". Las capturas son obligatorias."),
+    ("help_connect4", "Juega a Conecta 4 con \"Connect4 New\", \"Connect4 Move 4\", \"Connect4 Show\" y \"Connect4 Resign\"."),
+    ("help_tictactoe", "Juega al tres en raya con \"TicTacToe New\", \"TicTacToe Move 5\", \"TicTacToe Show\" y \"TicTacToe Resign\"."),
+    ("help_spam", "Repite tu mensaje. Ejemplo: \"Spam 3 hola\". El límite es 5 salvo que el bot esté configurado de otra forma. Solo para admins salvo que el servidor desactive \"admin only spam\"."),
+    ("help_everyone", "Hodgey te juzga por mencionar a todos."),
+    ("help_here", "Hodgey te juzga por mencionar a @here."),
+    ("help_someone", "Si aparece en cualquier parte del mensaje se mencionará a alguien al azar."),
+    ("help_decide", "Hodgey toma la decisión por ti."),
+    ("help_chess", "Hodgey tiene opiniones sobre el ajedrez."),
+    ("help_checkers", "Hodgey tiene opiniones sobre las damas."),
+    ("help_horse", "No es un caballo."),
+    ("help_castle", "No es un castillo."),
+    ("help_book", "Hodgey te recomienda un libro."),
+    ("help_music", "Hodgey te recomienda una canción."),
+    ("help_movie", "Hodgey te recomienda una película."),
+    //Slash command descriptions, Discord allows 100 characters
+    ("slash_help", "Da la lista de comandos de Hodgey bot"),
+    ("slash_help_topic", "Una categoría o un comando, p. ej. chess o spam"),
+    ("slash_joke", "Hodgey bot te cuenta un chiste"),
+    ("slash_val", "Elecciones de Valorant"),
+    ("slash_val_agent", "Elige un agente de Valorant para ti"),
+    ("slash_val_squad", "Elige cinco agentes de Valorant para tu equipo"),
+    ("slash_clip_review", "Da una crítica sincera de tu clip"),
+    ("slash_decide", "Hodgey toma la decisión por ti"),
+    ("slash_decide_question", "Lo que hay que decidir"),
+    ("slash_chess", "Juega al ajedrez contra Hodgey o un amigo"),
+    ("slash_new", "Empieza una partida nueva, contra mí salvo que elijas un rival"),
+    ("slash_opponent", "Contra quién quieres jugar"),
+    ("slash_move", "Hace una jugada en tu partida actual"),
+    ("slash_chess_move", "Por ejemplo e4, Nf3 o e7e8q"),
+    ("slash_resign", "Te rindes en tu partida actual"),
+    ("slash_show", "Muestra tu partida actual"),
+    ("slash_chess_board", "Muestra tu partida actual con menús para elegir tu jugada"),
+    ("slash_chess_toggle", "Cambia cómo se muestra tu tablero"),
+    ("slash_chess_toggle_setting", "Qué activar o desactivar"),
+    ("choice_coordinates", "Coordenadas"),
+    ("choice_board_flip", "Giro del tablero"),
+    ("choice_text_board", "Tablero de texto"),
+    ("slash_checkers", "Juega a las damas, si de verdad tienes que hacerlo"),
+    ("slash_checkers_move", "Números de casilla, por ejemplo 11-15 o 22x15x8"),
+    ("slash_connect4", "Juega a Conecta 4"),
+    ("slash_connect4_move", "Suelta una ficha en una columna"),
+    ("slash_connect4_column", "Columna del 1 al 7"),
+    ("slash_tictactoe", "Juega al tres en raya"),
+    ("slash_tictactoe_move", "Ocupa una casilla"),
+    ("slash_tictactoe_square", "Casilla del 1 al 9"),
+    //Every game
+    ("opponent_busy", "Tu rival está en otra partida. Si quiere dejarla puede rendirse con \"{command} resign\""),
+    ("game_over", "La partida ha terminado."),
+    ("not_your_turn", "No es tu turno"),
+    ("your_turn", "¡Te toca <@{player}>!"),
+    ("i_win", "¡GANO YO!"),
+    ("player_wins", "¡<@{player}> gana!"),
+    ("last_move", "Última jugada: {move}"),
+    ("illegal_move", "Esa jugada no es legal"),
+    ("ambiguous_move", "Esa jugada es ambigua, ¿querías decir {moves}?"),
+    ("or", " o "),
+    //Chess
+    ("no_chess_game", "No tienes ninguna partida de ajedrez activa. Empieza una nueva con \"Chess New\""),
+    ("chess_new_game", "¡Nueva partida creada!\nBlancas: <@{white}>\nNegras: <@{black}>"),
+    ("in_check", "¡Estás en jaque <@{player}>!"),
+    ("coordinates_on", "Coordenadas activadas."),
+    ("coordinates_off", "Coordenadas desactivadas."),
+    ("board_flip_on", "Giro del tablero activado."),
+    ("board_flip_off", "Giro del tablero desactivado."),
+    ("text_board_on", "Tablero de texto activado."),
+    ("text_board_off", "Tablero de texto desactivado."),
+    ("chess_invalid_move", "No entiendo la jugada que intentas hacer"),
+    ("wrong_piece", "Esa pieza no puede ir ahí. Jugadas a esa casilla: {moves}"),
+    ("pinned_piece", "Esa pieza está clavada a tu rey"),
+    ("king_in_check", "¡Tienes que salir del jaque! Tus jugadas legales: {moves}"),
+    ("promotion_missing", "Tienes que elegir a qué pieza coronar: {moves}"),
+    ("checkmate", "¡Jaque mate!"),
+    ("insufficient_material", "¡Tablas! Material insuficiente."),
+    ("fifty_moves", "¡Tablas por la regla de los 50 movimientos!"),
+    ("stalemate", "¡Rey ahogado!"),
+    ("white_to_move", "Juegan blancas"),
+    ("black_to_move", "Juegan negras"),
+    ("move_menu", "Jugadas de {pieces}"),
+    ("role_pawn", "Peón"),
+    ("role_knight", "Caballo"),
+    ("role_bishop", "Alfil"),
+    ("role_rook", "Torre"),
+    ("role_queen", "Dama"),
+    ("role_king", "Rey"),
+    ("button_resign", "Rendirse"),
+    ("button_draw", "Tablas"),
+    ("button_flip", "Girar"),
+    ("game_no_longer_active", "Esta partida ya no está activa."),
+    ("not_your_game", "Esta no es tu partida."),
+    ("move_no_longer_legal", "Esa jugada ya no es legal"),
+    ("no_draws", "Yo no acepto tablas, ¡sigue jugando!"),
+    ("draw_agreed", "¡Tablas acordadas!"),
+    ("draw_already_offered", "Ya has ofrecido tablas."),
+    ("draw_offer", "<@{player}> ofrece tablas. <@{opponent}> puede pulsar Tablas para aceptar."),
+    ("unknown_button", "No entiendo ese botón"),
+    //Checkers
+    ("no_checkers_game", "No tienes ninguna partida de damas activa. Empieza una nueva con \"Checkers New\""),
+    ("checkers_new_game", "¡Nueva partida de damas creada!\nNegras: <@{black}>\nBlancas: <@{white}>"),
+    ("i_win_checkers", "¡GANO YO! Aunque sigue siendo un juego malo."),
+    ("checkers_invalid_move", "No entiendo la jugada que intentas hacer. Las jugadas son como \"11-15\" o \"22x15x8\""),
+    ("capture_required", "¡Tienes que capturar! Tus capturas: {moves}"),
+    ("checkers_draw", "¡Tablas! 40 jugadas sin capturas ni coronaciones."),
+    ("black_stuck", "Las negras no pueden mover, ¡ganan las blancas!"),
+    ("white_stuck", "Las blancas no pueden mover, ¡ganan las negras!"),
+    ("checkers_black_to_move", "Juegan negras (b)"),
+    ("checkers_white_to_move", "Juegan blancas (w)"),
+    //Connect Four and Tic-Tac-Toe
+    ("connect_four", "Conecta 4"),
+    ("tic_tac_toe", "Tres en Raya"),
+    ("no_game", "No tienes ninguna partida de {game} activa. Empieza una nueva con \"{command} new\""),
+    ("turn_based_new_game", "¡Nueva partida de {game} creada!\n{first_piece}: <@{first}>\n{second_piece}: <@{second}>"),
+    ("piece_wins", "¡{piece} <@{player}> gana!"),
+    ("draw", "¡Empate!"),
+    ("pick_column", "Elige una columna del 1 al 7"),
+    ("column_full", "Esa columna está llena"),
+    ("pick_square", "Elige una casilla del 1 al 9"),
+    ("square_taken", "Esa casilla ya está ocupada"),
+    //Settings
+    ("server_only", "Los ajustes solo funcionan en un servidor"),
+    ("settings_list", "## Ajustes de Hodgey\n{settings}\nCambia uno con \"Hodgey Config Set <ajuste> <valor>\""),
+    ("setting_now", "**{key}** ahora es {value}"),
+    ("setting_reset", "**{key}** vuelve a ser {value}"),
+    ("settings_reset", "Todos los ajustes han vuelto a la normalidad."),
+    ("unknown_setting", "No hay ningún ajuste llamado \"{key}\". Usa \"Hodgey Config\" para verlos todos."),
+    ("invalid_value", "Ese valor no sirve, {expected}."),
+    ("expected_chance", "usa una probabilidad entre 0% y 100%"),
+    ("expected_channels", "menciona los canales o usa all"),
+    ("expected_bot", "menciona al bot o usa none"),
+    ("expected_role", "menciona el rol o usa none"),
+    ("expected_channel", "menciona el canal o usa none"),
+    ("expected_fallback", "usa event channel o silent"),
+    ("expected_language", "usa English (en) o Español (es)"),
+    ("expected_toggle", "usa on u off"),
+    ("message_channel_set", "A partir de ahora enviaré mis mensajes al azar a <#{channel}>."),
+    ("channel_not_in_server", "Ese canal no está en este servidor"),
+    ("no_message_channel", "No hay ningún canal de mensajes que quitar."),
+    ("message_channel_removed_silent", "Canal de mensajes quitado. Me quedaré callado salvo que alguien me hable."),
+    ("message_channel_removed", "Canal de mensajes quitado. Hablaré donde pasen las cosas."),
+    //Custom triggers
+    ("no_triggers", "Todavía no hay triggers personalizados. Añade uno con \"Hodgey Trigger Add\"."),
+    ("triggers_list", "## Triggers personalizados\n{list}"),
+    ("too_many_triggers", "Solo puedes tener {max} triggers personalizados, quita uno primero"),
+    ("trigger_added", "Trigger {number} añadido. {trigger}"),
+    ("no_trigger", "No hay ningún trigger {number}, \"Hodgey Trigger List\" los muestra todos"),
+    ("trigger_removed", "Trigger quitado: {trigger}"),
+    ("trigger_length", "El trigger tiene que tener entre 1 y {max} caracteres"),
+    ("response_count", "Tiene que haber entre 1 y {max} respuestas, separadas por |"),
+    ("word_trigger_letters", "Un trigger word necesita letras o números, usa contains para lo demás"),
+    ("bad_response", "Una respuesta no sirve, {problem}"),
+    ("bad_regex", "Esa regex no sirve: {problem}"),
+    ("template_unclosed", "una { nunca se cierra, usa \\{ para una { normal"),
+    ("template_unexpected_close", "una } no cierra nada, usa \\} para una } normal"),
+    ("template_unknown_placeholder", "no hay ningún marcador llamado {placeholder}, las opciones son {options}"),
+    ("template_too_deep", "las opciones solo se pueden anidar {depth} niveles")
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The "{name}" arguments in a message, which every translation has to keep
+    fn arguments(text: &str) -> Vec<&str> {
+        let mut arguments: Vec<&str> = text.split('{').skip(1).filter_map(|rest| rest.split_once('}').map(|(name, _)| name)).collect();
+        arguments.sort_unstable();
+        arguments
+    }
+
+    #[test]
+    fn every_key_is_in_every_locale() {
+        for locale in Locale::ALL {
+            for (key, text) in ENGLISH {
+                let translated = locale.messages().iter().find(|(message_key, _)| message_key == key);
+                assert!(translated.is_some(), "{} is missing {key}", locale.code());
+                assert_eq!(arguments(translated.unwrap().1), arguments(text), "{} {key}", locale.code());
+            }
+            for (key, _) in locale.messages() {
+                assert!(ENGLISH.iter().any(|(english_key, _)| english_key == key), "{} has {key} but English doesn't", locale.code());
+            }
+            let mut keys: Vec<&str> = locale.messages().iter().map(|(key, _)| *key).collect();
+            keys.sort_unstable();
+            keys.dedup();
+            assert_eq!(keys.len(), locale.messages().len(), "{} has a key twice", locale.code());
+        }
+    }
+
+    #[test]
+    fn every_command_has_help() {
+        for command in crate::commands::COMMANDS {
+            assert!(ENGLISH.iter().any(|(key, _)| *key == command.help), "{} has no help", command.name);
+        }
+        for category in crate::commands::Category::ALL {
+            assert!(ENGLISH.iter().any(|(key, _)| *key == category.title_key()), "{} has no title", category.keyword());
+        }
+    }
+
+    #[test]
+    fn locales_from_discord() {
+        assert_eq!(Locale::from_code("en-US"), Some(Locale::English));
+        assert_eq!(Locale::from_code("es-419"), Some(Locale::Spanish));
+        assert_eq!(Locale::from_code("Español"), Some(Locale::Spanish));
+        assert_eq!(Locale::from_code("fr"), None);
+        for locale in Locale::ALL {
+            assert!(locale.discord_codes().iter().all(|code| Locale::from_code(code) == Some(locale)));
+        }
+        assert_eq!(Locale::Spanish.format("cooldown", &[("seconds", "3")]), "¡Más despacio! Inténtalo de nuevo en 3 segundos.");
+        assert_eq!(Locale::Spanish.text("missing"), "missing");
+    }
+}
@@ -0,0 +1,69 @@
+use serenity::all::{ChannelId, GuildId};
+use serenity::prelude::*;
+use tracing::{error, info};
+use crate::config::{self, GuildConfigs, MessageChannelFallback};
+
+/// Posts something Hodgey decided to say by himself (events and random replies) in the server's message channel.
+/// If there isn't one, or it can't be posted in, the server's fallback decides whether it goes where the event happened.
+pub async fn say(ctx: &Context, guild_id: Option<GuildId>, event_channel_id: ChannelId, content: impl Into<String>) {
+    let content = content.into();
+    let config = config::guild_config(&ctx.data, guild_id).await;
+
+    if let Some(message_channel_id) = config.message_channel {
+        match ChannelId::new(message_channel_id).say(&ctx.http, &content).await {
+            Ok(_) => return,
+            Err(e) => error!("Error sending message to message channel {message_channel_id}: {e:?}")
+        }
+    }
+
+    if config.message_channel_fallback == MessageChannelFallback::EventChannel && config.channel_allowed(event_channel_id.get()) {
+        if let Err(e) = event_channel_id.say(&ctx.http, content).await {
+            error!("Error sending message: {e:?}");
+        }
+    }
+}
+
+/// For things that don't happen in a channel, like members joining. The server's system channel counts as where it happened.
+/// `event` is one of `config::EVENTS`, nothing is posted if the server switched it off.
+pub async fn announce(ctx: &Context, guild_id: GuildId, event: &str, content: impl Into<String>) {
+    let config = config::guild_config(&ctx.data, Some(guild_id)).await;
+    if !config.event_enabled(event) {
+        return;
+    }
+    //Every server Hodgey is in should be cached, Discord is only asked when it isn't
+    let cached_system_channel_id = ctx.cache.guild(guild_id).map(|guild| guild.system_channel_id);
+    let system_channel_id = match cached_system_channel_id {
+        Some(system_channel_id) => system_channel_id,
+        None => match guild_id.to_partial_guild(&ctx.http).await {
+            Ok(guild) => guild.system_channel_id,
+            Err(e) => {
+                error!("Error getting guild {guild_id}: {e:?}");
+                None
+            }
+        }
+    };
+    match system_channel_id {
+        Some(channel_id) => say(ctx, Some(guild_id), channel_id, content).await,
+        //Nowhere to fall back to, so it's the message channel or nothing
+        None => {
+            let Some(message_channel_id) = config.message_channel else {
+                return;
+            };
+            if let Err(e) = ChannelId::new(message_channel_id).say(&ctx.http, content.into()).await {
+                error!("Error sending message to message channel {message_channel_id}: {e:?}");
+            }
+        }
+    }
+}
+
+/// Called when a channel is deleted so Hodgey stops trying to post in it.
+pub async fn channel_deleted(ctx: &Context, guild_id: GuildId, channel_id: ChannelId) {
+    let rw_lock = ctx.data.read().await;
+    let mut config_store = rw_lock.get::<GuildConfigs>().expect("GuildConfigs not in TypeMap.").lock().await;
+    if config_store.remove_message_channel(guild_id.get(), channel_id.get()) {
+        info!("Message channel {channel_id} in {guild_id} was deleted");
+        if let Err(e) = config_store.save().await {
+            error!("Error saving guild config: {e:?}");
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use serenity::all::{Cache, GuildId, Member, Permissions, UserId};
+use crate::commands::CommandContext;
+use crate::config;
+
+/// The parts of a server member that decide what they are allowed to do.
+#[derive(Clone)]
+pub struct MemberInfo {
+    pub is_owner: bool,
+    pub permissions: Permissions,
+    pub role_ids: Vec<u64>
+}
+
+impl MemberInfo {
+    /// For interactions, where Discord has already worked out the member's permissions. Only the owner comes from the cache.
+    pub fn from_interaction_member(cache: &Cache, guild_id: Option<GuildId>, member: &Member) -> Self {
+        Self {
+            is_owner: guild_id.and_then(|guild_id| cache.guild(guild_id)).is_some_and(|guild| guild.owner_id == member.user.id),
+            permissions: member.permissions.unwrap_or(Permissions::empty()),
+            role_ids: member.roles.iter().map(|role_id| role_id.get()).collect()
+        }
+    }
+
+    /// None if the server or the member isn't cached
+    pub fn from_cache(cache: &Cache, guild_id: GuildId, user_id: UserId) -> Option<Self> {
+        let guild = cache.guild(guild_id)?;
+        let member = guild.members.get(&user_id)?;
+        Some(Self {
+            is_owner: guild.owner_id == user_id,
+            permissions: guild.member_permissions(member),
+            role_ids: member.roles.iter().map(|role_id| role_id.get()).collect()
+        })
+    }
+}
+
+/// The owner, anyone with Administrator or Manage Server, and anyone with the server's bot admin role.
+pub fn is_bot_admin(member: &MemberInfo, bot_admin_role: Option<u64>) -> bool {
+    member.is_owner
+    || member.permissions.contains(Permissions::ADMINISTRATOR)
+    || member.permissions.contains(Permissions::MANAGE_GUILD)
+    || bot_admin_role.is_some_and(|role_id| member.role_ids.contains(&role_id))
+}
+
+/// Whether whoever ran the command counts as an admin for Hodgey. Never true in DMs.
+pub async fn is_admin(cmd: &CommandContext<'_>) -> bool {
+    let Some(member) = cmd.transport.member_info().await else {
+        return false;
+    };
+    let config = config::guild_config(cmd.data, cmd.guild_id).await;
+    is_bot_admin(&member, config.bot_admin_role)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOT_ADMIN_ROLE: u64 = 1234;
+
+    fn member(permissions: Permissions, role_ids: Vec<u64>) -> MemberInfo {
+        MemberInfo { is_owner: false, permissions, role_ids }
+    }
+
+    #[test]
+    fn administrator_is_admin() {
+        assert!(is_bot_admin(&member(Permissions::ADMINISTRATOR, Vec::new()), None));
+    }
+
+    #[test]
+    fn manage_guild_is_admin() {
+        assert!(is_bot_admin(&member(Permissions::MANAGE_GUILD | Permissions::SEND_MESSAGES, Vec::new()), None));
+    }
+
+    #[test]
+    fn owner_is_admin() {
+        let owner = MemberInfo { is_owner: true, permissions: Permissions::empty(), role_ids: Vec::new() };
+        assert!(is_bot_admin(&owner, None));
+    }
+
+    #[test]
+    fn regular_member_is_not_admin() {
+        let regular = member(Permissions::SEND_MESSAGES | Permissions::MANAGE_MESSAGES | Permissions::KICK_MEMBERS, vec![1, 2, 3]);
+        assert!(!is_bot_admin(&regular, None));
+        assert!(!is_bot_admin(&regular, Some(BOT_ADMIN_ROLE)));
+    }
+
+    #[test]
+    fn bot_admin_role_is_admin() {
+        let role_holder = member(Permissions::SEND_MESSAGES, vec![1, BOT_ADMIN_ROLE]);
+        assert!(is_bot_admin(&role_holder, Some(BOT_ADMIN_ROLE)));
+        //The role only counts once a server has picked it
+        assert!(!is_bot_admin(&role_holder, None));
+    }
+
+    #[test]
+    fn missing_interaction_permissions_are_not_admin() {
+        let member = Member::default();
+        assert!(!is_bot_admin(&MemberInfo::from_interaction_member(&Cache::new(), Some(GuildId::new(1)), &member), Some(BOT_ADMIN_ROLE)));
+    }
+}
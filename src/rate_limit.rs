@@ -0,0 +1,365 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use serenity::prelude::*;
+use crate::locale::Locale;
+use crate::settings::{self, RateLimitSettings, Settings};
+
+/// Where the time comes from, so tests can move it along by hand.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Up to `capacity` uses in a row, then one more every `refill_every`.
+#[derive(Clone, Copy)]
+pub struct Limit {
+    pub capacity: u32,
+    pub refill_every: Duration
+}
+
+impl Limit {
+    pub const fn new(capacity: u32, refill_every_seconds: u64) -> Self {
+        Self {
+            capacity,
+            refill_every: Duration::from_secs(refill_every_seconds)
+        }
+    }
+}
+
+impl From<RateLimitSettings> for Limit {
+    fn from(settings: RateLimitSettings) -> Self {
+        Self::new(settings.uses, settings.seconds)
+    }
+}
+
+/// Extra limits for one command, on top of the per user and per channel ones.
+#[derive(Clone, Copy, Default)]
+pub struct CommandLimits {
+    /// Shared by everyone
+    pub global: Option<Limit>,
+    /// For each user separately
+    pub per_user: Option<Limit>
+}
+
+#[derive(Clone)]
+pub struct RateLimits {
+    pub per_user: Limit,
+    pub per_channel: Limit,
+    /// By command name
+    pub per_command: HashMap<String, CommandLimits>
+}
+
+impl From<&Settings> for RateLimits {
+    fn from(settings: &Settings) -> Self {
+        let mut per_command = HashMap::new();
+        per_command.insert(String::from("spam"), CommandLimits {
+            global: Some(settings.spam_rate_limit.into()),
+            per_user: Some(settings.spam_user_rate_limit.into())
+        });
+        Self {
+            per_user: settings.user_rate_limit.into(),
+            per_channel: settings.channel_rate_limit.into(),
+            per_command
+        }
+    }
+}
+
+/// The limits hodgey.toml starts with
+impl Default for RateLimits {
+    fn default() -> Self {
+        Self::from(&Settings::default())
+    }
+}
+
+/// Instead of counting tokens the bucket remembers when it will be full again,
+/// which keeps everything in whole durations with no rounding.
+struct TokenBucket {
+    full_at: Instant
+}
+
+impl TokenBucket {
+    fn new(now: Instant) -> Self {
+        Self { full_at: now }
+    }
+
+    /// How long until there is a token to take
+    fn wait_time(&self, limit: Limit, now: Instant) -> Duration {
+        let backlog = self.full_at.saturating_duration_since(now) + limit.refill_every;
+        backlog.saturating_sub(limit.refill_every * limit.capacity)
+    }
+
+    fn take(&mut self, limit: Limit, now: Instant) {
+        self.full_at = self.full_at.max(now) + limit.refill_every;
+    }
+
+    fn is_full(&self, now: Instant) -> bool {
+        self.full_at <= now
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum BucketKey {
+    User(u64),
+    Channel(u64),
+    Command(String),
+    UserCommand(u64, String)
+}
+
+/// A command was used too quickly
+#[derive(Debug, PartialEq)]
+pub struct Cooldown {
+    pub retry_after: Duration,
+    /// The user has already been told during this cooldown, so there is no need to say it again
+    pub already_warned: bool
+}
+
+/// Buckets are only kept for people who have used something recently, full ones get cleared out past this many.
+const MAX_BUCKETS: usize = 10_000;
+
+pub struct RateLimiter<C: Clock> {
+    clock: C,
+    /// `None` follows hodgey.toml, so changes to it apply straight away
+    limits: Option<RateLimits>,
+    buckets: HashMap<BucketKey, TokenBucket>,
+    /// When each user's current warning runs out
+    warned_until: HashMap<u64, Instant>
+}
+
+impl<C: Clock> RateLimiter<C> {
+    /// Always uses the same limits
+    pub fn new(clock: C, limits: RateLimits) -> Self {
+        Self {
+            clock,
+            limits: Some(limits),
+            buckets: HashMap::new(),
+            warned_until: HashMap::new()
+        }
+    }
+
+    /// Uses whatever limits hodgey.toml has at the time
+    pub fn from_settings(clock: C) -> Self {
+        Self { limits: None, ..Self::new(clock, RateLimits::default()) }
+    }
+
+    fn limits_for(&self, user_id: u64, channel_id: u64, command: &str) -> Vec<(BucketKey, Limit)> {
+        let from_settings;
+        let rate_limits = match &self.limits {
+            Some(rate_limits) => rate_limits,
+            None => {
+                from_settings = RateLimits::from(&*settings::get());
+                &from_settings
+            }
+        };
+        let mut limits = vec![
+            (BucketKey::User(user_id), rate_limits.per_user),
+            (BucketKey::Channel(channel_id), rate_limits.per_channel)
+        ];
+        if let Some(command_limits) = rate_limits.per_command.get(command) {
+            if let Some(limit) = command_limits.global {
+                limits.push((BucketKey::Command(command.to_string()), limit));
+            }
+            if let Some(limit) = command_limits.per_user {
+                limits.push((BucketKey::UserCommand(user_id, command.to_string()), limit));
+            }
+        }
+        limits
+    }
+
+    /// Uses up one go from every bucket the command falls under, or none of them if any is empty.
+    pub fn check(&mut self, user_id: u64, channel_id: u64, command: &str) -> Result<(), Cooldown> {
+        let now = self.clock.now();
+        let limits = self.limits_for(user_id, channel_id, command);
+
+        let mut retry_after = Duration::ZERO;
+        for (key, limit) in &limits {
+            if let Some(bucket) = self.buckets.get(key) {
+                retry_after = retry_after.max(bucket.wait_time(*limit, now));
+            }
+        }
+
+        if !retry_after.is_zero() {
+            let already_warned = self.warned_until.get(&user_id).is_some_and(|until| *until > now);
+            if !already_warned {
+                self.warned_until.insert(user_id, now + retry_after);
+            }
+            return Err(Cooldown { retry_after, already_warned });
+        }
+
+        for (key, limit) in limits {
+            self.buckets.entry(key).or_insert_with(|| TokenBucket::new(now)).take(limit, now);
+        }
+        self.prune(now);
+        Ok(())
+    }
+
+    fn prune(&mut self, now: Instant) {
+        if self.buckets.len() <= MAX_BUCKETS {
+            return;
+        }
+        self.buckets.retain(|_, bucket| !bucket.is_full(now));
+        self.warned_until.retain(|_, until| *until > now);
+    }
+}
+
+pub struct RateLimiters;
+
+impl TypeMapKey for RateLimiters {
+    type Value = Mutex<RateLimiter<SystemClock>>;
+}
+
+/// "Slow down! Try again in 12 seconds."
+pub fn cooldown_message(cooldown: &Cooldown, locale: Locale) -> String {
+    let seconds = cooldown.retry_after.as_secs_f64().ceil().max(1.0) as u64;
+    if seconds == 1 {
+        String::from(locale.text("cooldown_one"))
+    }
+    else {
+        locale.format("cooldown", &[("seconds", &seconds.to_string())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex as StdMutex;
+
+    struct FakeClock {
+        start: Instant,
+        elapsed: StdMutex<Duration>
+    }
+
+    impl FakeClock {
+        fn new() -> Self {
+            Self { start: Instant::now(), elapsed: StdMutex::new(Duration::ZERO) }
+        }
+    }
+
+    impl Clock for &FakeClock {
+        fn now(&self) -> Instant {
+            self.start + *self.elapsed.lock().unwrap()
+        }
+    }
+
+    fn advance(clock: &FakeClock, seconds: f64) {
+        *clock.elapsed.lock().unwrap() += Duration::from_secs_f64(seconds);
+    }
+
+    fn limits() -> RateLimits {
+        let mut per_command = HashMap::new();
+        per_command.insert(String::from("spam"), CommandLimits {
+            global: Some(Limit::new(3, 60)),
+            per_user: Some(Limit::new(1, 30))
+        });
+        RateLimits {
+            per_user: Limit::new(3, 10),
+            per_channel: Limit::new(5, 10),
+            per_command
+        }
+    }
+
+    const USER: u64 = 1;
+    const OTHER_USER: u64 = 2;
+    const CHANNEL: u64 = 100;
+    const OTHER_CHANNEL: u64 = 200;
+
+    #[test]
+    fn user_bucket_empties_then_refills() {
+        let clock = FakeClock::new();
+        let mut limiter = RateLimiter::new(&clock, limits());
+        for _ in 0..3 {
+            assert!(limiter.check(USER, CHANNEL, "hodgey joke").is_ok());
+        }
+        let cooldown = limiter.check(USER, CHANNEL, "hodgey joke").unwrap_err();
+        assert_eq!(cooldown.retry_after, Duration::from_secs(10));
+
+        advance(&clock, 9.0);
+        assert!(limiter.check(USER, CHANNEL, "hodgey joke").is_err());
+        advance(&clock, 1.0);
+        assert!(limiter.check(USER, CHANNEL, "hodgey joke").is_ok());
+        assert!(limiter.check(USER, CHANNEL, "hodgey joke").is_err());
+    }
+
+    #[test]
+    fn users_have_their_own_buckets() {
+        let clock = FakeClock::new();
+        let mut limiter = RateLimiter::new(&clock, limits());
+        for _ in 0..3 {
+            assert!(limiter.check(USER, CHANNEL, "hodgey joke").is_ok());
+        }
+        assert!(limiter.check(USER, CHANNEL, "hodgey joke").is_err());
+        assert!(limiter.check(OTHER_USER, CHANNEL, "hodgey joke").is_ok());
+    }
+
+    #[test]
+    fn channel_bucket_is_shared() {
+        let clock = FakeClock::new();
+        let mut limiter = RateLimiter::new(&clock, limits());
+        for user_id in 0..5 {
+            assert!(limiter.check(user_id, CHANNEL, "hodgey joke").is_ok());
+        }
+        assert!(limiter.check(5, CHANNEL, "hodgey joke").is_err());
+        assert!(limiter.check(5, OTHER_CHANNEL, "hodgey joke").is_ok());
+    }
+
+    #[test]
+    fn spam_has_its_own_cooldowns() {
+        let clock = FakeClock::new();
+        let mut limiter = RateLimiter::new(&clock, limits());
+        assert!(limiter.check(USER, CHANNEL, "spam").is_ok());
+        assert_eq!(limiter.check(USER, CHANNEL, "spam").unwrap_err().retry_after, Duration::from_secs(30));
+        //Other commands still work
+        assert!(limiter.check(USER, CHANNEL, "hodgey joke").is_ok());
+
+        //Everyone together can only spam 3 times a minute
+        assert!(limiter.check(OTHER_USER, OTHER_CHANNEL, "spam").is_ok());
+        assert!(limiter.check(3, OTHER_CHANNEL, "spam").is_ok());
+        assert!(limiter.check(4, OTHER_CHANNEL, "spam").is_err());
+        advance(&clock, 59.0);
+        assert!(limiter.check(4, OTHER_CHANNEL, "spam").is_err());
+        advance(&clock, 1.0);
+        assert!(limiter.check(4, OTHER_CHANNEL, "spam").is_ok());
+    }
+
+    #[test]
+    fn rejected_commands_dont_use_up_other_buckets() {
+        let clock = FakeClock::new();
+        let mut limiter = RateLimiter::new(&clock, limits());
+        assert!(limiter.check(USER, CHANNEL, "spam").is_ok());
+        for _ in 0..10 {
+            assert!(limiter.check(USER, CHANNEL, "spam").is_err());
+        }
+        //Only the first spam came out of the user's bucket
+        assert!(limiter.check(USER, CHANNEL, "hodgey joke").is_ok());
+        assert!(limiter.check(USER, CHANNEL, "hodgey joke").is_ok());
+        assert!(limiter.check(USER, CHANNEL, "hodgey joke").is_err());
+    }
+
+    #[test]
+    fn warns_once_per_cooldown() {
+        let clock = FakeClock::new();
+        let mut limiter = RateLimiter::new(&clock, limits());
+        assert!(limiter.check(USER, CHANNEL, "spam").is_ok());
+        assert!(!limiter.check(USER, CHANNEL, "spam").unwrap_err().already_warned);
+        advance(&clock, 5.0);
+        assert!(limiter.check(USER, CHANNEL, "spam").unwrap_err().already_warned);
+
+        advance(&clock, 25.0);
+        assert!(limiter.check(USER, CHANNEL, "spam").is_ok());
+        assert!(!limiter.check(USER, CHANNEL, "spam").unwrap_err().already_warned);
+    }
+
+    #[test]
+    fn cooldown_message_rounds_up() {
+        let cooldown = Cooldown { retry_after: Duration::from_millis(11_200), already_warned: false };
+        assert_eq!(cooldown_message(&cooldown, Locale::English), "Slow down! Try again in 12 seconds.");
+        let cooldown = Cooldown { retry_after: Duration::from_millis(300), already_warned: false };
+        assert_eq!(cooldown_message(&cooldown, Locale::English), "Slow down! Try again in a second.");
+    }
+}
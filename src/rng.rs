@@ -0,0 +1,34 @@
+use rand::{Rng, SeedableRng, thread_rng};
+use rand::rngs::StdRng;
+use serenity::prelude::*;
+use tracing::{error, info};
+
+/// Every random choice Hodgey makes comes from this one seeded generator,
+/// so running with the same seed and the same messages gives the same responses.
+pub struct SharedRng;
+
+impl TypeMapKey for SharedRng {
+    type Value = Mutex<StdRng>;
+}
+
+/// Uses `HODGEY_SEED` if it is set so a bug report can be replayed, otherwise a fresh random seed.
+/// The seed is logged either way.
+pub fn seed_from_env() -> u64 {
+    let seed = match std::env::var("HODGEY_SEED") {
+        Ok(seed) => seed.trim().parse().unwrap_or_else(|e| {
+            error!("HODGEY_SEED isn't a number ({e:?}), picking one instead");
+            thread_rng().gen()
+        }),
+        Err(_) => thread_rng().gen()
+    };
+    info!("RNG seed: {seed} (run with HODGEY_SEED={seed} to replay)");
+    seed
+}
+
+/// A generator of its own for one message or event, split off the shared one.
+/// It can be held across awaits and through long searches without keeping the shared one locked.
+pub async fn fork(data: &RwLock<TypeMap>) -> StdRng {
+    let rw_lock = data.read().await;
+    let mut shared_rng = rw_lock.get::<SharedRng>().expect("SharedRng not in TypeMap.").lock().await;
+    StdRng::seed_from_u64(shared_rng.gen())
+}
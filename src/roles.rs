@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+use serenity::all::{GuildId, Role, RoleId};
+use serenity::prelude::*;
+use crate::content::QuoteKind;
+use crate::{message_channel, templates};
+
+/// Every server's role names. The delete event only has the role if the cache did, so this is where its name comes from.
+/// Roles bots get for themselves are left out, so they come and go quietly.
+pub struct RoleNames;
+
+impl TypeMapKey for RoleNames {
+    type Value = Mutex<HashMap<u64, HashMap<RoleId, String>>>;
+}
+
+/// Called from `guild_create` so roles deleted later have names
+pub async fn remember(data: &RwLock<TypeMap>, guild_id: GuildId, roles: &HashMap<RoleId, Role>) {
+    let rw_lock = data.read().await;
+    let mut role_names = rw_lock.get::<RoleNames>().expect("RoleNames not in TypeMap.").lock().await;
+    role_names.insert(guild_id.get(), roles.iter().filter(|(_, role)| !role.managed).map(|(id, role)| (*id, role.name.clone())).collect());
+}
+
+/// Keeps the name up to date when a role is created or renamed
+pub async fn role_updated(data: &RwLock<TypeMap>, role: &Role) {
+    if role.managed {
+        return;
+    }
+    let rw_lock = data.read().await;
+    let mut role_names = rw_lock.get::<RoleNames>().expect("RoleNames not in TypeMap.").lock().await;
+    role_names.entry(role.guild_id.get()).or_default().insert(role.id, role.name.clone());
+}
+
+pub async fn role_created(ctx: &Context, role: &Role) {
+    role_updated(&ctx.data, role).await;
+    if role.managed {
+        return;
+    }
+    let quote = templates::event_quote(&ctx.data, QuoteKind::NewRole, &role.name).await;
+    message_channel::announce(ctx, role.guild_id, "role create", quote).await;
+}
+
+pub async fn role_deleted(ctx: &Context, guild_id: GuildId, role_id: RoleId) {
+    let rw_lock = ctx.data.read().await;
+    let mut role_names = rw_lock.get::<RoleNames>().expect("RoleNames not in TypeMap.").lock().await;
+    let name = role_names.get_mut(&guild_id.get()).and_then(|roles| roles.remove(&role_id));
+    drop(role_names); // drop mutex lock as soon as possible
+    drop(rw_lock);
+
+    let Some(name) = name else {
+        return;
+    };
+    let quote = templates::event_quote(&ctx.data, QuoteKind::DeletedRole, &name).await;
+    message_channel::announce(ctx, guild_id, "role delete", quote).await;
+}
@@ -0,0 +1,242 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::{Duration, SystemTime};
+use serde::Deserialize;
+use tracing::{error, info};
+use crate::HODGEY_BOT_ID;
+
+const DEFAULT_SETTINGS_PATH: &str = "hodgey.toml";
+/// How often the file is checked for changes
+const RELOAD_CHECK_EVERY: Duration = Duration::from_secs(5);
+
+/// Bot wide settings from hodgey.toml. Per server settings are in `config::GuildConfig`.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    /// Hodgey's own user id, used as the opponent in games against him
+    pub bot_id: u64,
+    /// The bot Hodgey gets mad at in servers that haven't picked one
+    pub default_rival_bot_id: Option<u64>,
+    /// From 0 to 1, for servers that haven't picked one
+    pub default_random_reply_chance: f64,
+    /// How long the chess engine thinks for each move
+    pub chess_engine_seconds: f64,
+    /// Most repeats allowed in one spam command
+    pub spam_limit: usize,
+    /// Commands and triggers one person can use, in any channel
+    pub user_rate_limit: RateLimitSettings,
+    /// Commands and triggers everyone in a channel can use between them
+    pub channel_rate_limit: RateLimitSettings,
+    /// Spams everyone in every server can use between them, on top of the others
+    pub spam_rate_limit: RateLimitSettings,
+    /// Spams one person can use, on top of the others
+    pub spam_user_rate_limit: RateLimitSettings
+}
+
+/// Up to `uses` in a row, then one more every `seconds`. Written as `{ uses = 8, seconds = 3 }`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimitSettings {
+    pub uses: u32,
+    pub seconds: u64
+}
+
+impl RateLimitSettings {
+    const fn new(uses: u32, seconds: u64) -> Self {
+        Self { uses, seconds }
+    }
+
+    fn validate(self, name: &str) -> Result<(), String> {
+        if !(1..=1000).contains(&self.uses) {
+            return Err(format!("{name} uses must be between 1 and 1000, not {}", self.uses));
+        }
+        if !(1..=3600).contains(&self.seconds) {
+            return Err(format!("{name} seconds must be between 1 and 3600, not {}", self.seconds));
+        }
+        Ok(())
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            bot_id: HODGEY_BOT_ID,
+            default_rival_bot_id: Some(159985870458322944), //MEE6
+            default_random_reply_chance: 0.01,
+            chess_engine_seconds: 1.0,
+            spam_limit: 5,
+            user_rate_limit: RateLimitSettings::new(8, 3),
+            channel_rate_limit: RateLimitSettings::new(15, 2),
+            spam_rate_limit: RateLimitSettings::new(5, 60),
+            spam_user_rate_limit: RateLimitSettings::new(1, 30)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SettingsError {
+    Read(PathBuf, std::io::Error),
+    Parse(PathBuf, toml::de::Error),
+    Invalid(PathBuf, String)
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Read(path, e) => write!(f, "Couldn't read {}: {e}", path.display()),
+            Self::Parse(path, e) => write!(f, "{} isn't valid: {e}", path.display()),
+            Self::Invalid(path, problem) => write!(f, "{}: {problem}", path.display())
+        }
+    }
+}
+
+impl Settings {
+    /// Describes the first setting that can't work, if any
+    pub fn validate(&self) -> Result<(), String> {
+        if self.bot_id == 0 {
+            return Err(String::from("bot_id can't be 0"));
+        }
+        if self.default_rival_bot_id == Some(0) {
+            return Err(String::from("default_rival_bot_id can't be 0, leave it out to have no rival"));
+        }
+        if !(0.0..=1.0).contains(&self.default_random_reply_chance) {
+            return Err(format!("default_random_reply_chance must be between 0 and 1, not {}", self.default_random_reply_chance));
+        }
+        if !(self.chess_engine_seconds > 0.0 && self.chess_engine_seconds <= 60.0) {
+            return Err(format!("chess_engine_seconds must be more than 0 and at most 60, not {}", self.chess_engine_seconds));
+        }
+        if !(1..=20).contains(&self.spam_limit) {
+            return Err(format!("spam_limit must be between 1 and 20, not {}", self.spam_limit));
+        }
+        self.user_rate_limit.validate("user_rate_limit")?;
+        self.channel_rate_limit.validate("channel_rate_limit")?;
+        self.spam_rate_limit.validate("spam_rate_limit")?;
+        self.spam_user_rate_limit.validate("spam_user_rate_limit")?;
+        Ok(())
+    }
+
+    pub fn parse(path: PathBuf, contents: &str) -> Result<Self, SettingsError> {
+        let settings: Self = match toml::from_str(contents) {
+            Ok(settings) => settings,
+            Err(e) => return Err(SettingsError::Parse(path, e))
+        };
+        match settings.validate() {
+            Ok(()) => Ok(settings),
+            Err(problem) => Err(SettingsError::Invalid(path, problem))
+        }
+    }
+
+    pub fn chess_engine_time(&self) -> Duration {
+        Duration::from_secs_f64(self.chess_engine_seconds)
+    }
+}
+
+/// `HODGEY_TOML_PATH`, or hodgey.toml if that isn't set
+pub fn path() -> PathBuf {
+    PathBuf::from(std::env::var("HODGEY_TOML_PATH").unwrap_or_else(|_| String::from(DEFAULT_SETTINGS_PATH)))
+}
+
+/// Reads the settings file. A missing file just means everything is left at its default.
+pub fn load() -> Result<Settings, SettingsError> {
+    let path = path();
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => Settings::parse(path, &contents),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            info!("No settings file at {}, using defaults", path.display());
+            Ok(Settings::default())
+        },
+        Err(e) => Err(SettingsError::Read(path, e))
+    }
+}
+
+fn current() -> &'static RwLock<Arc<Settings>> {
+    static CURRENT: OnceLock<RwLock<Arc<Settings>>> = OnceLock::new();
+    CURRENT.get_or_init(|| RwLock::new(Arc::new(Settings::default())))
+}
+
+/// The settings in use right now. Kept outside the TypeMap because defaults and the chess engine need them with no `Context` around.
+pub fn get() -> Arc<Settings> {
+    current().read().unwrap().clone()
+}
+
+pub fn set(settings: Settings) {
+    *current().write().unwrap() = Arc::new(settings);
+}
+
+fn modified_time() -> Option<SystemTime> {
+    std::fs::metadata(path()).and_then(|metadata| metadata.modified()).ok()
+}
+
+/// Checks the file every few seconds and swaps in the new settings when it changes.
+/// Broken settings are reported and the old ones kept, so a typo never takes the bot down.
+pub fn watch() {
+    tokio::spawn(async move {
+        let mut last_modified = modified_time();
+        loop {
+            tokio::time::sleep(RELOAD_CHECK_EVERY).await;
+            let modified = modified_time();
+            if modified == last_modified {
+                continue;
+            }
+            last_modified = modified;
+            match load() {
+                Ok(settings) => {
+                    info!("Reloaded settings from {}", path().display());
+                    set(settings);
+                },
+                Err(e) => error!("Keeping the old settings. {e}")
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(contents: &str) -> Result<Settings, SettingsError> {
+        Settings::parse(PathBuf::from("hodgey.toml"), contents)
+    }
+
+    #[test]
+    fn empty_file_is_all_defaults() {
+        assert_eq!(parse("").unwrap(), Settings::default());
+    }
+
+    #[test]
+    fn missing_settings_keep_their_defaults() {
+        let settings = parse("spam_limit = 3\nchess_engine_seconds = 0.5").unwrap();
+        assert_eq!(settings.spam_limit, 3);
+        assert_eq!(settings.chess_engine_time(), Duration::from_millis(500));
+        assert_eq!(settings.bot_id, HODGEY_BOT_ID);
+    }
+
+    #[test]
+    fn out_of_range_values_are_explained() {
+        let e = parse("default_random_reply_chance = 2.0").unwrap_err();
+        assert_eq!(e.to_string(), "hodgey.toml: default_random_reply_chance must be between 0 and 1, not 2");
+        let e = parse("spam_limit = 0").unwrap_err();
+        assert_eq!(e.to_string(), "hodgey.toml: spam_limit must be between 1 and 20, not 0");
+        assert!(parse("chess_engine_seconds = -1.0").is_err());
+        let e = parse("channel_rate_limit = { uses = 0, seconds = 2 }").unwrap_err();
+        assert_eq!(e.to_string(), "hodgey.toml: channel_rate_limit uses must be between 1 and 1000, not 0");
+    }
+
+    #[test]
+    fn rate_limits() {
+        let settings = parse("user_rate_limit = { uses = 4, seconds = 10 }").unwrap();
+        assert_eq!(settings.user_rate_limit, RateLimitSettings::new(4, 10));
+        assert_eq!(settings.spam_rate_limit, Settings::default().spam_rate_limit);
+        assert!(matches!(parse("user_rate_limit = { uses = 4 }").unwrap_err(), SettingsError::Parse(..)));
+        assert!(matches!(parse("user_rate_limit = { uses = 4, seconds = 1, burst = 2 }").unwrap_err(), SettingsError::Parse(..)));
+    }
+
+    #[test]
+    fn typos_and_wrong_types_are_errors() {
+        let e = parse("spam_limt = 3").unwrap_err();
+        assert!(matches!(e, SettingsError::Parse(..)));
+        assert!(e.to_string().contains("spam_limt"), "{e}");
+        assert!(matches!(parse("bot_id = \"hodgey\"").unwrap_err(), SettingsError::Parse(..)));
+    }
+}
@@ -0,0 +1,288 @@
+use serenity::all::{CommandInteraction, CommandOptionType, ResolvedOption, ResolvedValue};
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateInteractionResponse, CreateInteractionResponseMessage};
+use serenity::prelude::*;
+use tracing::error;
+use crate::commands::{self, Command, CommandContext};
+use crate::config;
+use crate::locale::Locale;
+use crate::transport::SlashTransport;
+
+/// The text for a key in every other language, by Discord locale code, e.g. ("es-ES", "...")
+fn translations(key: &'static str) -> Vec<(&'static str, &'static str)> {
+    Locale::ALL.into_iter()
+        .filter(|locale| *locale != Locale::English)
+        .flat_map(|locale| locale.discord_codes().iter().map(move |code| (*code, locale.text(key))))
+        .collect()
+}
+
+fn command(name: &str, key: &'static str) -> CreateCommand {
+    translations(key).into_iter().fold(CreateCommand::new(name).description(Locale::English.text(key)), |command, (code, text)| command.description_localized(code, text))
+}
+
+fn option(kind: CommandOptionType, name: &str, key: &'static str) -> CreateCommandOption {
+    translations(key).into_iter().fold(CreateCommandOption::new(kind, name, Locale::English.text(key)), |option, (code, text)| option.description_localized(code, text))
+}
+
+fn subcommand(name: &str, key: &'static str) -> CreateCommandOption {
+    option(CommandOptionType::SubCommand, name, key)
+}
+
+fn toggle_choice(option: CreateCommandOption, value: &str, key: &'static str) -> CreateCommandOption {
+    option.add_string_choice_localized(Locale::English.text(key), value, translations(key))
+}
+
+fn new_game_subcommand() -> CreateCommandOption {
+    subcommand("new", "slash_new")
+        .add_sub_option(option(CommandOptionType::User, "opponent", "slash_opponent"))
+}
+
+fn resign_subcommand() -> CreateCommandOption {
+    subcommand("resign", "slash_resign")
+}
+
+fn show_subcommand() -> CreateCommandOption {
+    subcommand("show", "slash_show")
+}
+
+/// Every slash command Hodgey has, registered globally when the bot connects.
+/// Descriptions come from the locale so Discord shows them in the user's language.
+pub fn create_commands() -> Vec<CreateCommand> {
+    let toggle_setting = option(CommandOptionType::String, "setting", "slash_chess_toggle_setting").required(true);
+    let toggle_setting = toggle_choice(toggle_setting, "coordinates", "choice_coordinates");
+    let toggle_setting = toggle_choice(toggle_setting, "board flip", "choice_board_flip");
+    let toggle_setting = toggle_choice(toggle_setting, "text board", "choice_text_board");
+    vec![
+        command("help", "slash_help")
+            .add_option(option(CommandOptionType::String, "topic", "slash_help_topic")),
+        command("joke", "slash_joke"),
+        command("val", "slash_val")
+            .add_option(subcommand("agent", "slash_val_agent"))
+            .add_option(subcommand("squad", "slash_val_squad")),
+        command("clip-review", "slash_clip_review"),
+        command("decide", "slash_decide")
+            .add_option(option(CommandOptionType::String, "question", "slash_decide_question")),
+        command("chess", "slash_chess")
+            .add_option(new_game_subcommand())
+            .add_option(subcommand("move", "slash_move")
+                .add_sub_option(option(CommandOptionType::String, "move", "slash_chess_move")
+                    .required(true)
+                    .set_autocomplete(true)))
+            .add_option(resign_subcommand())
+            .add_option(show_subcommand())
+            .add_option(subcommand("board", "slash_chess_board"))
+            .add_option(subcommand("toggle", "slash_chess_toggle")
+                .add_sub_option(toggle_setting)),
+        command("checkers", "slash_checkers")
+            .add_option(new_game_subcommand())
+            .add_option(subcommand("move", "slash_move")
+                .add_sub_option(option(CommandOptionType::String, "move", "slash_checkers_move")
+                    .required(true)))
+            .add_option(resign_subcommand())
+            .add_option(show_subcommand()),
+        command("connect4", "slash_connect4")
+            .add_option(new_game_subcommand())
+            .add_option(subcommand("move", "slash_connect4_move")
+                .add_sub_option(option(CommandOptionType::Integer, "column", "slash_connect4_column")
+                    .required(true)
+                    .min_int_value(1)
+                    .max_int_value(7)))
+            .add_option(resign_subcommand())
+            .add_option(show_subcommand()),
+        command("tictactoe", "slash_tictactoe")
+            .add_option(new_game_subcommand())
+            .add_option(subcommand("move", "slash_tictactoe_move")
+                .add_sub_option(option(CommandOptionType::Integer, "square", "slash_tictactoe_square")
+                    .required(true)
+                    .min_int_value(1)
+                    .max_int_value(9)))
+            .add_option(resign_subcommand())
+            .add_option(show_subcommand())
+    ]
+}
+
+/// Works out which registered command a slash command stands for, along with the arguments
+/// and mentions the text version would have had. This way both versions share one handler.
+fn to_registered_command(interaction: &CommandInteraction) -> Option<(&'static Command, String, Vec<u64>)> {
+    let options = interaction.data.options();
+    let (subcommand, options) = match options.first() {
+        Some(ResolvedOption { name, value: ResolvedValue::SubCommand(sub_options), .. }) => (*name, sub_options.as_slice()),
+        _ => ("", options.as_slice())
+    };
+
+    let mut argument = String::new();
+    let mut mentions = Vec::new();
+    for option in options {
+        match &option.value {
+            ResolvedValue::String(text) => argument = text.to_string(),
+            ResolvedValue::Integer(number) => argument = number.to_string(),
+            ResolvedValue::User(user, _) => mentions.push(user.id.get()),
+            _ => ()
+        }
+    }
+
+    let (name, args) = text_command(interaction.data.name.as_str(), subcommand, argument)?;
+    commands::find_command_by_name(name).map(|command| (command, args, mentions))
+}
+
+/// The text command a slash command and subcommand stand for, and its arguments.
+/// `argument` is the value of whichever option was given, or empty.
+fn text_command(name: &str, subcommand: &str, argument: String) -> Option<(&'static str, String)> {
+    Some(match (name, subcommand) {
+        ("help", _) => ("hodgey help", argument),
+        ("joke", _) => ("hodgey joke", String::new()),
+        ("val", "agent") => ("hodgey val agent", String::new()),
+        ("val", "squad") => ("hodgey val squad", String::new()),
+        ("clip-review", _) => ("hodgey clip review", String::new()),
+        ("decide", _) => ("hodgey decide", argument),
+        ("chess", "new") => ("chess new", String::new()),
+        ("chess", "move") => ("move", argument),
+        ("chess", "resign") => ("chess resign", String::new()),
+        ("chess", "show") => ("chess show", String::new()),
+        ("chess", "board") => ("chess board", String::new()),
+        ("chess", "toggle") => match argument.as_str() {
+            "coordinates" => ("toggle coordinates", String::new()),
+            "board flip" => ("toggle board flip", String::new()),
+            "text board" => ("toggle text board", String::new()),
+            _ => return None
+        },
+        ("checkers", "new") => ("checkers new", String::new()),
+        ("checkers", "move") => ("checkers move", argument),
+        ("checkers", "resign") => ("checkers resign", String::new()),
+        ("checkers", "show") => ("checkers show", String::new()),
+        ("connect4", subcommand) => ("connect4", format!("{subcommand} {argument}")),
+        ("tictactoe", subcommand) => ("tictactoe", format!("{subcommand} {argument}")),
+        _ => return None
+    })
+}
+
+pub async fn slash_command_interaction(ctx: &Context, interaction: &CommandInteraction) {
+    let Some((command, args, mentions)) = to_registered_command(interaction) else {
+        error!("Unknown slash command \"{}\"", interaction.data.name);
+        return;
+    };
+
+    let config = config::guild_config(&ctx.data, interaction.guild_id).await;
+    let locale = Locale::from_code(&interaction.locale).unwrap_or(config.locale);
+    if !config.channel_allowed(interaction.channel_id.get()) {
+        let response = CreateInteractionResponse::Message(CreateInteractionResponseMessage::new()
+            .content(locale.text("channel_not_allowed"))
+            .ephemeral(true));
+        if let Err(e) = interaction.create_response(&ctx.http, response).await {
+            error!("Error sending interaction response: {e:?}");
+        }
+        return;
+    }
+
+    //The bot can take a while to pick its move, deferring gives us 15 minutes instead of 3 seconds
+    if let Err(e) = interaction.defer(&ctx.http).await {
+        error!("Error sending interaction response: {e:?}");
+        return;
+    }
+
+    let transport = SlashTransport { http: &ctx.http, cache: &ctx.cache, interaction };
+    let cmd = CommandContext {
+        data: &ctx.data,
+        transport: &transport,
+        author_id: interaction.user.id.get(),
+        channel_id: interaction.channel_id,
+        guild_id: interaction.guild_id,
+        mentions,
+        args: &args,
+        locale
+    };
+    commands::run(command, &cmd).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(name: &str, subcommand: &str, argument: &str) -> Option<(&'static str, String)> {
+        text_command(name, subcommand, argument.to_string())
+    }
+
+    #[test]
+    fn every_slash_command_has_a_text_command() {
+        let expected = [
+            (("help", "", "chess"), ("hodgey help", "chess")),
+            (("joke", "", ""), ("hodgey joke", "")),
+            (("val", "agent", ""), ("hodgey val agent", "")),
+            (("val", "squad", ""), ("hodgey val squad", "")),
+            (("clip-review", "", ""), ("hodgey clip review", "")),
+            (("decide", "", "pizza or pasta?"), ("hodgey decide", "pizza or pasta?")),
+            (("chess", "new", ""), ("chess new", "")),
+            (("chess", "move", "Nf3"), ("move", "Nf3")),
+            (("chess", "resign", ""), ("chess resign", "")),
+            (("chess", "show", ""), ("chess show", "")),
+            (("chess", "board", ""), ("chess board", "")),
+            (("chess", "toggle", "coordinates"), ("toggle coordinates", "")),
+            (("chess", "toggle", "board flip"), ("toggle board flip", "")),
+            (("chess", "toggle", "text board"), ("toggle text board", "")),
+            (("checkers", "new", ""), ("checkers new", "")),
+            (("checkers", "move", "22x15x8"), ("checkers move", "22x15x8")),
+            (("checkers", "resign", ""), ("checkers resign", "")),
+            (("checkers", "show", ""), ("checkers show", "")),
+            (("connect4", "new", ""), ("connect4", "new ")),
+            (("connect4", "move", "4"), ("connect4", "move 4")),
+            (("connect4", "resign", ""), ("connect4", "resign ")),
+            (("tictactoe", "move", "5"), ("tictactoe", "move 5")),
+            (("tictactoe", "show", ""), ("tictactoe", "show "))
+        ];
+        for ((name, subcommand, argument), (command, args)) in expected {
+            assert_eq!(text(name, subcommand, argument), Some((command, String::from(args))), "/{name} {subcommand}");
+            assert!(commands::find_command_by_name(command).is_some(), "{command} isn't a command");
+        }
+    }
+
+    #[test]
+    fn unknown_slash_commands() {
+        assert_eq!(text("chess", "toggle", "sound"), None);
+        assert_eq!(text("val", "map", ""), None);
+        assert_eq!(text("poker", "new", ""), None);
+    }
+
+    #[test]
+    fn every_registered_slash_command_is_handled() {
+        for command in create_commands() {
+            let json = serde_json::to_value(&command).unwrap();
+            let name = json["name"].as_str().unwrap();
+            let options = json["options"].as_array().cloned().unwrap_or_default();
+            let subcommands: Vec<&str> = options.iter()
+                .filter(|option| option["type"] == 1)
+                .filter_map(|option| option["name"].as_str())
+                .collect();
+            if subcommands.is_empty() {
+                assert!(text(name, "", "").is_some(), "/{name}");
+            }
+            for subcommand in subcommands {
+                let argument = if subcommand == "toggle" { "coordinates" } else { "" };
+                assert!(text(name, subcommand, argument).is_some(), "/{name} {subcommand}");
+            }
+        }
+    }
+
+    //Discord refuses the whole list if one description is too long
+    #[test]
+    fn descriptions_fit_and_are_translated() {
+        fn check(json: &serde_json::Value, path: &str) {
+            if let Some(description) = json["description"].as_str() {
+                assert!(!description.is_empty() && description.chars().count() <= 100, "{path}: {description}");
+                let localized = json["description_localizations"].as_object().unwrap_or_else(|| panic!("{path} isn't translated"));
+                assert!(localized.contains_key("es-ES"), "{path}");
+                for text in localized.values().filter_map(|text| text.as_str()) {
+                    assert!(!text.is_empty() && text.chars().count() <= 100, "{path}: {text}");
+                }
+            }
+            for choice in json["choices"].as_array().into_iter().flatten() {
+                assert!(choice["name_localizations"].as_object().is_some_and(|localized| localized.contains_key("es-ES")), "{path}: {choice}");
+            }
+            for option in json["options"].as_array().into_iter().flatten() {
+                check(option, &format!("{path} {}", option["name"].as_str().unwrap()));
+            }
+        }
+        for command in create_commands() {
+            let json = serde_json::to_value(&command).unwrap();
+            check(&json, json["name"].as_str().unwrap());
+        }
+    }
+}
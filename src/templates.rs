@@ -0,0 +1,312 @@
+use std::fmt;
+use std::str::Chars;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serenity::prelude::*;
+use tracing::error;
+use crate::content::{self, Content, QuoteKind};
+use crate::locale::Locale;
+use crate::rng;
+use crate::transport::Transport;
+
+/// Stops a pack or custom trigger from nesting choices deep enough to matter
+const MAX_DEPTH: usize = 8;
+
+/// Something a placeholder in braces gets swapped for
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Placeholder {
+    /// A random one of these quotes, used as it is
+    Quote(QuoteKind),
+    /// A mention of whoever sent the message
+    Author,
+    Channel,
+    /// Whatever a server event is about, like the member who joined
+    Name,
+    /// A mention of anyone in the server who isn't a bot
+    RandomMember
+}
+
+impl Placeholder {
+    const ALL: [(&'static str, Self); 9] = [
+        ("song", Self::Quote(QuoteKind::Songs)),
+        ("book", Self::Quote(QuoteKind::Books)),
+        ("movie", Self::Quote(QuoteKind::Movies)),
+        ("bad", Self::Quote(QuoteKind::BadSynonyms)),
+        ("agent", Self::Quote(QuoteKind::ValAgents)),
+        ("author", Self::Author),
+        ("channel", Self::Channel),
+        ("random_member", Self::RandomMember),
+        ("name", Self::Name)
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().find(|(placeholder_name, _)| *placeholder_name == name).map(|(_, placeholder)| *placeholder)
+    }
+
+    fn value(self, context: &Context, rng: &mut impl Rng) -> String {
+        match self {
+            Self::Quote(kind) => context.content.choose(kind, rng).to_string(),
+            Self::Author => context.author_id.map_or(String::from("someone"), |author_id| format!("<@{author_id}>")),
+            Self::Channel => context.channel_id.map_or(String::from("here"), |channel_id| format!("<#{channel_id}>")),
+            Self::Name => context.name.to_string(),
+            Self::RandomMember => match context.member_ids.choose(rng) {
+                Some(member_id) => format!("<@{member_id}>"),
+                None => String::from("someone")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Part {
+    Text(String),
+    Placeholder(Placeholder),
+    /// One of these at random, each can have placeholders and choices of its own
+    Choice(Vec<Vec<Part>>)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TemplateError {
+    Unclosed,
+    UnexpectedClose,
+    UnknownPlaceholder(String),
+    TooDeep
+}
+
+impl TemplateError {
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            Self::Unclosed => String::from(locale.text("template_unclosed")),
+            Self::UnexpectedClose => String::from(locale.text("template_unexpected_close")),
+            Self::UnknownPlaceholder(name) => {
+                let names: Vec<String> = Placeholder::ALL.iter().map(|(name, _)| format!("{{{name}}}")).collect();
+                //The name is whatever was typed, so it goes in last
+                locale.format("template_unknown_placeholder", &[("options", &names.join(", ")), ("placeholder", &format!("{{{name}}}"))])
+            },
+            Self::TooDeep => locale.format("template_too_deep", &[("depth", &MAX_DEPTH.to_string())])
+        }
+    }
+}
+
+//Packs are checked when they load, and the log is in English
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message(Locale::English))
+    }
+}
+
+/// Text with placeholders to fill in when it gets sent:
+/// - `{song}`, `{book}`, `{movie}`, `{bad}` and `{agent}` become a random one of those quotes
+/// - `{author}`, `{channel}` and `{random_member}` become mentions
+/// - `{name}` is what a server event is about, e.g. the member who joined
+/// - `{this|that|the other}` picks one of the options, which can have placeholders and choices inside
+/// - `\{`, `\}`, `\|` and `\\` are just the character
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    parts: Vec<Part>
+}
+
+/// What a template is filled in with
+pub struct Context<'a> {
+    pub content: &'a Content,
+    /// Server events don't have an author or channel
+    pub author_id: Option<u64>,
+    pub channel_id: Option<u64>,
+    pub name: &'a str,
+    /// Only looked up when the template has `{random_member}` in it
+    pub member_ids: Vec<u64>
+}
+
+impl Template {
+    pub fn parse(text: &str) -> Result<Self, TemplateError> {
+        let mut options = parse_options(&mut text.chars(), 0)?;
+        //Outside of braces "|" is just text, so there is only ever one option
+        Ok(Self { parts: options.pop().unwrap_or_default() })
+    }
+
+    fn needs_members(&self) -> bool {
+        fn needs_members(parts: &[Part]) -> bool {
+            parts.iter().any(|part| match part {
+                Part::Placeholder(placeholder) => *placeholder == Placeholder::RandomMember,
+                Part::Choice(options) => options.iter().any(|option| needs_members(option)),
+                Part::Text(_) => false
+            })
+        }
+        needs_members(&self.parts)
+    }
+
+    pub fn render(&self, context: &Context, rng: &mut impl Rng) -> String {
+        let mut rendered = String::new();
+        render_parts(&self.parts, context, rng, &mut rendered);
+        rendered
+    }
+}
+
+fn push_text(text: &mut String, options: &mut [Vec<Part>]) {
+    if !text.is_empty() {
+        options.last_mut().expect("There is always an option").push(Part::Text(std::mem::take(text)));
+    }
+}
+
+/// Reads to the end of the text, or to the "}" closing the braces `depth` levels in. Inside braces "|" starts another option.
+fn parse_options(chars: &mut Chars, depth: usize) -> Result<Vec<Vec<Part>>, TemplateError> {
+    let mut options = vec![Vec::new()];
+    let mut text = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => text.push(chars.next().unwrap_or('\\')),
+            '{' => {
+                if depth == MAX_DEPTH {
+                    return Err(TemplateError::TooDeep);
+                }
+                push_text(&mut text, &mut options);
+                let inner = parse_options(chars, depth + 1)?;
+                options.last_mut().expect("There is always an option").push(braces_part(inner)?);
+            },
+            '}' if depth == 0 => return Err(TemplateError::UnexpectedClose),
+            '}' => {
+                push_text(&mut text, &mut options);
+                return Ok(options);
+            },
+            '|' if depth > 0 => {
+                push_text(&mut text, &mut options);
+                options.push(Vec::new());
+            },
+            _ => text.push(c)
+        }
+    }
+    if depth > 0 {
+        return Err(TemplateError::Unclosed);
+    }
+    push_text(&mut text, &mut options);
+    Ok(options)
+}
+
+/// "{a|b}" is a choice, "{name}" a placeholder
+fn braces_part(mut options: Vec<Vec<Part>>) -> Result<Part, TemplateError> {
+    if options.len() > 1 {
+        return Ok(Part::Choice(options));
+    }
+    let name = match options.pop().unwrap_or_default().as_slice() {
+        [Part::Text(name)] => name.trim().to_lowercase(),
+        [] => String::new(),
+        //A choice of one, like "{{song}}", is the same as what's inside
+        parts => return Ok(Part::Choice(vec![parts.to_vec()]))
+    };
+    Placeholder::from_name(&name).map(Part::Placeholder).ok_or(TemplateError::UnknownPlaceholder(name))
+}
+
+fn render_parts(parts: &[Part], context: &Context, rng: &mut impl Rng, rendered: &mut String) {
+    for part in parts {
+        match part {
+            Part::Text(text) => rendered.push_str(text),
+            Part::Placeholder(placeholder) => rendered.push_str(&placeholder.value(context, rng)),
+            Part::Choice(options) => {
+                if let Some(option) = options.choose(rng) {
+                    render_parts(option, context, rng, rendered);
+                }
+            }
+        }
+    }
+}
+
+/// Fills in a template for a message, only asking Discord for the members when it has to.
+/// Packs and custom triggers are checked when they are added, so text that doesn't parse can only come from the code and is sent as it is.
+pub async fn render(text: &str, content: &Content, transport: &dyn Transport, author_id: u64, channel_id: u64, rng: &mut impl Rng) -> String {
+    let Some(template) = parse_or_log(text) else {
+        return text.to_string();
+    };
+    let member_ids = if template.needs_members() {
+        transport.human_member_ids().await.unwrap_or_else(|e| {
+            error!("Error getting members: {e:?}");
+            Vec::new()
+        })
+    }
+    else {
+        Vec::new()
+    };
+    template.render(&Context { content, author_id: Some(author_id), channel_id: Some(channel_id), name: "", member_ids }, rng)
+}
+
+fn parse_or_log(text: &str) -> Option<Template> {
+    Template::parse(text).map_err(|e| error!("Template \"{text}\" doesn't parse: {e}")).ok()
+}
+
+/// A random quote of that kind, filled in if it's a template
+pub async fn quote(data: &RwLock<TypeMap>, kind: QuoteKind, transport: &dyn Transport, author_id: u64, channel_id: u64) -> String {
+    let content = content::get(data).await;
+    let mut rng = rng::fork(data).await;
+    let quote = content.choose(kind, &mut rng);
+    if !kind.is_template() {
+        return quote.to_string();
+    }
+    render(quote, &content, transport, author_id, channel_id, &mut rng).await
+}
+
+/// A random quote for something that happened in a server rather than a message, with `{name}` as what it happened to
+pub async fn event_quote(data: &RwLock<TypeMap>, kind: QuoteKind, name: &str) -> String {
+    let content = content::get(data).await;
+    let mut rng = rng::fork(data).await;
+    let quote = content.choose(kind, &mut rng);
+    match parse_or_log(quote) {
+        Some(template) => template.render(&Context { content: &content, author_id: None, channel_id: None, name, member_ids: Vec::new() }, &mut rng),
+        None => quote.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    fn render_all(text: &str, content: &Content) -> Vec<String> {
+        let template = Template::parse(text).unwrap();
+        let context = Context { content, author_id: Some(101), channel_id: Some(2), name: "Hodgey", member_ids: vec![102, 103] };
+        let mut rng = StdRng::seed_from_u64(555);
+        (0..200).map(|_| template.render(&context, &mut rng)).collect()
+    }
+
+    #[test]
+    fn placeholders_are_filled_in() {
+        let content = Content::built_in();
+        for rendered in render_all("{author} in {channel}: have you heard {song}?", &content) {
+            let song = rendered.strip_prefix("<@101> in <#2>: have you heard ").unwrap().strip_suffix('?').unwrap();
+            assert!(content.quotes(QuoteKind::Songs).iter().any(|quote| quote == song), "{rendered}");
+        }
+        let members = render_all("{random_member}", &content);
+        assert!(members.contains(&String::from("<@102>")) && members.contains(&String::from("<@103>")));
+        assert!(members.iter().all(|member| member == "<@102>" || member == "<@103>"));
+    }
+
+    #[test]
+    fn choices_can_nest() {
+        let content = Content::built_in();
+        let rendered = render_all("I {love|{really|kind of} like} it{!|}", &content);
+        let expected = ["I love it!", "I love it", "I really like it!", "I really like it", "I kind of like it!", "I kind of like it"];
+        for option in expected {
+            assert!(rendered.iter().any(|text| text == option), "{option}");
+        }
+        assert!(rendered.iter().all(|text| expected.contains(&text.as_str())));
+        assert_eq!(render_all("{{author}}", &content)[0], "<@101>");
+        assert_eq!(render_all("hi {name}", &content)[0], "hi Hodgey");
+    }
+
+    #[test]
+    fn escapes_and_plain_text() {
+        let content = Content::built_in();
+        assert_eq!(render_all(r"a \{song\} b|c \\", &content)[0], r"a {song} b|c \");
+        assert!(!Template::parse("no placeholders").unwrap().needs_members());
+        assert!(Template::parse("{a|{random_member}}").unwrap().needs_members());
+    }
+
+    #[test]
+    fn bad_templates_are_explained() {
+        assert_eq!(Template::parse("have you heard {song"), Err(TemplateError::Unclosed));
+        assert_eq!(Template::parse("oops}"), Err(TemplateError::UnexpectedClose));
+        assert_eq!(Template::parse("{Song}").unwrap(), Template::parse("{song}").unwrap());
+        let e = Template::parse("{songs}").unwrap_err();
+        assert_eq!(e, TemplateError::UnknownPlaceholder(String::from("songs")));
+        assert!(e.to_string().starts_with("there is no placeholder called {songs}, the options are {song}, {book}"), "{e}");
+        assert_eq!(Template::parse(&"{a|".repeat(MAX_DEPTH + 1)), Err(TemplateError::TooDeep));
+    }
+}
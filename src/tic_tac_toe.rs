@@ -0,0 +1,120 @@
+use crate::games::TurnBasedGame;
+
+const LINES: [[usize; 3]; 8] = [[0, 1, 2], [3, 4, 5], [6, 7, 8], [0, 3, 6], [1, 4, 7], [2, 5, 8], [0, 4, 8], [2, 4, 6]];
+
+/// Cells are numbered 1-9 left to right, top to bottom
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct TicTacToe {
+    cells: [Option<usize>; 9],
+    moves_played: usize
+}
+
+impl TurnBasedGame for TicTacToe {
+    type Move = usize;
+
+    const NAME: &'static str = "tic_tac_toe";
+    const PIECES: [&'static str; 2] = ["❌", "⭕"];
+    //The whole game fits in the search
+    const SEARCH_DEPTH: u32 = 9;
+
+    fn current_player(&self) -> usize {
+        self.moves_played % 2
+    }
+
+    fn legal_moves(&self) -> Vec<usize> {
+        if self.winner().is_some() {
+            return Vec::new();
+        }
+        (0..9).filter(|cell| self.cells[*cell].is_none()).collect()
+    }
+
+    fn parse_move(&self, input: &str) -> Result<usize, &'static str> {
+        let cell = match input.trim().parse::<usize>() {
+            Ok(cell @ 1..=9) => cell - 1,
+            _ => return Err("pick_square")
+        };
+        if self.cells[cell].is_some() {
+            return Err("square_taken");
+        }
+        Ok(cell)
+    }
+
+    fn play(&mut self, selected_move: &usize) {
+        self.cells[*selected_move] = Some(self.current_player());
+        self.moves_played += 1;
+    }
+
+    fn winner(&self) -> Option<usize> {
+        LINES.iter().find_map(|[a, b, c]| {
+            match (self.cells[*a], self.cells[*b], self.cells[*c]) {
+                (Some(x), Some(y), Some(z)) if x == y && y == z => Some(x),
+                _ => None
+            }
+        })
+    }
+
+    fn render(&self) -> String {
+        const NUMBERS: [&str; 9] = ["1️⃣", "2️⃣", "3️⃣", "4️⃣", "5️⃣", "6️⃣", "7️⃣", "8️⃣", "9️⃣"];
+        let mut result = String::new();
+        for row in 0..3 {
+            for col in 0..3 {
+                let cell = row * 3 + col;
+                result += match self.cells[cell] {
+                    Some(player) => Self::PIECES[player],
+                    None => NUMBERS[cell]
+                };
+            }
+            result += "\n";
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_cover_every_row_column_and_diagonal() {
+        let mut lines: Vec<[usize; 3]> = (0..3).map(|row| [row * 3, row * 3 + 1, row * 3 + 2]).collect();
+        lines.extend((0..3).map(|col| [col, col + 3, col + 6]));
+        lines.push([0, 4, 8]);
+        lines.push([2, 4, 6]);
+        lines.sort_unstable();
+        let mut table = LINES.to_vec();
+        table.sort_unstable();
+        assert_eq!(table, lines);
+
+        for line in LINES {
+            let mut game = TicTacToe::default();
+            for cell in line {
+                game.cells[cell] = Some(1);
+            }
+            assert_eq!(game.winner(), Some(1));
+        }
+    }
+
+    #[test]
+    fn bad_squares() {
+        let mut game = TicTacToe::default();
+        game.play(&4);
+        assert_eq!(game.parse_move("0"), Err("pick_square"));
+        assert_eq!(game.parse_move("10"), Err("pick_square"));
+        assert_eq!(game.parse_move("middle"), Err("pick_square"));
+        assert_eq!(game.parse_move("5"), Err("square_taken"));
+        assert_eq!(game.parse_move("9"), Ok(8));
+    }
+
+    #[test]
+    fn full_board_is_a_draw() {
+        let mut game = TicTacToe::default();
+        //❌⭕❌
+        //❌⭕⭕
+        //⭕❌❌
+        for cell in [0, 1, 2, 4, 3, 5, 7, 6, 8] {
+            game.play(&cell);
+        }
+        assert_eq!(game.winner(), None);
+        assert!(game.gameover());
+    }
+}
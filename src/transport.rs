@@ -0,0 +1,195 @@
+use serenity::all::{Cache, ChannelId, CommandInteraction, GuildId, Http, Message};
+use serenity::async_trait;
+use serenity::builder::{CreateActionRow, CreateEmbed, CreateInteractionResponseFollowup, CreateMessage};
+use crate::permissions::MemberInfo;
+
+/// Everything a command can send back, as a plain value so tests can look at it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outgoing {
+    /// A reply to the message, or the answer to a slash command
+    Reply(Reply),
+    /// A message in the channel. Slash commands have nowhere else to put it so it becomes part of the answer.
+    Say(String),
+    /// A reaction on the message. Slash commands have no message so this is skipped.
+    React(char)
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Reply {
+    /// Left out when empty
+    pub content: String,
+    pub embeds: Vec<CreateEmbed>,
+    pub components: Vec<CreateActionRow>
+}
+
+impl Reply {
+    pub fn text(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// The only way commands talk to Discord, so they can be run against a fake in tests.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    async fn send(&self, outgoing: Outgoing) -> serenity::Result<()>;
+    /// What the person who sent the command is allowed to do, None outside of servers
+    async fn member_info(&self) -> Option<MemberInfo>;
+    /// Every member of the server who isn't a bot
+    async fn human_member_ids(&self) -> serenity::Result<Vec<u64>>;
+    /// Whether a channel Hodgey can see is in the server
+    async fn channel_in_guild(&self, guild_id: GuildId, channel_id: ChannelId) -> bool;
+}
+
+//Discord is only asked when the server isn't cached, or for threads which the cache keeps separately
+async fn channel_in_guild(http: &Http, cache: &Cache, guild_id: GuildId, channel_id: ChannelId) -> bool {
+    if cache.guild(guild_id).is_some_and(|guild| guild.channels.contains_key(&channel_id)) {
+        return true;
+    }
+    channel_id.to_channel(http).await.ok().and_then(|channel| channel.guild()).is_some_and(|channel| channel.guild_id == guild_id)
+}
+
+pub struct MessageTransport<'a> {
+    pub http: &'a Http,
+    pub cache: &'a Cache,
+    pub msg: &'a Message
+}
+
+#[async_trait]
+impl Transport for MessageTransport<'_> {
+    async fn send(&self, outgoing: Outgoing) -> serenity::Result<()> {
+        match outgoing {
+            Outgoing::Reply(reply) => {
+                let mut builder = CreateMessage::new()
+                    .embeds(reply.embeds)
+                    .components(reply.components)
+                    .reference_message(self.msg);
+                if !reply.content.is_empty() {
+                    builder = builder.content(reply.content);
+                }
+                self.msg.channel_id.send_message(self.http, builder).await?;
+            },
+            Outgoing::Say(content) => {
+                self.msg.channel_id.say(self.http, content).await?;
+            },
+            Outgoing::React(reaction) => {
+                self.msg.react(self.http, reaction).await?;
+            }
+        }
+        Ok(())
+    }
+
+    //Discord is only asked when the server or member isn't cached
+    async fn member_info(&self) -> Option<MemberInfo> {
+        let guild_id = self.msg.guild_id?;
+        if let Some(member_info) = MemberInfo::from_cache(self.cache, guild_id, self.msg.author.id) {
+            return Some(member_info);
+        }
+        let guild = guild_id.to_partial_guild(self.http).await.ok()?;
+        let member = self.msg.member(self.http).await.ok()?;
+        Some(MemberInfo {
+            is_owner: guild.owner_id == self.msg.author.id,
+            permissions: guild.member_permissions(&member),
+            role_ids: member.roles.iter().map(|role_id| role_id.get()).collect()
+        })
+    }
+
+    async fn human_member_ids(&self) -> serenity::Result<Vec<u64>> {
+        let Some(guild_id) = self.msg.guild_id else {
+            return Ok(Vec::new());
+        };
+        let members = guild_id.members(self.http, None, None).await?;
+        Ok(members.iter().filter(|member| !member.user.bot).map(|member| member.user.id.get()).collect())
+    }
+
+    async fn channel_in_guild(&self, guild_id: GuildId, channel_id: ChannelId) -> bool {
+        channel_in_guild(self.http, self.cache, guild_id, channel_id).await
+    }
+}
+
+/// Slash commands are deferred before running, so everything sent is a followup
+pub struct SlashTransport<'a> {
+    pub http: &'a Http,
+    pub cache: &'a Cache,
+    pub interaction: &'a CommandInteraction
+}
+
+#[async_trait]
+impl Transport for SlashTransport<'_> {
+    async fn send(&self, outgoing: Outgoing) -> serenity::Result<()> {
+        let reply = match outgoing {
+            Outgoing::Reply(reply) => reply,
+            Outgoing::Say(content) => Reply::text(content),
+            Outgoing::React(_) => return Ok(())
+        };
+        let mut builder = CreateInteractionResponseFollowup::new()
+            .embeds(reply.embeds)
+            .components(reply.components);
+        if !reply.content.is_empty() {
+            builder = builder.content(reply.content);
+        }
+        self.interaction.create_followup(self.http, builder).await?;
+        Ok(())
+    }
+
+    //Discord works out the member's permissions for interactions
+    async fn member_info(&self) -> Option<MemberInfo> {
+        let member = self.interaction.member.as_deref()?;
+        Some(MemberInfo::from_interaction_member(self.cache, self.interaction.guild_id, member))
+    }
+
+    async fn human_member_ids(&self) -> serenity::Result<Vec<u64>> {
+        let Some(guild_id) = self.interaction.guild_id else {
+            return Ok(Vec::new());
+        };
+        let members = guild_id.members(self.http, None, None).await?;
+        Ok(members.iter().filter(|member| !member.user.bot).map(|member| member.user.id.get()).collect())
+    }
+
+    async fn channel_in_guild(&self, guild_id: GuildId, channel_id: ChannelId) -> bool {
+        channel_in_guild(self.http, self.cache, guild_id, channel_id).await
+    }
+}
+
+/// Records everything instead of sending it.
+#[cfg(test)]
+#[derive(Default)]
+pub struct FakeTransport {
+    pub sent: std::sync::Mutex<Vec<Outgoing>>,
+    /// None acts like a DM
+    pub member: Option<MemberInfo>,
+    pub human_member_ids: Vec<u64>,
+    /// Channels and the server each one is in, any other channel is in no server Hodgey can see
+    pub channels: Vec<(ChannelId, GuildId)>
+}
+
+#[cfg(test)]
+impl FakeTransport {
+    /// Everything sent since the last call
+    pub fn take_sent(&self) -> Vec<Outgoing> {
+        std::mem::take(&mut *self.sent.lock().unwrap())
+    }
+}
+
+#[cfg(test)]
+#[async_trait]
+impl Transport for FakeTransport {
+    async fn send(&self, outgoing: Outgoing) -> serenity::Result<()> {
+        self.sent.lock().unwrap().push(outgoing);
+        Ok(())
+    }
+
+    async fn member_info(&self) -> Option<MemberInfo> {
+        self.member.clone()
+    }
+
+    async fn human_member_ids(&self) -> serenity::Result<Vec<u64>> {
+        Ok(self.human_member_ids.clone())
+    }
+
+    async fn channel_in_guild(&self, guild_id: GuildId, channel_id: ChannelId) -> bool {
+        self.channels.contains(&(channel_id, guild_id))
+    }
+}